@@ -0,0 +1,43 @@
+// 로컬 알림 gRPC 서비스 스텁 (개발 / 테스트용)
+// 실행: cargo run --example notification_stub
+// 설정: APP__NOTIFICATION_GRPC_ADDR=http://127.0.0.1:50060
+// 받은 알림을 출력하고, dedup_key 가 이미 받은 값이면 중복으로 표시
+use std::{collections::HashSet, sync::Mutex};
+
+use reservation_msservice::notification_proto::{notification_service_server::{NotificationService, NotificationServiceServer}, NotificationRequest, NotificationResponse};
+use tonic::{transport::Server, Request, Response, Status};
+
+#[derive(Default)]
+struct NotificationStub {
+    seen: Mutex<HashSet<String>>,
+}
+
+#[tonic::async_trait]
+impl NotificationService for NotificationStub {
+    async fn send_notification(&self, request: Request<NotificationRequest>) -> Result<Response<NotificationResponse>, Status> {
+        let req = request.into_inner();
+        let duplicate = !self.seen.lock().unwrap().insert(req.dedup_key.clone());
+
+        println!(
+            "🔔 {}[{}] {} / {} → {:?} {:?}",
+            if duplicate { "(중복) " } else { "" }, req.dedup_key, req.title, req.body, req.user_ids, req.data
+        );
+
+        Ok(Response::new(NotificationResponse {
+            success: true,
+            sent_count: if duplicate { 0 } else { req.user_ids.len() as i32 },
+            message: String::new(),
+        }))
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = "127.0.0.1:50060".parse()?;
+    println!("알림 스텁 실행: {}", addr);
+    Server::builder()
+        .add_service(NotificationServiceServer::new(NotificationStub::default()))
+        .serve(addr)
+        .await?;
+    Ok(())
+}
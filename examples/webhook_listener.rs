@@ -0,0 +1,36 @@
+// 로컬 웹훅 수신기 (개발 / 테스트용)
+// 실행: WEBHOOK_SECRET=<구독 secret> cargo run --example webhook_listener
+// 구독 등록 시 url 을 http://127.0.0.1:9090/webhook 으로 지정하면 서명 검증 결과와 본문을 출력
+// (로컬 주소는 기본적으로 거부되므로 서비스에 APP__WEBHOOK_ALLOW_PRIVATE_TARGETS=true 설정 필요)
+// WEBHOOK_FAIL=1 이면 항상 500 을 반환해 재시도 / 데드레터 동작을 확인할 수 있음
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer, Responder};
+use chrono::Utc;
+use reservation_msservice::common::webhook_signature::{self, SIGNATURE_HEADER};
+
+const SIGNATURE_TOLERANCE_SECS: i64 = 5 * 60;
+
+async fn receive(http_req: HttpRequest, body: String) -> impl Responder {
+    let secret = std::env::var("WEBHOOK_SECRET").unwrap_or_default();
+    let signature = http_req.headers().get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok()).unwrap_or("");
+    let event_type = http_req.headers().get("X-Event-Type").and_then(|v| v.to_str().ok()).unwrap_or("");
+    let valid = webhook_signature::verify(&secret, signature, &body, Utc::now(), SIGNATURE_TOLERANCE_SECS);
+
+    println!("📥 {} (서명 {}): {}", event_type, if valid { "OK" } else { "불일치" }, body);
+
+    if !valid {
+        return HttpResponse::Unauthorized().finish();
+    }
+    if std::env::var("WEBHOOK_FAIL").is_ok() {
+        return HttpResponse::InternalServerError().finish();
+    }
+    HttpResponse::Ok().finish()
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    println!("웹훅 수신 대기: http://127.0.0.1:9090/webhook");
+    HttpServer::new(|| App::new().route("/webhook", web::post().to(receive)))
+        .bind(("127.0.0.1", 9090))?
+        .run()
+        .await
+}
@@ -0,0 +1,13 @@
+-- 예약 생성 멱등성 키 테이블 (Idempotency-Key 헤더 / gRPC idempotency_key)
+CREATE TABLE IDEMPOTENCY_KEY (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id VARCHAR(6) NOT NULL,
+    idem_key VARCHAR(255) NOT NULL,
+    request_fingerprint VARCHAR(255) NOT NULL,
+    status ENUM('IN_PROGRESS', 'COMPLETED') NOT NULL DEFAULT 'IN_PROGRESS',
+    response_code SMALLINT UNSIGNED,
+    response_body TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    UNIQUE KEY uk_idempotency_user_key (user_id, idem_key)
+);
@@ -0,0 +1,11 @@
+-- 컨텐츠별 예약 규칙 테이블 (값이 NULL이면 해당 규칙 미적용)
+CREATE TABLE CONTENT_BOOKING_RULE (
+    content_id BIGINT UNSIGNED PRIMARY KEY,
+    children_only BOOLEAN NOT NULL DEFAULT FALSE,     -- 어린이 전용 회차
+    max_party_size INT NULL,                          -- 1회 예약 최대 인원
+    max_adults INT NULL,                              -- 1회 예약 최대 성인 수
+    max_children INT NULL,                            -- 1회 예약 최대 어린이 수
+    booking_opens_days_before INT NULL,               -- 시작 N일 전부터 예약 가능
+    booking_closes_minutes_before INT NULL,           -- 시작 N분 전 예약 마감
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
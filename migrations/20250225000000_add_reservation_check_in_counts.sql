@@ -0,0 +1,11 @@
+-- 부분 입장 처리용 입장 인원 (성인 / 어린이)
+ALTER TABLE RESERVATION
+    ADD COLUMN checked_in_ad_cnt INT NOT NULL DEFAULT 0,
+    ADD COLUMN checked_in_cd_cnt INT NOT NULL DEFAULT 0;
+
+-- 기존에 사용 처리(CONFIRMED)된 예약은 전원 입장으로 간주
+UPDATE RESERVATION
+SET checked_in_ad_cnt = COALESCE(ad_cnt, 0),
+    checked_in_cd_cnt = COALESCE(cd_cnt, 0),
+    use_at = 1
+WHERE status = 'CONFIRMED';
@@ -0,0 +1,16 @@
+-- 노쇼 상태 추가
+ALTER TABLE RESERVATION
+    MODIFY COLUMN status ENUM('PENDING', 'CONFIRMED', 'CANCELLED', 'NO_SHOW') DEFAULT 'PENDING';
+
+-- 노쇼 기록 (리포트 / 사용자별 노쇼 횟수 집계용)
+CREATE TABLE NO_SHOW_RECORD (
+    reservation_id INT PRIMARY KEY,
+    user_id VARCHAR(6) NOT NULL,
+    content_schedule_id BIGINT UNSIGNED NOT NULL,
+    ad_cnt INT NOT NULL,
+    cd_cnt INT NOT NULL,
+    schedule_start_time TIMESTAMP NULL,
+    recorded_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_no_show_user (user_id, schedule_start_time),
+    FOREIGN KEY (reservation_id) REFERENCES RESERVATION(id) ON DELETE CASCADE
+);
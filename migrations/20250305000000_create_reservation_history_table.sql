@@ -0,0 +1,22 @@
+-- 예약 변경 이력 (예약 변경과 같은 트랜잭션에서 기록)
+CREATE TABLE RESERVATION_HISTORY (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    reservation_id INT NOT NULL,
+    action VARCHAR(20) NOT NULL,                 -- CREATE / CHANGE_COUNT / MOVE / CHECK_IN / CANCEL / NO_SHOW / STATUS_CHANGE / DELETE
+    actor_id VARCHAR(64) NULL,                   -- 요청한 사용자 (내부 작업이면 NULL)
+    channel VARCHAR(10) NOT NULL,                -- REST / GRPC / MANUAL / SYSTEM
+    before_schedule_id BIGINT UNSIGNED NULL,
+    before_ad_cnt INT NULL,
+    before_cd_cnt INT NULL,
+    before_status VARCHAR(20) NULL,
+    before_checked_in_ad_cnt INT NULL,
+    before_checked_in_cd_cnt INT NULL,
+    after_schedule_id BIGINT UNSIGNED NULL,
+    after_ad_cnt INT NULL,
+    after_cd_cnt INT NULL,
+    after_status VARCHAR(20) NULL,
+    after_checked_in_ad_cnt INT NULL,
+    after_checked_in_cd_cnt INT NULL,
+    created_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    INDEX idx_reservation_history (reservation_id, id)
+);
@@ -0,0 +1,15 @@
+-- 예약 도메인 이벤트 아웃박스 (예약 변경과 같은 트랜잭션에서 기록 → 릴레이 작업이 외부로 전달)
+CREATE TABLE RESERVATION_OUTBOX (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    event_type VARCHAR(30) NOT NULL,             -- RESERVATION_CREATED / RESERVATION_UPDATED / RESERVATION_CANCELLED / RESERVATION_USED
+    reservation_id INT NOT NULL,
+    payload JSON NOT NULL,                       -- 변경 후 예약 상태
+    created_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    attempts INT NOT NULL DEFAULT 0,             -- 전달 시도 횟수
+    next_attempt_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    last_error VARCHAR(500) NULL,
+    published_at TIMESTAMP(6) NULL,              -- 전달 완료 시각 (NULL 이면 미전달)
+    dead_at TIMESTAMP(6) NULL,                   -- payload 를 읽을 수 없어 전달을 포기한 시각
+    INDEX idx_outbox_pending (published_at, next_attempt_at, id),
+    INDEX idx_outbox_reservation (reservation_id, id) -- 같은 예약의 앞선 미전달 이벤트 확인용
+);
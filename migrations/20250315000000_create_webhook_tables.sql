@@ -0,0 +1,29 @@
+-- 웹훅 구독 (파트너 시스템 수신 URL)
+CREATE TABLE WEBHOOK_SUBSCRIPTION (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    url VARCHAR(500) NOT NULL,
+    secret VARCHAR(255) NOT NULL,                -- 서명 키
+    event_types VARCHAR(200) NULL,               -- 쉼표 구분 이벤트 종류 (NULL 이면 전체)
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+-- 웹훅 전달 (구독 × 이벤트, 아웃박스 릴레이가 생성)
+CREATE TABLE WEBHOOK_DELIVERY (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    subscription_id BIGINT NOT NULL,
+    event_id BIGINT NOT NULL,                    -- RESERVATION_OUTBOX.id
+    event_type VARCHAR(30) NOT NULL,
+    body TEXT NOT NULL,                          -- 전송 본문 (서명 대상)
+    status ENUM('PENDING', 'DELIVERED', 'DEAD') NOT NULL DEFAULT 'PENDING',
+    attempts INT NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    last_status_code SMALLINT UNSIGNED NULL,
+    last_error VARCHAR(500) NULL,
+    created_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    delivered_at TIMESTAMP(6) NULL,
+    UNIQUE KEY uq_webhook_delivery (subscription_id, event_id), -- 이벤트 재전달 시 중복 생성 방지
+    INDEX idx_webhook_delivery_pending (status, next_attempt_at, id),
+    FOREIGN KEY (subscription_id) REFERENCES WEBHOOK_SUBSCRIPTION(id) ON DELETE CASCADE
+);
@@ -0,0 +1,10 @@
+-- 회차 시작 전 알림 발송 기록 (예약 × 발송 시점당 1건, 중복 발송 방지 / 늦게 예약한 사용자도 다음 조회에서 발송)
+CREATE TABLE RESERVATION_REMINDER (
+    reservation_id INT NOT NULL,
+    minutes_before INT NOT NULL,                 -- 시작 N분 전 알림
+    status ENUM('SENDING', 'SENT') NOT NULL DEFAULT 'SENDING',
+    claimed_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    sent_at TIMESTAMP(6) NULL,
+    PRIMARY KEY (reservation_id, minutes_before),
+    FOREIGN KEY (reservation_id) REFERENCES RESERVATION(id) ON DELETE CASCADE
+);
@@ -0,0 +1,62 @@
+-- 단체 계정 (학교 / 여행사 등)
+CREATE TABLE ORGANISATION (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    org_type ENUM('SCHOOL', 'AGENCY', 'OTHER') NOT NULL DEFAULT 'OTHER',
+    max_party_size INT NOT NULL,                 -- 단체 예약 1건의 회차별 최대 인원
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- 단체 소속 사용자 (사용자당 하나의 단체)
+CREATE TABLE ORGANISATION_MEMBER (
+    user_id VARCHAR(6) PRIMARY KEY,
+    organisation_id BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES USERS(id) ON DELETE CASCADE,
+    FOREIGN KEY (organisation_id) REFERENCES ORGANISATION(id) ON DELETE CASCADE
+);
+
+-- 회차별 단체 전용 좌석 (개인 예약 가능 좌석 = 총 좌석 - quota_seats)
+CREATE TABLE SCHEDULE_GROUP_QUOTA (
+    content_schedule_id BIGINT UNSIGNED PRIMARY KEY,
+    quota_seats INT NOT NULL,
+    held_seats INT NOT NULL DEFAULT 0,           -- 승인 대기 + 승인된 단체 예약 인원
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (content_schedule_id) REFERENCES CONTENT_SCHEDULES(id) ON DELETE CASCADE
+);
+
+-- 단체 예약 (여러 회차를 묶어 한 번에 승인 / 반려)
+CREATE TABLE GROUP_RESERVATION (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    organisation_id BIGINT NOT NULL,
+    requested_by VARCHAR(6) NOT NULL,
+    status ENUM('REQUESTED', 'APPROVED', 'REJECTED', 'CANCELLED') NOT NULL DEFAULT 'REQUESTED',
+    note VARCHAR(500) NULL,
+    reviewed_by VARCHAR(6) NULL,
+    review_note VARCHAR(500) NULL,
+    reviewed_at TIMESTAMP NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_group_reservation_status (status, created_at),
+    FOREIGN KEY (organisation_id) REFERENCES ORGANISATION(id),
+    FOREIGN KEY (requested_by) REFERENCES USERS(id)
+);
+
+-- 단체 예약 회차별 인원 (승인 시 RESERVATION 생성)
+CREATE TABLE GROUP_RESERVATION_ITEM (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    group_reservation_id BIGINT NOT NULL,
+    content_schedule_id BIGINT UNSIGNED NOT NULL,
+    ad_cnt INT NOT NULL,
+    cd_cnt INT NOT NULL,
+    reservation_id INT NULL,
+    UNIQUE KEY uq_group_reservation_item (group_reservation_id, content_schedule_id),
+    FOREIGN KEY (group_reservation_id) REFERENCES GROUP_RESERVATION(id) ON DELETE CASCADE,
+    FOREIGN KEY (content_schedule_id) REFERENCES CONTENT_SCHEDULES(id),
+    FOREIGN KEY (reservation_id) REFERENCES RESERVATION(id) ON DELETE SET NULL
+);
+
+-- 단체 예약으로 생성된 예약 (단체 좌석을 사용하므로 회차 인원 카운터에 포함되지 않음)
+ALTER TABLE RESERVATION
+    ADD COLUMN group_reservation_id BIGINT NULL,
+    ADD CONSTRAINT fk_reservation_group FOREIGN KEY (group_reservation_id) REFERENCES GROUP_RESERVATION(id);
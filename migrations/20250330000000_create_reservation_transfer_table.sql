@@ -0,0 +1,21 @@
+-- 예약 양도 요청 (소유자가 요청 → 받는 사용자가 수락하면 소유자 변경)
+CREATE TABLE RESERVATION_TRANSFER (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    reservation_id INT NOT NULL,
+    from_user_id VARCHAR(6) NOT NULL,
+    to_user_id VARCHAR(6) NOT NULL,
+    status ENUM('PENDING', 'ACCEPTED', 'DECLINED', 'CANCELLED', 'EXPIRED') NOT NULL DEFAULT 'PENDING',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    responded_at TIMESTAMP NULL,
+    INDEX idx_transfer_reservation (reservation_id, status),
+    INDEX idx_transfer_to_user (to_user_id, status),
+    INDEX idx_transfer_from_user (from_user_id, status),
+    FOREIGN KEY (reservation_id) REFERENCES RESERVATION(id) ON DELETE CASCADE,
+    FOREIGN KEY (to_user_id) REFERENCES USERS(id) ON DELETE CASCADE
+);
+
+-- 양도 이력을 위해 변경 전 / 후 소유자 기록
+ALTER TABLE RESERVATION_HISTORY
+    ADD COLUMN before_user_id VARCHAR(6) NULL AFTER channel,
+    ADD COLUMN after_user_id VARCHAR(6) NULL AFTER before_checked_in_cd_cnt;
@@ -0,0 +1,25 @@
+-- 예약별 권종 인원 (ad_cnt / cd_cnt 는 기존 클라이언트 호환용 합계로 유지)
+-- 성인 합계 = ADULT + SENIOR + DISABLED + COMPANION, 어린이 합계 = CHILD + INFANT
+CREATE TABLE RESERVATION_PARTY (
+    reservation_id INT NOT NULL,
+    category ENUM('ADULT', 'CHILD', 'INFANT', 'SENIOR', 'DISABLED', 'COMPANION') NOT NULL,
+    cnt INT NOT NULL,
+    PRIMARY KEY (reservation_id, category),
+    FOREIGN KEY (reservation_id) REFERENCES RESERVATION(id) ON DELETE CASCADE
+);
+
+-- 기존 예약은 성인 / 어린이 권종으로 이관
+INSERT INTO RESERVATION_PARTY (reservation_id, category, cnt)
+SELECT id, 'ADULT', ad_cnt FROM RESERVATION WHERE COALESCE(ad_cnt, 0) > 0;
+
+INSERT INTO RESERVATION_PARTY (reservation_id, category, cnt)
+SELECT id, 'CHILD', cd_cnt FROM RESERVATION WHERE COALESCE(cd_cnt, 0) > 0;
+
+-- 컨텐츠별 권종 규칙 (값이 NULL이면 해당 규칙 미적용)
+CREATE TABLE CONTENT_CATEGORY_RULE (
+    content_id BIGINT UNSIGNED NOT NULL,
+    category ENUM('ADULT', 'CHILD', 'INFANT', 'SENIOR', 'DISABLED', 'COMPANION') NOT NULL,
+    max_per_reservation INT NULL,                     -- 1회 예약 최대 인원
+    max_per_schedule INT NULL,                        -- 회차별 최대 인원 (휠체어석 등)
+    PRIMARY KEY (content_id, category)
+);
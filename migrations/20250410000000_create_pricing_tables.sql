@@ -0,0 +1,37 @@
+-- 컨텐츠별 권종 단가 (가격이 등록되지 않은 컨텐츠는 무료)
+CREATE TABLE CONTENT_PRICE (
+    content_id BIGINT UNSIGNED NOT NULL,
+    category ENUM('ADULT', 'CHILD', 'INFANT', 'SENIOR', 'DISABLED', 'COMPANION') NOT NULL,
+    unit_price BIGINT NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    PRIMARY KEY (content_id, category)
+);
+
+-- 할인 규칙 (content_id 가 NULL 이면 전체 컨텐츠, 중복 할인 없이 할인 금액이 가장 큰 규칙 1개만 적용)
+CREATE TABLE PRICING_DISCOUNT (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    content_id BIGINT UNSIGNED NULL,
+    discount_type ENUM('MEMBER', 'GROUP', 'TIME_OF_DAY') NOT NULL,
+    rate_percent INT NOT NULL,                        -- 할인율 (1 ~ 100)
+    min_party_size INT NULL,                          -- GROUP: 최소 인원
+    start_hour INT NULL,                              -- TIME_OF_DAY: 회차 시작 시각 (영업 시간대 기준, 시작 포함)
+    end_hour INT NULL,                                -- TIME_OF_DAY: 회차 시작 시각 (종료 미포함)
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_pricing_discount_content (content_id, active)
+);
+
+-- 회원 할인 대상 (expires_at 이 NULL 이면 만료 없음)
+CREATE TABLE MEMBERSHIP (
+    user_id VARCHAR(6) PRIMARY KEY,
+    expires_at TIMESTAMP NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES USERS(id) ON DELETE CASCADE
+);
+
+-- 예약 시점에 산정한 금액 (가격 산정 이전 예약은 NULL)
+ALTER TABLE RESERVATION
+    ADD COLUMN list_price BIGINT NULL,
+    ADD COLUMN total_price BIGINT NULL,
+    ADD COLUMN discount_type VARCHAR(20) NULL,
+    ADD COLUMN currency CHAR(3) NULL;
@@ -0,0 +1,27 @@
+-- 결제 대기 상태 추가 (유료 예약은 결제 완료 전까지 좌석만 잡아 둠)
+ALTER TABLE RESERVATION
+    MODIFY COLUMN status ENUM('PENDING_PAYMENT', 'PENDING', 'CONFIRMED', 'CANCELLED', 'NO_SHOW') DEFAULT 'PENDING',
+    ADD COLUMN payment_due_at TIMESTAMP NULL,          -- 결제 기한 (지나면 예약 취소 + 좌석 반환)
+    ADD INDEX idx_reservation_payment_due (status, payment_due_at);
+
+-- 예약 결제 (예약당 여러 건 가능, 결제 대기 / 완료 건은 예약당 1건)
+CREATE TABLE PAYMENT (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    reservation_id INT NOT NULL,
+    user_id VARCHAR(6) NOT NULL,                      -- 결제한 사용자 (양도 후에도 환불은 결제한 사용자에게)
+    provider VARCHAR(20) NOT NULL,                    -- 결제 게이트웨이 (mock 등)
+    provider_payment_id VARCHAR(100) NULL,            -- 게이트웨이 결제 ID (결제창 생성 후 기록)
+    checkout_url VARCHAR(500) NULL,
+    amount BIGINT NOT NULL,
+    currency CHAR(3) NOT NULL,
+    status ENUM('PENDING', 'PAID', 'FAILED', 'EXPIRED', 'REFUND_PENDING', 'REFUNDED') NOT NULL DEFAULT 'PENDING',
+    refund_amount BIGINT NULL,                        -- 환불 요청 / 완료 금액 (취소 시점 환불 정책 기준)
+    failure_reason VARCHAR(255) NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    paid_at TIMESTAMP NULL,
+    refunded_at TIMESTAMP NULL,
+    UNIQUE KEY uq_payment_provider (provider, provider_payment_id),
+    INDEX idx_payment_reservation (reservation_id, id),
+    INDEX idx_payment_status (status),
+    FOREIGN KEY (reservation_id) REFERENCES RESERVATION(id) ON DELETE CASCADE
+);
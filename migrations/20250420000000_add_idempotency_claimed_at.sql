@@ -0,0 +1,8 @@
+-- 처리 중(IN_PROGRESS) 키 점유 시각 (점유 시간이 지나면 중단된 요청으로 보고 같은 키로 재시도 허용)
+ALTER TABLE IDEMPOTENCY_KEY
+    ADD COLUMN claimed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP;
+
+-- 기존 키는 생성 시각을 점유 시각으로 사용
+UPDATE IDEMPOTENCY_KEY
+SET claimed_at = created_at
+WHERE created_at IS NOT NULL;
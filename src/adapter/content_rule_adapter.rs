@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{application::port::out::content_rule_port::ContentRulePort, domain::content_rule::ContentBookingRule, infra::db::content_rule_repository::ContentRuleRepository};
+
+// Adapter Implementation
+pub struct ContentRuleAdapter {
+    repository: Arc<dyn ContentRuleRepository + Send + Sync>,
+}
+
+impl ContentRuleAdapter {
+    pub fn new(repository: Arc<dyn ContentRuleRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl ContentRulePort for ContentRuleAdapter {
+    async fn load_content_rule(&self, content_id: u64) -> Result<Option<ContentBookingRule>, String> {
+        self.repository.load_content_rule(content_id).await
+    }
+    async fn save_content_rule(&self, rule: ContentBookingRule) -> Result<(), String> {
+        self.repository.save_content_rule(rule).await
+    }
+}
@@ -0,0 +1,56 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::{application::port::out::group_booking_port::GroupBookingPort, domain::{group_booking::{GroupQuota, GroupReservation, GroupReservationStatus, Organisation}, pricing::ReservationPrice, reservation_history::AuditContext}, infra::db::group_booking_repository::GroupBookingRepository};
+
+// Adapter Implementation
+pub struct GroupBookingAdapter {
+    repository: Arc<dyn GroupBookingRepository + Send + Sync>,
+}
+
+impl GroupBookingAdapter {
+    pub fn new(repository: Arc<dyn GroupBookingRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl GroupBookingPort for GroupBookingAdapter {
+    async fn save_organisation(&self, organisation: &Organisation) -> Result<i64, String> {
+        self.repository.save_organisation(organisation).await
+    }
+    async fn load_organisation(&self, organisation_id: i64) -> Result<Option<Organisation>, String> {
+        self.repository.load_organisation(organisation_id).await
+    }
+    async fn save_organisation_member(&self, organisation_id: i64, user_id: &str) -> Result<(), String> {
+        self.repository.save_organisation_member(organisation_id, user_id).await
+    }
+    async fn load_member_organisation(&self, user_id: &str) -> Result<Option<Organisation>, String> {
+        self.repository.load_member_organisation(user_id).await
+    }
+    async fn save_group_quota(&self, content_schedule_id: u64, quota_seats: i32) -> Result<GroupQuota, String> {
+        self.repository.save_group_quota(content_schedule_id, quota_seats).await
+    }
+    async fn load_group_quota(&self, content_schedule_id: u64) -> Result<Option<GroupQuota>, String> {
+        self.repository.load_group_quota(content_schedule_id).await
+    }
+    async fn save_group_reservation(&self, group: &GroupReservation) -> Result<i64, String> {
+        self.repository.save_group_reservation(group).await
+    }
+    async fn load_group_reservation(&self, group_reservation_id: i64) -> Result<Option<GroupReservation>, String> {
+        self.repository.load_group_reservation(group_reservation_id).await
+    }
+    async fn load_group_reservations(&self, status: Option<GroupReservationStatus>, organisation_id: Option<i64>, limit: i64) -> Result<Vec<GroupReservation>, String> {
+        self.repository.load_group_reservations(status, organisation_id, limit).await
+    }
+    async fn approve_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<&str>, prices: &HashMap<u64, ReservationPrice>, audit: &AuditContext) -> Result<Vec<i32>, String> {
+        self.repository.approve_group_reservation(group_reservation_id, reviewer_id, review_note, prices, audit).await
+    }
+    async fn reject_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<&str>) -> Result<(), String> {
+        self.repository.reject_group_reservation(group_reservation_id, reviewer_id, review_note).await
+    }
+    async fn cancel_group_reservation(&self, group_reservation_id: i64, audit: &AuditContext) -> Result<Vec<i32>, String> {
+        self.repository.cancel_group_reservation(group_reservation_id, audit).await
+    }
+}
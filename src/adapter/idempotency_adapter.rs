@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{application::port::out::idempotency_port::IdempotencyPort, domain::idempotency::{IdempotencyClaim, IdempotentResponse}, infra::db::idempotency_repository::IdempotencyRepository};
+
+// Adapter Implementation
+pub struct IdempotencyAdapter {
+    repository: Arc<dyn IdempotencyRepository + Send + Sync>,
+}
+
+impl IdempotencyAdapter {
+    pub fn new(repository: Arc<dyn IdempotencyRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl IdempotencyPort for IdempotencyAdapter {
+    async fn claim_key(&self, user_id: &str, key: &str, fingerprint: &str, ttl_secs: i64, lease_secs: i64) -> Result<IdempotencyClaim, String> {
+        self.repository.claim_key(user_id, key, fingerprint, ttl_secs, lease_secs).await
+    }
+    async fn complete_key(&self, user_id: &str, key: &str, response: &IdempotentResponse) -> Result<(), String> {
+        self.repository.complete_key(user_id, key, response).await
+    }
+    async fn release_key(&self, user_id: &str, key: &str) -> Result<(), String> {
+        self.repository.release_key(user_id, key).await
+    }
+}
@@ -1 +1,10 @@
-pub mod reservation_adapter;
\ No newline at end of file
+pub mod reservation_adapter;
+pub mod idempotency_adapter;
+pub mod content_rule_adapter;
+pub mod outbox_adapter;
+pub mod webhook_adapter;
+pub mod reminder_adapter;
+pub mod group_booking_adapter;
+pub mod transfer_adapter;
+pub mod pricing_adapter;
+pub mod payment_record_adapter;
\ No newline at end of file
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{application::port::out::outbox_port::OutboxPort, domain::reservation_event::OutboxEvent, infra::db::outbox_repository::OutboxRepository};
+
+// Adapter Implementation
+pub struct OutboxAdapter {
+    repository: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl OutboxAdapter {
+    pub fn new(repository: Arc<dyn OutboxRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl OutboxPort for OutboxAdapter {
+    async fn claim_pending(&self, now: DateTime<Utc>, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxEvent>, String> {
+        self.repository.claim_pending(now, limit, lease_until).await
+    }
+    async fn mark_published(&self, event_id: i64) -> Result<(), String> {
+        self.repository.mark_published(event_id).await
+    }
+    async fn mark_failed(&self, event_id: i64, error: &str, next_attempt_at: DateTime<Utc>) -> Result<(), String> {
+        self.repository.mark_failed(event_id, error, next_attempt_at).await
+    }
+}
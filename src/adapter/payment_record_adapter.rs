@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{application::port::out::payment_record_port::PaymentRecordPort, domain::{payment::{Payment, PaymentConfirmation, PaymentStatus}, reservation_history::AuditContext}, infra::db::payment_repository::PaymentRepository};
+
+// Adapter Implementation
+pub struct PaymentRecordAdapter {
+    repository: Arc<dyn PaymentRepository + Send + Sync>,
+}
+
+impl PaymentRecordAdapter {
+    pub fn new(repository: Arc<dyn PaymentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl PaymentRecordPort for PaymentRecordAdapter {
+    async fn save_payment(&self, payment: &Payment) -> Result<i64, String> {
+        self.repository.save_payment(payment).await
+    }
+    async fn attach_checkout(&self, payment_id: i64, provider_payment_id: &str, checkout_url: &str) -> Result<(), String> {
+        self.repository.attach_checkout(payment_id, provider_payment_id, checkout_url).await
+    }
+    async fn load_payment_by_provider_id(&self, provider: &str, provider_payment_id: &str) -> Result<Option<Payment>, String> {
+        self.repository.load_payment_by_provider_id(provider, provider_payment_id).await
+    }
+    async fn load_latest_payment(&self, reservation_id: i32) -> Result<Option<Payment>, String> {
+        self.repository.load_latest_payment(reservation_id).await
+    }
+    async fn confirm_payment(&self, payment_id: i64, now: DateTime<Utc>, audit: &AuditContext) -> Result<PaymentConfirmation, String> {
+        self.repository.confirm_payment(payment_id, now, audit).await
+    }
+    async fn release_unpaid_reservation(&self, reservation_id: i32, payment_status: PaymentStatus, failure_reason: Option<&str>, audit: &AuditContext) -> Result<bool, String> {
+        self.repository.release_unpaid_reservation(reservation_id, payment_status, failure_reason, audit).await
+    }
+    async fn load_overdue_reservation_ids(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, String> {
+        self.repository.load_overdue_reservation_ids(now, limit).await
+    }
+    async fn request_refund(&self, payment_id: i64, amount: i64) -> Result<bool, String> {
+        self.repository.request_refund(payment_id, amount).await
+    }
+    async fn complete_refund(&self, payment_id: i64, now: DateTime<Utc>) -> Result<(), String> {
+        self.repository.complete_refund(payment_id, now).await
+    }
+    async fn load_refund_pending(&self, limit: i64) -> Result<Vec<Payment>, String> {
+        self.repository.load_refund_pending(limit).await
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{application::port::out::pricing_port::PricingPort, domain::pricing::{ContentPrice, PricingDiscount}, infra::db::pricing_repository::PricingRepository};
+
+// Adapter Implementation
+pub struct PricingAdapter {
+    repository: Arc<dyn PricingRepository + Send + Sync>,
+}
+
+impl PricingAdapter {
+    pub fn new(repository: Arc<dyn PricingRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl PricingPort for PricingAdapter {
+    async fn load_content_prices(&self, content_id: u64) -> Result<Vec<ContentPrice>, String> {
+        self.repository.load_content_prices(content_id).await
+    }
+    async fn save_content_prices(&self, content_id: u64, prices: &[ContentPrice]) -> Result<(), String> {
+        self.repository.save_content_prices(content_id, prices).await
+    }
+    async fn load_applicable_discounts(&self, content_id: u64) -> Result<Vec<PricingDiscount>, String> {
+        self.repository.load_applicable_discounts(content_id).await
+    }
+    async fn load_discounts(&self) -> Result<Vec<PricingDiscount>, String> {
+        self.repository.load_discounts().await
+    }
+    async fn save_discount(&self, discount: &PricingDiscount) -> Result<i64, String> {
+        self.repository.save_discount(discount).await
+    }
+    async fn deactivate_discount(&self, discount_id: i64) -> Result<bool, String> {
+        self.repository.deactivate_discount(discount_id).await
+    }
+    async fn is_member(&self, user_id: &str, now: DateTime<Utc>) -> Result<bool, String> {
+        self.repository.is_member(user_id, now).await
+    }
+    async fn save_membership(&self, user_id: &str, expires_at: Option<DateTime<Utc>>) -> Result<(), String> {
+        self.repository.save_membership(user_id, expires_at).await
+    }
+    async fn delete_membership(&self, user_id: &str) -> Result<bool, String> {
+        self.repository.delete_membership(user_id).await
+    }
+}
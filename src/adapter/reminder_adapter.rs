@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{application::port::out::reminder_port::ReminderPort, domain::reminder::ReminderTarget, infra::db::reminder_repository::ReminderRepository};
+
+// Adapter Implementation
+pub struct ReminderAdapter {
+    repository: Arc<dyn ReminderRepository + Send + Sync>,
+}
+
+impl ReminderAdapter {
+    pub fn new(repository: Arc<dyn ReminderRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl ReminderPort for ReminderAdapter {
+    async fn load_due_reminders(&self, now: DateTime<Utc>, minutes_before: i64, stale_before: DateTime<Utc>) -> Result<Vec<ReminderTarget>, String> {
+        self.repository.load_due_reminders(now, minutes_before, stale_before).await
+    }
+    async fn claim_reminders(&self, reservation_ids: &[i32], minutes_before: i64, stale_before: DateTime<Utc>) -> Result<Vec<i32>, String> {
+        self.repository.claim_reminders(reservation_ids, minutes_before, stale_before).await
+    }
+    async fn complete_reminders(&self, reservation_ids: &[i32], minutes_before: i64) -> Result<(), String> {
+        self.repository.complete_reminders(reservation_ids, minutes_before).await
+    }
+    async fn release_reminders(&self, reservation_ids: &[i32], minutes_before: i64) -> Result<(), String> {
+        self.repository.release_reminders(reservation_ids, minutes_before).await
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
-use crate::{application::port::out::{reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort}, domain::reservation::{Reservation, ReservationStatus}, dto::reservation_chk_dto::ReservationLimits, infra::db::reservation_repository::ReservationRepository};
+use crate::{application::port::out::{reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort}, common::page::{Page, PageRequest}, domain::{reservation::{Reservation, ReservationStatus}, reservation_history::{AuditContext, ReservationHistory}, schedule_availability::ScheduleAvailability, pricing::ReservationPrice, ticket_category::PartyComposition}, dto::{reservation_chk_dto::ReservationLimits, reservation_query_dto::ReservationFilter, schedule_filter_dto::ScheduleAvailabilityFilter}, infra::db::reservation_repository::ReservationRepository};
 
 // Adapter Implementation
 pub struct ReservationAdapter {
@@ -18,11 +18,14 @@ impl ReservationAdapter {
 
 #[async_trait]
 impl ReservationLoadPort for ReservationAdapter {
-    async fn load_reservation(&self, reservation_id: i32) -> Option<Reservation> {
-        self.repository.load_reservation(reservation_id.try_into().unwrap()).await
+    async fn load_reservation(&self, reservation_id: i32) -> Result<Option<Reservation>, String> {
+        self.repository.load_reservation(reservation_id).await
     }
-    async fn load_reservations_by_date(&self, start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Result<Vec<Reservation>,String>{
-        self.repository.laod_reservations_by_date(start_time, end_time).await
+    async fn load_reservations_by_date(&self, start_time: DateTime<Utc>, end_time: DateTime<Utc>, page: PageRequest) -> Result<Page<Reservation>,String>{
+        self.repository.laod_reservations_by_date(start_time, end_time, page).await
+    }
+    async fn load_reservation_history(&self, reservation_id: i32) -> Result<Vec<ReservationHistory>, String> {
+        self.repository.load_reservation_history(reservation_id).await
     }
     async fn check_reservation_for_user_count(&self, user_id: &str, schedule_id: u64) -> Result<ReservationLimits, String> {
         let limits = self.repository.check_reservation_for_user_count(user_id, schedule_id).await?;
@@ -36,28 +39,56 @@ impl ReservationLoadPort for ReservationAdapter {
         let chk_val = self.repository.check_user_reservation_for_content(user_id, schedule_id).await?;
         Ok(chk_val) 
     }
-    async fn load_reservations_by_user(&self, user_id: &str) -> Result<Vec<Reservation>, String>
+    async fn load_reservations_by_user(&self, user_id: &str, page: PageRequest) -> Result<Page<Reservation>, String>
+    {
+        self.repository.load_reservations_by_user(user_id, page).await
+    }
+    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64, page: PageRequest)-> Result<Page<Reservation>, String>
+    {
+        self.repository.load_reservations_by_content_schedule(content_schedule_id, page).await
+    }
+    async fn load_schedule_availability(&self, schedule_id: u64) -> Result<Option<ScheduleAvailability>, String>
+    {
+        self.repository.load_schedule_availability(schedule_id).await
+    }
+    async fn load_schedules_availability(&self, filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String>
     {
-        self.repository.load_reservations_by_user(user_id).await
+        self.repository.load_schedules_availability(filter).await
     }
-    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64)-> Result<Vec<Reservation>, String>
+    async fn count_user_no_shows(&self, user_id: &str, since: DateTime<Utc>) -> Result<i64, String>
     {
-        self.repository.load_reservations_by_content_schedule(content_schedule_id).await
+        self.repository.count_user_no_shows(user_id, since).await
+    }
+    async fn search_reservations(&self, filter: ReservationFilter, page: PageRequest) -> Result<Page<Reservation>, String>
+    {
+        self.repository.search_reservations(filter, page).await
     }
     
 }
 
 #[async_trait]
 impl ReservationSavePort for ReservationAdapter {
-    async fn save_reservation(&self, reservation: Reservation) -> Result<(), String> {
-        self.repository.save_reservation(reservation).await
+    async fn save_reservation(&self, reservation: Reservation, audit: &AuditContext) -> Result<i32, String> {
+        self.repository.save_reservation(reservation, audit).await
+    }
+    async fn update_status(&self, reservation_id: i32, status: ReservationStatus, audit: &AuditContext) -> Result<(), String> {
+        self.repository.update_status(reservation_id,status, audit).await
+    }
+    async fn update_reservaiton_user_count(&self, reservation_id: i32, party: &PartyComposition, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>
+    {
+        self.repository.update_reservaiton_user_count(reservation_id, party, price, audit).await
     }
-    async fn update_status(&self, reservation_id: i32, status: ReservationStatus) -> Result<(), String> {
-        self.repository.update_status(reservation_id,status).await
+    async fn check_in_party(&self, reservation_id: i32, content_schedule_id: u64, ad_cnt: i32, cd_cnt: i32, arriving_ad_cnt: i32, arriving_cd_cnt: i32, audit: &AuditContext) -> Result<bool, String>
+    {
+        self.repository.check_in_party(reservation_id, content_schedule_id, ad_cnt, cd_cnt, arriving_ad_cnt, arriving_cd_cnt, audit).await
+    }
+    async fn move_reservation(&self, reservation_id: i32, new_schedule_id: u64, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>
+    {
+        self.repository.move_reservation(reservation_id, new_schedule_id, price, audit).await
     }
-    async fn update_reservaiton_user_count(&self, reservation_id: i32, ad_cnt:i32, cd_cnt:i32) -> Result<(), String>
+    async fn mark_no_shows(&self, cutoff: DateTime<Utc>, audit: &AuditContext) -> Result<u64, String>
     {
-        self.repository.update_reservaiton_user_count(reservation_id, ad_cnt, cd_cnt).await
+        self.repository.mark_no_shows(cutoff, audit).await
     }
 }
 
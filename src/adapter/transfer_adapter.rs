@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{application::port::out::transfer_port::TransferPort, domain::{reservation_history::AuditContext, reservation_transfer::{ReservationTransfer, TransferStatus}}, infra::db::transfer_repository::TransferRepository};
+
+// Adapter Implementation
+pub struct TransferAdapter {
+    repository: Arc<dyn TransferRepository + Send + Sync>,
+}
+
+impl TransferAdapter {
+    pub fn new(repository: Arc<dyn TransferRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl TransferPort for TransferAdapter {
+    async fn save_transfer(&self, transfer: &ReservationTransfer, now: DateTime<Utc>) -> Result<i64, String> {
+        self.repository.save_transfer(transfer, now).await
+    }
+    async fn load_transfer(&self, transfer_id: i64) -> Result<Option<ReservationTransfer>, String> {
+        self.repository.load_transfer(transfer_id).await
+    }
+    async fn load_user_transfers(&self, user_id: &str, limit: i64) -> Result<Vec<ReservationTransfer>, String> {
+        self.repository.load_user_transfers(user_id, limit).await
+    }
+    async fn accept_transfer(&self, transfer_id: i64, max_adult: i32, max_child: i32, now: DateTime<Utc>, audit: &AuditContext) -> Result<(), String> {
+        self.repository.accept_transfer(transfer_id, max_adult, max_child, now, audit).await
+    }
+    async fn close_transfer(&self, transfer_id: i64, status: TransferStatus, now: DateTime<Utc>) -> Result<bool, String> {
+        self.repository.close_transfer(transfer_id, status, now).await
+    }
+}
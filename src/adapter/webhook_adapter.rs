@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{application::port::out::webhook_port::WebhookPort, domain::webhook::{DeliveryOutcome, PendingWebhookDelivery, WebhookDelivery, WebhookSubscription}, infra::db::webhook_repository::WebhookRepository};
+
+// Adapter Implementation
+pub struct WebhookAdapter {
+    repository: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl WebhookAdapter {
+    pub fn new(repository: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl WebhookPort for WebhookAdapter {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<i64, String> {
+        self.repository.save_subscription(subscription).await
+    }
+    async fn update_subscription(&self, subscription: &WebhookSubscription) -> Result<bool, String> {
+        self.repository.update_subscription(subscription).await
+    }
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<bool, String> {
+        self.repository.delete_subscription(subscription_id).await
+    }
+    async fn load_subscription(&self, subscription_id: i64) -> Result<Option<WebhookSubscription>, String> {
+        self.repository.load_subscription(subscription_id).await
+    }
+    async fn load_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String> {
+        self.repository.load_subscriptions().await
+    }
+    async fn enqueue_deliveries(&self, event_id: i64, event_type: &str, body: &str) -> Result<u64, String> {
+        self.repository.enqueue_deliveries(event_id, event_type, body).await
+    }
+    async fn claim_pending_deliveries(&self, now: DateTime<Utc>, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<PendingWebhookDelivery>, String> {
+        self.repository.claim_pending_deliveries(now, limit, lease_until).await
+    }
+    async fn record_delivery_outcome(&self, delivery_id: i64, outcome: &DeliveryOutcome) -> Result<(), String> {
+        self.repository.record_delivery_outcome(delivery_id, outcome).await
+    }
+    async fn load_dead_deliveries(&self, subscription_id: Option<i64>, limit: i64) -> Result<Vec<WebhookDelivery>, String> {
+        self.repository.load_dead_deliveries(subscription_id, limit).await
+    }
+    async fn replay_delivery(&self, delivery_id: i64) -> Result<bool, String> {
+        self.repository.replay_delivery(delivery_id).await
+    }
+}
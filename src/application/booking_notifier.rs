@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+
+use crate::domain::{notification::{Locale, NotificationKind, NotificationMessage, NotificationVars}, reservation::Reservation};
+
+use super::port::out::{notification_port::NotificationPort, user_locale_port::UserLocalePort};
+
+// 예약 변경이 커밋된 뒤 사용자에게 알림 발송
+// 응답이 늦어지지 않도록 백그라운드로 보내고, 발송 실패는 예약 처리 결과에 영향을 주지 않음
+pub struct BookingNotifier {
+    port: Arc<dyn NotificationPort + Send + Sync>,
+    locales: Arc<dyn UserLocalePort + Send + Sync>,
+    timezone: Tz,
+    default_locale: Locale, // 사용자 언어 설정이 없거나 조회 실패 시
+}
+
+impl BookingNotifier {
+    pub fn new(port: Arc<dyn NotificationPort + Send + Sync>, locales: Arc<dyn UserLocalePort + Send + Sync>, timezone: Tz, default_locale: Locale) -> Self {
+        Self { port, locales, timezone, default_locale }
+    }
+
+    pub fn notify(&self, kind: NotificationKind, reservation: &Reservation) {
+        self.notify_user(reservation.user_id.clone(), kind, reservation);
+    }
+
+    // 예약 소유자가 아닌 사용자에게 발송 (양도 요청 등)
+    pub fn notify_user(&self, user_id: String, kind: NotificationKind, reservation: &Reservation) {
+        let schedule = reservation.schedule.as_ref();
+        let vars = NotificationVars {
+            reservation_id: reservation.id,
+            content_title: schedule.and_then(|schedule| schedule.content_title.clone()),
+            start_time: schedule
+                .and_then(|schedule| schedule.start_time)
+                .map(|start_time| start_time.with_timezone(&self.timezone).format("%Y-%m-%d %H:%M").to_string()),
+            ad_cnt: reservation.ad_cnt,
+            cd_cnt: reservation.cd_cnt,
+        };
+        // 같은 변경(예약 / 종류 / 변경 이력 버전)의 재시도는 같은 키 → 알림 서비스에서 중복 제거
+        let dedup_key = format!("{}:{}:{}", kind, reservation.id, reservation.version.unwrap_or(0));
+
+        let port = Arc::clone(&self.port);
+        let locales = Arc::clone(&self.locales);
+        let default_locale = self.default_locale;
+        tokio::spawn(async move {
+            let locale = match locales.load_locale(&user_id).await {
+                Ok(locale) => locale.unwrap_or(default_locale),
+                Err(err) => {
+                    eprintln!("알림 언어 조회 실패 (user_id={}), 기본 언어로 발송: {}", user_id, err);
+                    default_locale
+                }
+            };
+            let message = NotificationMessage::render(user_id, kind, locale, &vars, dedup_key);
+            if let Err(err) = port.notify(&message).await {
+                eprintln!("예약 알림 발송 실패 ({}): {}", message.dedup_key, err);
+            }
+        });
+    }
+}
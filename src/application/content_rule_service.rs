@@ -0,0 +1,62 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::domain::content_rule::ContentBookingRule;
+
+use super::port::{r#in::content_rule_usecase::ContentRuleUseCase, out::content_rule_port::ContentRulePort};
+
+// Use Case Implementation
+pub struct ContentRuleService {
+    rule_port: Arc<dyn ContentRulePort + Send + Sync>,
+}
+
+impl ContentRuleService {
+    pub fn new(rule_port: Arc<dyn ContentRulePort + Send + Sync>) -> Self {
+        Self { rule_port }
+    }
+
+    /// 규칙 입력값을 검증하는 함수 (모든 값은 0 이상)
+    fn validate_rule(&self, rule: &ContentBookingRule) -> Result<(), String> {
+        let values = [
+            ("max_party_size", rule.max_party_size),
+            ("max_adults", rule.max_adults),
+            ("max_children", rule.max_children),
+            ("booking_opens_days_before", rule.booking_opens_days_before),
+            ("booking_closes_minutes_before", rule.booking_closes_minutes_before),
+        ];
+        for (name, value) in values {
+            if value.is_some_and(|v| v < 0) {
+                return Err(format!("{}는 0 이상이어야 합니다.", name));
+            }
+        }
+        if rule.children_only && rule.max_adults.is_some_and(|v| v > 0) {
+            return Err("어린이 전용 컨텐츠에는 성인 최대 인원을 설정할 수 없습니다.".to_string());
+        }
+
+        let mut categories = HashSet::new();
+        for category_rule in &rule.categories {
+            if !categories.insert(category_rule.category) {
+                return Err(format!("권종 규칙이 중복되었습니다: {}", category_rule.category));
+            }
+            if category_rule.max_per_reservation.is_some_and(|v| v < 0) || category_rule.max_per_schedule.is_some_and(|v| v < 0) {
+                return Err(format!("{} 권종 최대 인원은 0 이상이어야 합니다.", category_rule.category));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ContentRuleUseCase for ContentRuleService {
+    // 등록된 규칙이 없으면 제한 없는 기본 규칙 반환
+    async fn show_content_rule(&self, content_id: u64) -> Result<ContentBookingRule, String> {
+        let rule = self.rule_port.load_content_rule(content_id).await?;
+        Ok(rule.unwrap_or(ContentBookingRule { content_id, ..Default::default() }))
+    }
+
+    async fn update_content_rule(&self, rule: ContentBookingRule) -> Result<(), String> {
+        self.validate_rule(&rule)?;
+        self.rule_port.save_content_rule(rule).await
+    }
+}
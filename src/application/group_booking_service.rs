@@ -0,0 +1,257 @@
+use std::{collections::{HashMap, HashSet}, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::{common::{clock::Clock, page::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT}}, domain::{booking_window::{BookingAction, BookingWindow}, group_booking::{GroupQuota, GroupReservation, GroupReservationItem, GroupReservationStatus, Organisation, OrganisationType}, notification::NotificationKind, pricing::ReservationPrice, reservation_history::{AuditChannel, AuditContext}, ticket_category::PartyComposition}};
+
+use super::booking_notifier::BookingNotifier;
+use super::port::{r#in::{group_booking_usecase::GroupBookingUseCase, pricing_usecase::PricingUseCase}, out::{group_booking_port::GroupBookingPort, reservation_load_port::ReservationLoadPort}};
+
+const MAX_GROUP_ITEMS: usize = 20; // 단체 예약 1건에 묶을 수 있는 회차 수
+const MAX_NAME_LEN: usize = 100;
+const MAX_NOTE_LEN: usize = 500;
+
+// Use Case Implementation
+pub struct GroupBookingService {
+    group_port: Arc<dyn GroupBookingPort + Send + Sync>,
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    window: BookingWindow,
+    notifier: Arc<BookingNotifier>,
+    pricing: Arc<dyn PricingUseCase + Send + Sync>,
+}
+
+impl GroupBookingService {
+    pub fn new(
+        group_port: Arc<dyn GroupBookingPort + Send + Sync>,
+        load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        window: BookingWindow,
+        notifier: Arc<BookingNotifier>,
+        pricing: Arc<dyn PricingUseCase + Send + Sync>,
+    ) -> Self {
+        Self { group_port, load_port, clock, window, notifier, pricing }
+    }
+
+    /// 사용자가 속한 활성 단체 조회
+    async fn member_organisation(&self, user_id: &str) -> Result<Organisation, String> {
+        let organisation = self.group_port
+            .load_member_organisation(user_id)
+            .await?
+            .ok_or("단체 계정에 소속된 사용자만 단체 예약을 할 수 있습니다.".to_string())?;
+        if !organisation.active {
+            return Err("비활성화된 단체입니다.".to_string());
+        }
+        Ok(organisation)
+    }
+
+    async fn load_group(&self, group_reservation_id: i64) -> Result<GroupReservation, String> {
+        self.group_port
+            .load_group_reservation(group_reservation_id)
+            .await?
+            .ok_or(format!("단체 예약을 찾을 수 없습니다! ID: {}", group_reservation_id))
+    }
+
+    /// 회차 시작 시간 기준 작업 가능 시간인지 검사 (시작 시간이 없는 회차는 제한 없음)
+    async fn ensure_window_open(&self, action: BookingAction, schedule_id: u64) -> Result<(), String> {
+        let schedule = self.load_port
+            .load_schedule_availability(schedule_id)
+            .await?
+            .ok_or(format!("스케줄을 찾을 수 없습니다! ID: {}", schedule_id))?;
+
+        match schedule.start_time {
+            Some(start_time) => self.window.check(action, start_time, self.clock.now()),
+            None => Ok(()),
+        }
+    }
+
+    /// 회차별 인원 검증 (단체는 개인 인원 제한 대신 단체별 최대 인원 적용)
+    fn validate_items(&self, organisation: &Organisation, items: &[GroupReservationItem]) -> Result<(), String> {
+        if items.is_empty() || items.len() > MAX_GROUP_ITEMS {
+            return Err(format!("단체 예약은 1~{}개 회차로 신청해야 합니다.", MAX_GROUP_ITEMS));
+        }
+        let mut schedule_ids = HashSet::new();
+        for item in items {
+            if !schedule_ids.insert(item.content_schedule_id) {
+                return Err(format!("같은 회차를 중복으로 신청할 수 없습니다. (회차 ID: {})", item.content_schedule_id));
+            }
+            if item.ad_cnt < 0 || item.cd_cnt < 0 || item.party_size() == 0 {
+                return Err(format!("회차 {}의 인원이 올바르지 않습니다.", item.content_schedule_id));
+            }
+            if item.party_size() > organisation.max_party_size {
+                return Err(format!(
+                    "회차별 최대 {}명까지 신청할 수 있습니다. (회차 {}: {}명)",
+                    organisation.max_party_size, item.content_schedule_id, item.party_size()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 회차별 금액 산정 (개인 예약과 같은 견적 규칙, 단체 할인은 회차별 인원 기준으로 적용)
+    async fn price_items(&self, user_id: &str, items: &[GroupReservationItem]) -> Result<HashMap<u64, ReservationPrice>, String> {
+        let mut prices = HashMap::with_capacity(items.len());
+        for item in items {
+            let party = PartyComposition::from_legacy(item.ad_cnt, item.cd_cnt);
+            let quote = self.pricing.quote(user_id, item.content_schedule_id, &party).await?;
+            println!("단체 예약 금액 산정: schedule_id={}, 정가={}, 결제 금액={} {}", item.content_schedule_id, quote.list_price, quote.total_price, quote.currency);
+            prices.insert(item.content_schedule_id, quote.to_reservation_price());
+        }
+        Ok(prices)
+    }
+
+    /// 커밋된 예약을 다시 읽어 신청자에게 알림 발송
+    async fn notify_reservations(&self, kind: NotificationKind, reservation_ids: &[i32]) {
+        for reservation_id in reservation_ids {
+            match self.load_port.load_reservation(*reservation_id).await {
+                Ok(Some(reservation)) => self.notifier.notify(kind, &reservation),
+                Ok(None) => eprintln!("알림 대상 예약을 찾을 수 없습니다! ID: {}", reservation_id),
+                Err(e) => eprintln!("알림 대상 예약 조회 실패 (ID: {}): {}", reservation_id, e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl GroupBookingUseCase for GroupBookingService {
+    async fn create_organisation(&self, name: String, org_type: OrganisationType, max_party_size: i32) -> Result<Organisation, String> {
+        let name = name.trim().to_string();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            return Err(format!("단체 이름은 1~{}자여야 합니다.", MAX_NAME_LEN));
+        }
+        if max_party_size <= 0 {
+            return Err("max_party_size는 1 이상이어야 합니다.".to_string());
+        }
+        let mut organisation = Organisation { id: 0, name, org_type, max_party_size, active: true, created_at: None };
+        organisation.id = self.group_port.save_organisation(&organisation).await?;
+        Ok(organisation)
+    }
+
+    async fn add_organisation_member(&self, organisation_id: i64, user_id: &str) -> Result<(), String> {
+        self.group_port
+            .load_organisation(organisation_id)
+            .await?
+            .ok_or(format!("단체를 찾을 수 없습니다! ID: {}", organisation_id))?;
+        self.group_port.save_organisation_member(organisation_id, user_id).await
+    }
+
+    // 단체 좌석이 설정되지 않은 회차는 0석
+    async fn show_group_quota(&self, content_schedule_id: u64) -> Result<GroupQuota, String> {
+        Ok(self.group_port
+            .load_group_quota(content_schedule_id)
+            .await?
+            .unwrap_or(GroupQuota { content_schedule_id, quota_seats: 0, held_seats: 0 }))
+    }
+
+    async fn update_group_quota(&self, content_schedule_id: u64, quota_seats: i32) -> Result<GroupQuota, String> {
+        if quota_seats < 0 {
+            return Err("quota_seats는 0 이상이어야 합니다.".to_string());
+        }
+        self.group_port.save_group_quota(content_schedule_id, quota_seats).await
+    }
+
+    async fn request_group_reservation(&self, user_id: &str, items: Vec<GroupReservationItem>, note: Option<String>) -> Result<GroupReservation, String> {
+        let organisation = self.member_organisation(user_id).await?;
+        self.validate_items(&organisation, &items)?;
+        if note.as_ref().is_some_and(|note| note.chars().count() > MAX_NOTE_LEN) {
+            return Err(format!("메모는 {}자 이하여야 합니다.", MAX_NOTE_LEN));
+        }
+        for item in &items {
+            self.ensure_window_open(BookingAction::Create, item.content_schedule_id).await?;
+        }
+        // 가격이 등록되지 않은 권종 등 승인 시점에 실패할 신청은 미리 거절
+        self.price_items(user_id, &items).await?;
+
+        let mut group = GroupReservation {
+            id: 0,
+            organisation_id: organisation.id,
+            requested_by: user_id.to_string(),
+            status: GroupReservationStatus::Requested,
+            note,
+            reviewed_by: None,
+            review_note: None,
+            reviewed_at: None,
+            created_at: None,
+            items,
+        };
+        group.id = self.group_port.save_group_reservation(&group).await?;
+        println!("단체 예약 신청: group_reservation_id={}, organisation_id={}, 인원={}", group.id, organisation.id, group.total_party_size());
+        self.load_group(group.id).await
+    }
+
+    // 같은 단체 소속 사용자만 조회 가능
+    async fn show_group_reservation(&self, user_id: &str, group_reservation_id: i64) -> Result<GroupReservation, String> {
+        let organisation = self.member_organisation(user_id).await?;
+        let group = self.load_group(group_reservation_id).await?;
+        if group.organisation_id != organisation.id {
+            return Err(format!("단체 예약을 찾을 수 없습니다! ID: {}", group_reservation_id));
+        }
+        Ok(group)
+    }
+
+    async fn show_organisation_group_reservations(&self, user_id: &str, limit: Option<u32>) -> Result<Vec<GroupReservation>, String> {
+        let organisation = self.member_organisation(user_id).await?;
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            return Err(format!("limit은 1~{} 사이여야 합니다.", MAX_PAGE_LIMIT));
+        }
+        self.group_port.load_group_reservations(None, Some(organisation.id), limit.into()).await
+    }
+
+    async fn show_group_reservations(&self, status: Option<GroupReservationStatus>, limit: Option<u32>) -> Result<Vec<GroupReservation>, String> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            return Err(format!("limit은 1~{} 사이여야 합니다.", MAX_PAGE_LIMIT));
+        }
+        self.group_port.load_group_reservations(status, None, limit.into()).await
+    }
+
+    // 이미 시작된 회차가 포함되어 있으면 승인 불가 (예약 마감 시각은 직원 판단에 맡김)
+    async fn approve_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<String>) -> Result<GroupReservation, String> {
+        let group = self.load_group(group_reservation_id).await?;
+        let now = self.clock.now();
+        for item in &group.items {
+            let schedule = self.load_port
+                .load_schedule_availability(item.content_schedule_id)
+                .await?
+                .ok_or(format!("스케줄을 찾을 수 없습니다! ID: {}", item.content_schedule_id))?;
+            if schedule.start_time.is_some_and(|start_time| start_time <= now) {
+                return Err(format!("이미 시작된 회차가 포함되어 있어 승인할 수 없습니다. (회차 ID: {})", item.content_schedule_id));
+            }
+        }
+
+        // 예약이 생성되는 승인 시점 금액으로 저장 (할인은 신청자 기준)
+        let prices = self.price_items(&group.requested_by, &group.items).await?;
+
+        let audit = AuditContext::new(Some(reviewer_id.to_string()), AuditChannel::Rest);
+        let reservation_ids = self.group_port
+            .approve_group_reservation(group_reservation_id, reviewer_id, review_note.as_deref(), &prices, &audit)
+            .await?;
+        println!("단체 예약 승인: group_reservation_id={}, 예약 {}건, 승인자={}", group_reservation_id, reservation_ids.len(), reviewer_id);
+        self.notify_reservations(NotificationKind::Confirmed, &reservation_ids).await;
+        self.load_group(group_reservation_id).await
+    }
+
+    async fn reject_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<String>) -> Result<GroupReservation, String> {
+        self.group_port
+            .reject_group_reservation(group_reservation_id, reviewer_id, review_note.as_deref())
+            .await?;
+        println!("단체 예약 반려: group_reservation_id={}, 반려자={}", group_reservation_id, reviewer_id);
+        self.load_group(group_reservation_id).await
+    }
+
+    // 승인된 단체 예약은 회차별 취소 마감 시각 이전에만 취소 가능
+    async fn cancel_group_reservation(&self, user_id: &str, group_reservation_id: i64) -> Result<GroupReservation, String> {
+        let group = self.show_group_reservation(user_id, group_reservation_id).await?;
+        if group.status == GroupReservationStatus::Approved {
+            for item in &group.items {
+                self.ensure_window_open(BookingAction::Cancel, item.content_schedule_id).await?;
+            }
+        }
+
+        let audit = AuditContext::new(Some(user_id.to_string()), AuditChannel::Rest);
+        let cancelled_ids = self.group_port.cancel_group_reservation(group_reservation_id, &audit).await?;
+        self.notify_reservations(NotificationKind::Cancelled, &cancelled_ids).await;
+        self.load_group(group_reservation_id).await
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::idempotency::{IdempotencyClaim, IdempotentResponse};
+
+use super::port::{r#in::idempotency_usecase::IdempotencyUseCase, out::idempotency_port::IdempotencyPort};
+
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+// Use Case Implementation
+pub struct IdempotencyService {
+    port: Arc<dyn IdempotencyPort + Send + Sync>,
+    ttl_secs: i64,
+    lease_secs: i64, // 처리 중 키 점유 시간 (처리 도중 프로세스가 죽어도 이 시간 이후 재시도 가능)
+}
+
+impl IdempotencyService {
+    pub fn new(port: Arc<dyn IdempotencyPort + Send + Sync>, ttl_secs: i64, lease_secs: i64) -> Self {
+        Self { port, ttl_secs, lease_secs }
+    }
+}
+
+#[async_trait]
+impl IdempotencyUseCase for IdempotencyService {
+    async fn begin(&self, user_id: &str, key: &str, fingerprint: &str) -> Result<IdempotencyClaim, String> {
+        let key = key.trim();
+        if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return Err(format!("Idempotency-Key는 1~{}자여야 합니다.", MAX_IDEMPOTENCY_KEY_LEN));
+        }
+        self.port.claim_key(user_id, key, fingerprint, self.ttl_secs, self.lease_secs).await
+    }
+
+    async fn complete(&self, user_id: &str, key: &str, response: IdempotentResponse) -> Result<(), String> {
+        self.port.complete_key(user_id, key.trim(), &response).await
+    }
+
+    async fn release(&self, user_id: &str, key: &str) -> Result<(), String> {
+        self.port.release_key(user_id, key.trim()).await
+    }
+}
@@ -1,2 +1,13 @@
 pub mod port;
-pub mod reservation_service;
\ No newline at end of file
+pub mod policy;
+pub mod reservation_service;
+pub mod idempotency_service;
+pub mod content_rule_service;
+pub mod outbox_relay_service;
+pub mod webhook_service;
+pub mod reminder_service;
+pub mod booking_notifier;
+pub mod group_booking_service;
+pub mod transfer_service;
+pub mod pricing_service;
+pub mod payment_service;
\ No newline at end of file
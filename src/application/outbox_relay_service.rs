@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::common::clock::Clock;
+
+use super::port::{r#in::outbox_relay_usecase::OutboxRelayUseCase, out::{event_publish_port::EventPublishPort, outbox_port::OutboxPort}};
+
+// 선점한 이벤트를 이 시간 안에 처리하지 못하면 (인스턴스 중단 등) 다른 인스턴스가 다시 가져감
+const OUTBOX_LEASE_SECS: i64 = 60;
+
+// 재시도 간격 (지수 백오프)
+#[derive(Debug, Clone, Copy)]
+pub struct RetryBackoff {
+    pub base_secs: i64,
+    pub max_secs: i64,
+}
+
+impl RetryBackoff {
+    // attempts 번 실패한 뒤의 대기 시간: base * 2^(attempts - 1), 최대 max
+    pub fn delay(&self, attempts: i32) -> Duration {
+        let exponent = attempts.saturating_sub(1).clamp(0, 30) as u32;
+        let secs = self.base_secs.max(1).saturating_mul(1_i64 << exponent);
+        Duration::seconds(secs.min(self.max_secs.max(1)))
+    }
+}
+
+// Use Case Implementation
+// 최소 1회 전달: 발행 성공 후 완료 기록에 실패하면 같은 이벤트가 다시 전달될 수 있음
+pub struct OutboxRelayService {
+    outbox_port: Arc<dyn OutboxPort + Send + Sync>,
+    publisher: Arc<dyn EventPublishPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    batch_size: i64,
+    backoff: RetryBackoff,
+}
+
+impl OutboxRelayService {
+    pub fn new(
+        outbox_port: Arc<dyn OutboxPort + Send + Sync>,
+        publisher: Arc<dyn EventPublishPort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        batch_size: i64,
+        backoff: RetryBackoff,
+    ) -> Self {
+        Self { outbox_port, publisher, clock, batch_size, backoff }
+    }
+}
+
+#[async_trait]
+impl OutboxRelayUseCase for OutboxRelayService {
+    async fn relay_pending(&self) -> Result<usize, String> {
+        let now = self.clock.now();
+        let events = self.outbox_port
+            .claim_pending(now, self.batch_size.max(1), now + Duration::seconds(OUTBOX_LEASE_SECS))
+            .await?;
+
+        let mut published = 0;
+        for event in &events {
+            match self.publisher.publish(event).await {
+                Ok(()) => {
+                    self.outbox_port.mark_published(event.event_id).await?;
+                    published += 1;
+                },
+                Err(err) => {
+                    let next_attempt_at = self.clock.now() + self.backoff.delay(event.attempts + 1);
+                    println!("이벤트 전달 실패 (event_id={}, 시도 {}회): {}", event.event_id, event.attempts + 1, err);
+                    self.outbox_port.mark_failed(event.event_id, &err, next_attempt_at).await?;
+                },
+            }
+        }
+
+        if published > 0 {
+            println!("예약 이벤트 {}건 전달 완료", published);
+        }
+        Ok(published)
+    }
+}
@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{common::clock::Clock, domain::{notification::NotificationKind, payment::{Payment, PaymentConfirmation, PaymentRequest, PaymentResult, PaymentStatus, RefundPolicy}, reservation::Reservation, reservation_history::AuditContext}};
+
+use super::booking_notifier::BookingNotifier;
+use super::port::{r#in::payment_usecase::PaymentUseCase, out::{payment_port::PaymentPort, payment_record_port::PaymentRecordPort, reservation_load_port::ReservationLoadPort}};
+
+// Use Case Implementation
+pub struct PaymentService {
+    record_port: Arc<dyn PaymentRecordPort + Send + Sync>,
+    gateway: Arc<dyn PaymentPort + Send + Sync>,
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    notifier: Arc<BookingNotifier>,
+    refund_policy: RefundPolicy,
+    timeout: Duration,
+    batch_size: i64,
+}
+
+impl PaymentService {
+    pub fn new(
+        record_port: Arc<dyn PaymentRecordPort + Send + Sync>,
+        gateway: Arc<dyn PaymentPort + Send + Sync>,
+        load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        notifier: Arc<BookingNotifier>,
+        refund_policy: RefundPolicy,
+        timeout_minutes: i64,
+        batch_size: i64,
+    ) -> Self {
+        Self { record_port, gateway, load_port, clock, notifier, refund_policy, timeout: Duration::minutes(timeout_minutes), batch_size }
+    }
+
+    /// 본인 예약만 조회 (다른 사용자 예약은 존재 여부도 노출하지 않음)
+    async fn load_own_reservation(&self, user_id: &str, reservation_id: i32) -> Result<Reservation, String> {
+        match self.load_port.load_reservation(reservation_id).await? {
+            Some(reservation) if reservation.user_id == user_id => Ok(reservation),
+            _ => Err(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id)),
+        }
+    }
+
+    /// 결제 해제 후 알림 발송 (이미 처리된 예약이면 알림 없음)
+    async fn release_and_notify(&self, reservation_id: i32, status: PaymentStatus, reason: &str) -> Result<bool, String> {
+        let released = self.record_port
+            .release_unpaid_reservation(reservation_id, status, Some(reason), &AuditContext::system())
+            .await?;
+        if released {
+            println!("💳 결제 미완료 예약 해제 (예약 ID: {}, 사유: {})", reservation_id, reason);
+            if let Some(reservation) = self.load_port.load_reservation(reservation_id).await? {
+                self.notifier.notify(NotificationKind::PaymentExpired, &reservation);
+            }
+        }
+        Ok(released)
+    }
+
+    /// 게이트웨이 환불 후 환불 완료 처리 (실패 시 REFUND_PENDING 으로 남아 재시도)
+    async fn refund(&self, payment: &Payment, amount: i64) -> Result<(), String> {
+        let provider_payment_id = payment.provider_payment_id
+            .as_deref()
+            .ok_or(format!("게이트웨이 결제 ID가 없는 결제입니다! ID: {}", payment.id))?;
+        self.gateway.refund(provider_payment_id, amount).await?;
+        self.record_port.complete_refund(payment.id, self.clock.now()).await?;
+        println!("💳 환불 완료 (결제 ID: {}, 예약 ID: {}, 금액: {} {})", payment.id, payment.reservation_id, amount, payment.currency);
+
+        if let Some(reservation) = self.load_port.load_reservation(payment.reservation_id).await? {
+            self.notifier.notify_user(payment.user_id.clone(), NotificationKind::Refunded, &reservation);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PaymentUseCase for PaymentService {
+    fn payment_due_at(&self) -> DateTime<Utc> {
+        self.clock.now() + self.timeout
+    }
+
+    fn callback_signature_header(&self) -> &'static str {
+        self.gateway.signature_header()
+    }
+
+    async fn start_payment(&self, user_id: &str, reservation_id: i32) -> Result<Payment, String> {
+        let reservation = self.load_own_reservation(user_id, reservation_id).await?;
+        if !reservation.is_pending_payment() {
+            return Err("결제 대기 중인 예약이 아닙니다.".to_string());
+        }
+        if reservation.payment_due_at.is_some_and(|due_at| due_at <= self.clock.now()) {
+            return Err("결제 기한이 지난 예약입니다. 다시 예약해 주세요.".to_string());
+        }
+
+        // 진행 중인 결제가 있으면 재사용 (결제창 중복 생성 방지)
+        let mut payment = match self.record_port.load_latest_payment(reservation_id).await? {
+            Some(payment) if payment.status == PaymentStatus::Pending => payment,
+            _ => {
+                let price = reservation.price.as_ref().ok_or("결제 금액이 없는 예약입니다.".to_string())?;
+                let mut payment = Payment {
+                    id: 0,
+                    reservation_id,
+                    user_id: reservation.user_id.clone(),
+                    provider: self.gateway.provider().to_string(),
+                    provider_payment_id: None,
+                    checkout_url: None,
+                    amount: price.total_price,
+                    currency: price.currency.clone(),
+                    status: PaymentStatus::Pending,
+                    refund_amount: None,
+                    failure_reason: None,
+                    created_at: None,
+                    paid_at: None,
+                    refunded_at: None,
+                };
+                payment.id = self.record_port.save_payment(&payment).await?;
+                payment
+            }
+        };
+
+        if payment.checkout_url.is_none() {
+            let checkout = self.gateway
+                .create_checkout(&PaymentRequest {
+                    payment_id: payment.id,
+                    reservation_id,
+                    user_id: payment.user_id.clone(),
+                    amount: payment.amount,
+                    currency: payment.currency.clone(),
+                })
+                .await?;
+            self.record_port.attach_checkout(payment.id, &checkout.provider_payment_id, &checkout.checkout_url).await?;
+            payment.provider_payment_id = Some(checkout.provider_payment_id);
+            payment.checkout_url = Some(checkout.checkout_url);
+        }
+        Ok(payment)
+    }
+
+    async fn show_payment(&self, user_id: &str, reservation_id: i32) -> Result<Payment, String> {
+        self.load_own_reservation(user_id, reservation_id).await?;
+        self.record_port
+            .load_latest_payment(reservation_id)
+            .await?
+            .ok_or(format!("결제 내역이 없습니다! 예약 ID: {}", reservation_id))
+    }
+
+    async fn handle_callback(&self, body: &str, signature: Option<&str>) -> Result<(), String> {
+        let callback = self.gateway.parse_callback(body, signature)?;
+        let payment = self.record_port
+            .load_payment_by_provider_id(self.gateway.provider(), &callback.provider_payment_id)
+            .await?
+            .ok_or(format!("결제를 찾을 수 없습니다! ID: {}", callback.provider_payment_id))?;
+
+        // 결제 금액이 다르면 예약을 확정하지 않음 (게이트웨이 쪽 조작 / 잘못된 결제창)
+        if callback.result == PaymentResult::Paid && callback.amount != Some(payment.amount) {
+            eprintln!("💳 결제 금액 불일치 (결제 ID: {}, 예상 {}, 콜백 {:?})", payment.id, payment.amount, callback.amount);
+            return Err("결제 금액이 일치하지 않습니다.".to_string());
+        }
+
+        match callback.result {
+            PaymentResult::Paid => match self.record_port.confirm_payment(payment.id, self.clock.now(), &AuditContext::system()).await? {
+                PaymentConfirmation::Confirmed => {
+                    println!("💳 결제 완료 (결제 ID: {}, 예약 ID: {})", payment.id, payment.reservation_id);
+                    if let Some(reservation) = self.load_port.load_reservation(payment.reservation_id).await? {
+                        self.notifier.notify(NotificationKind::Confirmed, &reservation);
+                    }
+                }
+                PaymentConfirmation::AlreadyProcessed => {
+                    println!("💳 이미 처리된 결제 콜백 (결제 ID: {})", payment.id);
+                }
+                // 예약이 이미 해제된 뒤 결제됨 → 전액 환불 (실패 시 재시도 작업에서 처리)
+                PaymentConfirmation::Late => {
+                    println!("💳 결제 기한 이후 결제 → 전액 환불 (결제 ID: {}, 예약 ID: {})", payment.id, payment.reservation_id);
+                    if let Err(e) = self.refund(&payment, payment.amount).await {
+                        eprintln!("환불 처리 실패 (결제 ID: {}): {}", payment.id, e);
+                    }
+                }
+            },
+            PaymentResult::Failed(reason) => {
+                if payment.status == PaymentStatus::Pending {
+                    self.release_and_notify(payment.reservation_id, PaymentStatus::Failed, &reason).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn release_unpaid(&self, reservation: &Reservation, audit: &AuditContext) -> Result<(), String> {
+        let released = self.record_port
+            .release_unpaid_reservation(reservation.id, PaymentStatus::Expired, None, audit)
+            .await?;
+        if !released {
+            return Err("이미 결제가 완료되었거나 취소된 예약입니다.".to_string());
+        }
+        Ok(())
+    }
+
+    async fn refund_for_cancellation(&self, reservation: &Reservation) -> Result<Option<i64>, String> {
+        let payment = match self.record_port.load_latest_payment(reservation.id).await? {
+            Some(payment) if payment.status == PaymentStatus::Paid => payment,
+            _ => return Ok(None),
+        };
+
+        let start_time = self.load_port
+            .load_schedule_availability(reservation.content_schedule_id)
+            .await?
+            .and_then(|schedule| schedule.start_time);
+        let amount = self.refund_policy.refund_amount(payment.amount, start_time, self.clock.now());
+        if amount == 0 {
+            println!("💳 환불 기간이 지나 환불 없음 (결제 ID: {}, 예약 ID: {})", payment.id, reservation.id);
+            return Ok(Some(0));
+        }
+
+        if !self.record_port.request_refund(payment.id, amount).await? {
+            return Ok(None);
+        }
+        self.refund(&payment, amount).await?;
+        Ok(Some(amount))
+    }
+
+    async fn expire_unpaid(&self) -> Result<u64, String> {
+        let mut processed = 0;
+
+        let overdue = self.record_port.load_overdue_reservation_ids(self.clock.now(), self.batch_size).await?;
+        for reservation_id in overdue {
+            match self.release_and_notify(reservation_id, PaymentStatus::Expired, "결제 기한 초과").await {
+                Ok(true) => processed += 1,
+                Ok(false) => {}
+                Err(e) => eprintln!("결제 기한 처리 실패 (예약 ID: {}): {}", reservation_id, e),
+            }
+        }
+
+        // 게이트웨이 환불이 실패했던 결제 재시도
+        for payment in self.record_port.load_refund_pending(self.batch_size).await? {
+            let amount = payment.refund_amount.unwrap_or(payment.amount);
+            match self.refund(&payment, amount).await {
+                Ok(_) => processed += 1,
+                Err(e) => eprintln!("환불 재시도 실패 (결제 ID: {}): {}", payment.id, e),
+            }
+        }
+        Ok(processed)
+    }
+}
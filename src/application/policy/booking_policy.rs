@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{application::port::out::{content_rule_port::ContentRulePort, reservation_load_port::ReservationLoadPort}, common::clock::Clock, domain::{policy_violation::PolicyViolation, reservation::Reservation, ticket_category::PartyComposition}};
+
+use super::rules::{CategoryMixRule, ContentRule, HeadCountRule, OnePerContentRule, PartySizeRule, TimeSlotRule};
+
+// 예약 정책 평가에 필요한 입력값
+#[derive(Debug, Clone)]
+pub struct BookingContext {
+    pub user_id: String,
+    pub schedule_id: u64,
+    pub party: PartyComposition,       // 권종별 인원 (성인 / 어린이 한도는 권종별 합계로 검사)
+    pub max_adult: i32,                // User-Service 기준 성인 한도
+    pub max_child: i32,                // User-Service 기준 어린이 한도
+    pub current: Option<Reservation>,  // 수정 대상 기존 예약 (신규 예약이면 None)
+}
+
+// 개별 예약 규칙
+#[async_trait]
+pub trait BookingRule: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String>;
+}
+
+// 예약 규칙들을 한 곳에서 평가하는 정책 엔진
+pub struct BookingPolicy {
+    rules: Vec<Arc<dyn BookingRule>>,
+}
+
+impl BookingPolicy {
+    pub fn new(rules: Vec<Arc<dyn BookingRule>>) -> Self {
+        Self { rules }
+    }
+
+    // 기본 규칙: 인원 범위 / 권종 동반 규칙 / 사용자 인원 한도 / 컨텐츠당 1건 / 동일 시간대 중복 금지 / 컨텐츠별 규칙
+    pub fn with_default_rules(
+        load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+        rule_port: Arc<dyn ContentRulePort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        min_party_size: i32,
+        max_party_size: i32,
+    ) -> Self {
+        Self::new(vec![
+            Arc::new(PartySizeRule::new(min_party_size, max_party_size)),
+            Arc::new(CategoryMixRule),
+            Arc::new(HeadCountRule::new(Arc::clone(&load_port))),
+            Arc::new(OnePerContentRule::new(Arc::clone(&load_port))),
+            Arc::new(TimeSlotRule::new(Arc::clone(&load_port))),
+            Arc::new(ContentRule::new(Arc::clone(&load_port), Arc::clone(&rule_port), Arc::clone(&clock))),
+        ])
+    }
+
+    pub fn with_rule(mut self, rule: Arc<dyn BookingRule>) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    // 모든 규칙을 평가하고 위반된 규칙을 전부 반환 (비어 있으면 예약 가능)
+    pub async fn evaluate(&self, ctx: &BookingContext) -> Result<Vec<PolicyViolation>, String> {
+        let mut violations = Vec::new();
+        for rule in &self.rules {
+            if let Some(violation) = rule.evaluate(ctx).await? {
+                println!("예약 정책 위반: {}", violation);
+                violations.push(violation);
+            }
+        }
+        Ok(violations)
+    }
+}
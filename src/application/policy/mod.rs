@@ -0,0 +1,2 @@
+pub mod booking_policy;
+pub mod rules;
\ No newline at end of file
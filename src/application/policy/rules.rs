@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::{application::port::out::{content_rule_port::ContentRulePort, reservation_load_port::ReservationLoadPort}, common::clock::Clock, domain::policy_violation::PolicyViolation};
+
+use super::booking_policy::{BookingContext, BookingRule};
+
+// 1회 예약 인원 범위 (최소 / 최대)
+pub struct PartySizeRule {
+    min_party_size: i32,
+    max_party_size: i32,
+}
+
+impl PartySizeRule {
+    pub fn new(min_party_size: i32, max_party_size: i32) -> Self {
+        Self { min_party_size, max_party_size }
+    }
+}
+
+#[async_trait]
+impl BookingRule for PartySizeRule {
+    fn name(&self) -> &'static str {
+        "PARTY_SIZE"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        if ctx.party.has_negative() {
+            return Ok(Some(PolicyViolation::new(self.name(), "인원 수는 음수일 수 없습니다.")));
+        }
+
+        let party_size = ctx.party.total();
+        if party_size < self.min_party_size {
+            return Ok(Some(PolicyViolation::new(self.name(), format!("최소 {}명 이상 예약해야 합니다. (요청 {}명)", self.min_party_size, party_size))));
+        }
+        if party_size > self.max_party_size {
+            return Ok(Some(PolicyViolation::new(self.name(), format!("1회 최대 {}명까지 예약할 수 있습니다. (요청 {}명)", self.max_party_size, party_size))));
+        }
+        Ok(None)
+    }
+}
+
+// 권종 간 동반 규칙 (유아는 보호자 동반, 동반자는 장애인 1명당 1명)
+pub struct CategoryMixRule;
+
+#[async_trait]
+impl BookingRule for CategoryMixRule {
+    fn name(&self) -> &'static str {
+        "TICKET_CATEGORY"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        let reasons = ctx.party.companion_violations();
+        if reasons.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(PolicyViolation::new(self.name(), reasons.join(", "))))
+        }
+    }
+}
+
+// 사용자별 인원 한도 (User-Service adCnt / cdCnt, 동일 컨텐츠 기존 예약 포함, 권종은 성인 / 어린이 합계로 집계)
+pub struct HeadCountRule {
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+}
+
+impl HeadCountRule {
+    pub fn new(load_port: Arc<dyn ReservationLoadPort + Send + Sync>) -> Self {
+        Self { load_port }
+    }
+}
+
+#[async_trait]
+impl BookingRule for HeadCountRule {
+    fn name(&self) -> &'static str {
+        "USER_HEAD_COUNT"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        let limits = self.load_port.check_reservation_for_user_count(&ctx.user_id, ctx.schedule_id).await?;
+        let mut booked_adults = limits.total_adults.unwrap_or(0).max(0);
+        let mut booked_children = limits.total_children.unwrap_or(0).max(0);
+
+        // 수정 중인 예약의 기존 인원은 새 인원으로 대체되므로 제외
+        if let Some(current) = &ctx.current {
+            booked_adults = (booked_adults - current.ad_cnt).max(0);
+            booked_children = (booked_children - current.cd_cnt).max(0);
+        }
+
+        let total_adults = booked_adults + ctx.party.adults();
+        let total_children = booked_children + ctx.party.children();
+
+        let mut reasons = Vec::new();
+        if total_adults > ctx.max_adult {
+            reasons.push(format!("성인 수 초과 ({}명 > {}명)", total_adults, ctx.max_adult));
+        }
+        if total_children > ctx.max_child {
+            reasons.push(format!("어린이 수 초과 ({}명 > {}명)", total_children, ctx.max_child));
+        }
+
+        if reasons.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(PolicyViolation::new(self.name(), reasons.join(", "))))
+        }
+    }
+}
+
+// 동일 컨텐츠는 사용자당 1건만 예약 가능
+pub struct OnePerContentRule {
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+}
+
+impl OnePerContentRule {
+    pub fn new(load_port: Arc<dyn ReservationLoadPort + Send + Sync>) -> Self {
+        Self { load_port }
+    }
+}
+
+#[async_trait]
+impl BookingRule for OnePerContentRule {
+    fn name(&self) -> &'static str {
+        "ONE_PER_CONTENT"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        // 기존 예약 수정 시에는 자기 자신이 중복으로 잡히므로 검사하지 않음
+        if ctx.current.is_some() {
+            return Ok(None);
+        }
+
+        if self.load_port.check_user_reservation_for_content(&ctx.user_id, ctx.schedule_id).await? {
+            return Ok(Some(PolicyViolation::new(self.name(), "이미 동일 컨텐츠에 대한 예약이 있습니다.")));
+        }
+        Ok(None)
+    }
+}
+
+// 동일 시간대에 다른 예약이 있으면 예약 불가
+pub struct TimeSlotRule {
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+}
+
+impl TimeSlotRule {
+    pub fn new(load_port: Arc<dyn ReservationLoadPort + Send + Sync>) -> Self {
+        Self { load_port }
+    }
+}
+
+#[async_trait]
+impl BookingRule for TimeSlotRule {
+    fn name(&self) -> &'static str {
+        "NO_OVERLAPPING_TIME_SLOT"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        // 같은 스케줄의 인원만 변경하는 경우 자기 자신과 겹치므로 검사하지 않음
+        if ctx.current.as_ref().is_some_and(|current| current.content_schedule_id == ctx.schedule_id) {
+            return Ok(None);
+        }
+
+        if self.load_port.check_schedule_and_reservation(&ctx.user_id, ctx.schedule_id).await? {
+            return Ok(Some(PolicyViolation::new(self.name(), "동일 시간대에 이미 예약이 있습니다.")));
+        }
+        Ok(None)
+    }
+}
+
+// 컨텐츠별 예약 규칙 (CONTENT_BOOKING_RULE: 어린이 전용 / 1회 최대 인원 / 예약 가능 기간, CONTENT_CATEGORY_RULE: 권종별 1회 최대 인원)
+pub struct ContentRule {
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    rule_port: Arc<dyn ContentRulePort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ContentRule {
+    pub fn new(load_port: Arc<dyn ReservationLoadPort + Send + Sync>, rule_port: Arc<dyn ContentRulePort + Send + Sync>, clock: Arc<dyn Clock>) -> Self {
+        Self { load_port, rule_port, clock }
+    }
+}
+
+#[async_trait]
+impl BookingRule for ContentRule {
+    fn name(&self) -> &'static str {
+        "CONTENT_RULE"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        let Some(schedule) = self.load_port.load_schedule_availability(ctx.schedule_id).await? else {
+            return Ok(Some(PolicyViolation::new(self.name(), format!("스케줄을 찾을 수 없습니다! ID: {}", ctx.schedule_id))));
+        };
+        // 규칙이 등록되지 않은 컨텐츠는 제한 없음
+        let Some(rule) = self.rule_port.load_content_rule(schedule.content_id).await? else {
+            return Ok(None);
+        };
+
+        let ad_cnt = ctx.party.adults();
+        let cd_cnt = ctx.party.children();
+        let mut reasons = Vec::new();
+        if rule.children_only && ad_cnt > 0 {
+            reasons.push("어린이 전용 회차로 성인은 예약할 수 없습니다.".to_string());
+        }
+        if let Some(max_party_size) = rule.max_party_size {
+            if ctx.party.total() > max_party_size {
+                reasons.push(format!("이 컨텐츠는 1회 최대 {}명까지 예약할 수 있습니다.", max_party_size));
+            }
+        }
+        if let Some(max_adults) = rule.max_adults {
+            if ad_cnt > max_adults {
+                reasons.push(format!("이 컨텐츠는 성인 최대 {}명까지 예약할 수 있습니다.", max_adults));
+            }
+        }
+        if let Some(max_children) = rule.max_children {
+            if cd_cnt > max_children {
+                reasons.push(format!("이 컨텐츠는 어린이 최대 {}명까지 예약할 수 있습니다.", max_children));
+            }
+        }
+        // 회차별 권종 최대 인원은 예약 저장 시 회차 행을 잠근 뒤 확인
+        for (category, count) in ctx.party.iter() {
+            let max_per_reservation = rule.category_rule(category).and_then(|category_rule| category_rule.max_per_reservation);
+            match max_per_reservation {
+                Some(0) if count > 0 => reasons.push(format!("이 컨텐츠는 {} 권종을 예약할 수 없습니다.", category.label())),
+                Some(max) if count > max => reasons.push(format!("이 컨텐츠는 {} 최대 {}명까지 예약할 수 있습니다.", category.label(), max)),
+                _ => {},
+            }
+        }
+
+        // 예약 가능 기간 (시작 N일 전 오픈 ~ 시작 N분 전 마감)
+        if let Some(start_time) = schedule.start_time {
+            let now = self.clock.now();
+            if let Some(opens_at) = rule.booking_opens_at(start_time) {
+                if now < opens_at {
+                    reasons.push(format!("예약은 {}부터 가능합니다.", opens_at.to_rfc3339()));
+                }
+            }
+            if let Some(closes_at) = rule.booking_closes_at(start_time) {
+                if now >= closes_at {
+                    reasons.push(format!("예약이 마감되었습니다. (마감 {})", closes_at.to_rfc3339()));
+                }
+            }
+        }
+
+        if reasons.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(PolicyViolation::new(self.name(), reasons.join(", "))))
+        }
+    }
+}
+
+// 최근 N일 동안 노쇼가 한도 이상인 사용자는 예약 제한
+pub struct NoShowRule {
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    max_no_shows: i64,
+    lookback_days: i64,
+}
+
+impl NoShowRule {
+    pub fn new(load_port: Arc<dyn ReservationLoadPort + Send + Sync>, clock: Arc<dyn Clock>, max_no_shows: i64, lookback_days: i64) -> Self {
+        Self { load_port, clock, max_no_shows, lookback_days }
+    }
+}
+
+#[async_trait]
+impl BookingRule for NoShowRule {
+    fn name(&self) -> &'static str {
+        "NO_SHOW_LIMIT"
+    }
+
+    async fn evaluate(&self, ctx: &BookingContext) -> Result<Option<PolicyViolation>, String> {
+        // 기존 예약 인원 변경은 제한하지 않음
+        if ctx.current.is_some() {
+            return Ok(None);
+        }
+
+        let since = self.clock.now() - Duration::days(self.lookback_days);
+        let no_shows = self.load_port.count_user_no_shows(&ctx.user_id, since).await?;
+        if no_shows >= self.max_no_shows {
+            return Ok(Some(PolicyViolation::new(self.name(), format!("최근 {}일간 노쇼 {}회로 예약이 제한되었습니다.", self.lookback_days, no_shows))));
+        }
+        Ok(None)
+    }
+}
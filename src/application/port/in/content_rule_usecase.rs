@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::content_rule::ContentBookingRule;
+
+#[async_trait]
+pub trait ContentRuleUseCase: Send + Sync {
+    async fn show_content_rule(&self, content_id: u64) -> Result<ContentBookingRule, String>;
+    async fn update_content_rule(&self, rule: ContentBookingRule) -> Result<(), String>;
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::group_booking::{GroupQuota, GroupReservation, GroupReservationItem, GroupReservationStatus, Organisation, OrganisationType};
+
+#[async_trait]
+pub trait GroupBookingUseCase: Send + Sync {
+    async fn create_organisation(&self, name: String, org_type: OrganisationType, max_party_size: i32) -> Result<Organisation, String>;
+    async fn add_organisation_member(&self, organisation_id: i64, user_id: &str) -> Result<(), String>;
+    async fn show_group_quota(&self, content_schedule_id: u64) -> Result<GroupQuota, String>;
+    async fn update_group_quota(&self, content_schedule_id: u64, quota_seats: i32) -> Result<GroupQuota, String>;
+    // 단체 소속 사용자의 단체 예약 신청 (직원 승인 전까지 단체 좌석만 확보)
+    async fn request_group_reservation(&self, user_id: &str, items: Vec<GroupReservationItem>, note: Option<String>) -> Result<GroupReservation, String>;
+    async fn show_group_reservation(&self, user_id: &str, group_reservation_id: i64) -> Result<GroupReservation, String>;
+    async fn show_organisation_group_reservations(&self, user_id: &str, limit: Option<u32>) -> Result<Vec<GroupReservation>, String>;
+    async fn show_group_reservations(&self, status: Option<GroupReservationStatus>, limit: Option<u32>) -> Result<Vec<GroupReservation>, String>;
+    async fn approve_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<String>) -> Result<GroupReservation, String>;
+    async fn reject_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<String>) -> Result<GroupReservation, String>;
+    async fn cancel_group_reservation(&self, user_id: &str, group_reservation_id: i64) -> Result<GroupReservation, String>;
+}
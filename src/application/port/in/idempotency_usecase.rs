@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::idempotency::{IdempotencyClaim, IdempotentResponse};
+
+#[async_trait]
+pub trait IdempotencyUseCase: Send + Sync {
+    async fn begin(&self, user_id: &str, key: &str, fingerprint: &str) -> Result<IdempotencyClaim, String>;
+    async fn complete(&self, user_id: &str, key: &str, response: IdempotentResponse) -> Result<(), String>;
+    async fn release(&self, user_id: &str, key: &str) -> Result<(), String>;
+}
\ No newline at end of file
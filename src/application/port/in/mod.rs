@@ -1 +1,10 @@
-pub mod reservation_usecase;
\ No newline at end of file
+pub mod reservation_usecase;
+pub mod idempotency_usecase;
+pub mod content_rule_usecase;
+pub mod outbox_relay_usecase;
+pub mod webhook_usecase;
+pub mod reminder_usecase;
+pub mod group_booking_usecase;
+pub mod transfer_usecase;
+pub mod pricing_usecase;
+pub mod payment_usecase;
\ No newline at end of file
@@ -0,0 +1,7 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OutboxRelayUseCase: Send + Sync {
+    // 전달 대기 중인 이벤트를 한 번 전달하고 성공 건수 반환
+    async fn relay_pending(&self) -> Result<usize, String>;
+}
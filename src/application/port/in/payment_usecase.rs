@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{payment::Payment, reservation::Reservation, reservation_history::AuditContext};
+
+#[async_trait]
+pub trait PaymentUseCase: Send + Sync {
+    // 지금 생성되는 유료 예약의 결제 기한
+    fn payment_due_at(&self) -> DateTime<Utc>;
+    // 게이트웨이 콜백 서명 헤더 이름
+    fn callback_signature_header(&self) -> &'static str;
+    // 예약 소유자가 결제창 요청 (진행 중인 결제가 있으면 그대로 반환)
+    async fn start_payment(&self, user_id: &str, reservation_id: i32) -> Result<Payment, String>;
+    async fn show_payment(&self, user_id: &str, reservation_id: i32) -> Result<Payment, String>;
+    // 게이트웨이 결제 결과 반영 (같은 콜백이 여러 번 와도 한 번만 처리)
+    async fn handle_callback(&self, body: &str, signature: Option<&str>) -> Result<(), String>;
+    // 결제 전 예약 취소 (좌석 반환)
+    async fn release_unpaid(&self, reservation: &Reservation, audit: &AuditContext) -> Result<(), String>;
+    // 결제 완료 예약 취소 후 환불 정책에 따라 환불 (환불 금액 반환, 결제 기록이 없으면 None)
+    async fn refund_for_cancellation(&self, reservation: &Reservation) -> Result<Option<i64>, String>;
+    // 결제 기한이 지난 예약 취소 + 실패한 환불 재시도 (처리 건수 반환)
+    async fn expire_unpaid(&self) -> Result<u64, String>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{pricing::{ContentPrice, PriceQuote, PricingDiscount}, ticket_category::PartyComposition};
+
+#[async_trait]
+pub trait PricingUseCase: Send + Sync {
+    // 예약 전 금액 견적 (예약 생성 / 인원 변경 / 회차 이동 시 같은 방식으로 산정해 예약에 저장)
+    async fn quote(&self, user_id: &str, schedule_id: u64, party: &PartyComposition) -> Result<PriceQuote, String>;
+    async fn show_content_prices(&self, content_id: u64) -> Result<Vec<ContentPrice>, String>;
+    async fn update_content_prices(&self, content_id: u64, prices: Vec<ContentPrice>) -> Result<(), String>;
+    async fn show_discounts(&self) -> Result<Vec<PricingDiscount>, String>;
+    async fn create_discount(&self, discount: PricingDiscount) -> Result<PricingDiscount, String>;
+    async fn deactivate_discount(&self, discount_id: i64) -> Result<(), String>;
+    async fn register_member(&self, user_id: &str, expires_at: Option<DateTime<Utc>>) -> Result<(), String>;
+    async fn remove_member(&self, user_id: &str) -> Result<(), String>;
+}
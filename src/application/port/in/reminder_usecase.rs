@@ -0,0 +1,7 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ReminderUseCase: Send + Sync {
+    // 시작 N분 전이 된 회차에 알림을 한 번 발송하고 발송한 회차 수 반환
+    async fn send_due_reminders(&self) -> Result<usize, String>;
+}
@@ -1,15 +1,26 @@
 use async_trait::async_trait;
 
-use crate::domain::reservation::Reservation;
+use crate::{common::page::{Page, PageRequest}, domain::{check_in::CheckInOutcome, policy_violation::{BookingOutcome, PolicyViolation}, reservation::Reservation, reservation_history::{AuditContext, ReservationHistory}, schedule_availability::ScheduleAvailability, ticket_category::PartyComposition}, dto::{reservation_query_dto::ReservationFilter, schedule_filter_dto::ScheduleAvailabilityFilter}};
 
 #[async_trait]
 pub trait ReservationUseCase: Send + Sync {
-    async fn create_reservation(&self, reservation: Reservation) -> Result<(), String>;
-    async fn show_reservation(&self, reservation_id: i32 )->  Result<Reservation, String>;
-    async fn show_user_reservations(&self, user_id:&str) -> Result<Vec<Reservation>,String>; 
-    async fn show_today_reservations(&self) -> Result<Vec<Reservation>,String>;   
+    // 예약 정책(사용자별 한도 max_adult / max_child 포함)을 검사한 뒤 생성 (REST / gRPC 공통)
+    async fn create_reservation(&self, reservation: Reservation, max_adult: i32, max_child: i32, audit: &AuditContext) -> Result<BookingOutcome, String>;
+    // 예약이 없거나 본인 예약이 아니면 Ok(None) (조회 실패와 구분)
+    async fn show_reservation(&self, user_id: &str, reservation_id: i32 )->  Result<Option<Reservation>, String>;
+    async fn show_reservation_history(&self, reservation_id: i32) -> Result<Vec<ReservationHistory>, String>;
+    async fn show_user_reservations(&self, user_id:&str, page: PageRequest) -> Result<Page<Reservation>,String>;
+    async fn show_today_reservations(&self, page: PageRequest) -> Result<Page<Reservation>,String>;
+    async fn search_reservations(&self, filter: ReservationFilter, page: PageRequest) -> Result<Page<Reservation>,String>;
     async fn check_reservation(&self, user_id: String,schedule_id: u64, ad_cnt: i32, cd_cnt: i32, max_adult:i32,max_child:i32) -> Result<bool, String>; 
-    async fn use_reservation(&self, reservation_id: i32 ) -> Result<(), String>;
-    async fn cancel_reservation(&self, reservation_id: i32) -> Result<(), String>;
-    async fn update_reservation(&self, reservation_id: i32, ad_cnt: i32, cd_cnt: i32, max_adult: i32, max_child: i32) -> Result<(), String>;
+    async fn evaluate_booking(&self, user_id: String, schedule_id: u64, party: PartyComposition, max_adult: i32, max_child: i32) -> Result<Vec<PolicyViolation>, String>;
+    async fn check_in(&self, token: &str, schedule_id: u64, ad_cnt: Option<i32>, cd_cnt: Option<i32>, audit: &AuditContext) -> Result<CheckInOutcome, String>;
+    async fn mark_no_shows(&self) -> Result<u64, String>;
+    async fn cancel_reservation(&self, user_id: &str, reservation_id: i32, audit: &AuditContext) -> Result<(), String>;
+    async fn cancel_reservation_by_staff(&self, reservation_id: i32, audit: &AuditContext) -> Result<(), String>;
+    async fn update_reservation(&self, user_id: &str, reservation_id: i32, party: PartyComposition, max_adult: i32, max_child: i32, audit: &AuditContext) -> Result<(), String>;
+    async fn move_reservation(&self, user_id: &str, reservation_id: i32, new_schedule_id: u64, max_adult: i32, max_child: i32, audit: &AuditContext) -> Result<(), String>;
+    // 스케줄이 없으면 Ok(None) (조회 실패와 구분)
+    async fn show_schedule_availability(&self, user_id: String, schedule_id: u64, max_adult: i32, max_child: i32) -> Result<Option<ScheduleAvailability>, String>;
+    async fn show_schedules_availability(&self, filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String>;
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::reservation_transfer::ReservationTransfer;
+
+#[async_trait]
+pub trait TransferUseCase: Send + Sync {
+    // 예약 소유자가 다른 사용자에게 양도 요청
+    async fn request_transfer(&self, user_id: &str, reservation_id: i32, to_user_id: &str) -> Result<ReservationTransfer, String>;
+    // 받는 사용자가 수락 (받는 사용자 기준 예약 정책을 다시 검사)
+    async fn accept_transfer(&self, user_id: &str, transfer_id: i64, max_adult: i32, max_child: i32) -> Result<ReservationTransfer, String>;
+    async fn decline_transfer(&self, user_id: &str, transfer_id: i64) -> Result<ReservationTransfer, String>;
+    async fn cancel_transfer(&self, user_id: &str, transfer_id: i64) -> Result<ReservationTransfer, String>;
+    async fn show_user_transfers(&self, user_id: &str, limit: Option<u32>) -> Result<Vec<ReservationTransfer>, String>;
+}
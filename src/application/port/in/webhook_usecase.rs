@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::{reservation_event::ReservationEventType, webhook::{WebhookDelivery, WebhookSubscription}};
+
+#[async_trait]
+pub trait WebhookUseCase: Send + Sync {
+    async fn create_subscription(&self, url: String, secret: String, event_types: Vec<ReservationEventType>) -> Result<WebhookSubscription, String>;
+    async fn update_subscription(&self, subscription_id: i64, url: String, secret: Option<String>, event_types: Vec<ReservationEventType>, active: bool) -> Result<WebhookSubscription, String>;
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<(), String>;
+    async fn show_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String>;
+    async fn show_dead_deliveries(&self, subscription_id: Option<i64>, limit: Option<u32>) -> Result<Vec<WebhookDelivery>, String>;
+    async fn replay_delivery(&self, delivery_id: i64) -> Result<(), String>;
+    // 전달 대기 중인 웹훅을 한 번 전송하고 처리 건수 반환
+    async fn deliver_pending(&self) -> Result<usize, String>;
+}
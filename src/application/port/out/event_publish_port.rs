@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::reservation_event::OutboxEvent;
+
+// 예약 이벤트 외부 전달 (HTTP / gRPC / 로그 등 구현 교체 가능)
+#[async_trait]
+pub trait EventPublishPort: Send + Sync {
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String>;
+}
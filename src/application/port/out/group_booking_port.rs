@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::domain::{group_booking::{GroupQuota, GroupReservation, GroupReservationStatus, Organisation}, pricing::ReservationPrice, reservation_history::AuditContext};
+
+#[async_trait]
+pub trait GroupBookingPort: Send + Sync {
+    async fn save_organisation(&self, organisation: &Organisation) -> Result<i64, String>;
+    async fn load_organisation(&self, organisation_id: i64) -> Result<Option<Organisation>, String>;
+    async fn save_organisation_member(&self, organisation_id: i64, user_id: &str) -> Result<(), String>;
+    async fn load_member_organisation(&self, user_id: &str) -> Result<Option<Organisation>, String>;
+    async fn save_group_quota(&self, content_schedule_id: u64, quota_seats: i32) -> Result<GroupQuota, String>;
+    async fn load_group_quota(&self, content_schedule_id: u64) -> Result<Option<GroupQuota>, String>;
+    async fn save_group_reservation(&self, group: &GroupReservation) -> Result<i64, String>;
+    async fn load_group_reservation(&self, group_reservation_id: i64) -> Result<Option<GroupReservation>, String>;
+    async fn load_group_reservations(&self, status: Option<GroupReservationStatus>, organisation_id: Option<i64>, limit: i64) -> Result<Vec<GroupReservation>, String>;
+    async fn approve_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<&str>, prices: &HashMap<u64, ReservationPrice>, audit: &AuditContext) -> Result<Vec<i32>, String>;
+    async fn reject_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<&str>) -> Result<(), String>;
+    async fn cancel_group_reservation(&self, group_reservation_id: i64, audit: &AuditContext) -> Result<Vec<i32>, String>;
+}
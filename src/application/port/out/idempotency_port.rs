@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::idempotency::{IdempotencyClaim, IdempotentResponse};
+
+#[async_trait]
+pub trait IdempotencyPort: Send + Sync {
+    async fn claim_key(&self, user_id: &str, key: &str, fingerprint: &str, ttl_secs: i64, lease_secs: i64) -> Result<IdempotencyClaim, String>;
+    async fn complete_key(&self, user_id: &str, key: &str, response: &IdempotentResponse) -> Result<(), String>;
+    async fn release_key(&self, user_id: &str, key: &str) -> Result<(), String>;
+}
\ No newline at end of file
@@ -1,2 +1,17 @@
 pub mod reservation_load_port;
-pub mod reservation_save_port;
\ No newline at end of file
+pub mod reservation_save_port;
+pub mod idempotency_port;
+pub mod content_rule_port;
+pub mod outbox_port;
+pub mod event_publish_port;
+pub mod webhook_port;
+pub mod webhook_send_port;
+pub mod reminder_port;
+pub mod push_notification_port;
+pub mod notification_port;
+pub mod group_booking_port;
+pub mod transfer_port;
+pub mod pricing_port;
+pub mod payment_port;
+pub mod payment_record_port;
+pub mod user_locale_port;
\ No newline at end of file
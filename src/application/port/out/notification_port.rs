@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::notification::NotificationMessage;
+
+// 예약 알림 발송 (gRPC 알림 서비스 / SMTP 이메일 / 로그 구현 교체 가능)
+#[async_trait]
+pub trait NotificationPort: Send + Sync {
+    async fn notify(&self, message: &NotificationMessage) -> Result<(), String>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::reservation_event::OutboxEvent;
+
+#[async_trait]
+pub trait OutboxPort: Send + Sync {
+    async fn claim_pending(&self, now: DateTime<Utc>, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxEvent>, String>;
+    async fn mark_published(&self, event_id: i64) -> Result<(), String>;
+    async fn mark_failed(&self, event_id: i64, error: &str, next_attempt_at: DateTime<Utc>) -> Result<(), String>;
+}
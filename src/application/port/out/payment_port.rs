@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::payment::{PaymentCallback, PaymentCheckout, PaymentRequest};
+
+// 결제 게이트웨이 (PG사별 구현 교체 가능, 로컬 개발 / 테스트는 mock 구현)
+#[async_trait]
+pub trait PaymentPort: Send + Sync {
+    // PAYMENT.provider 에 기록되는 게이트웨이 이름
+    fn provider(&self) -> &'static str;
+    // 콜백 서명이 담긴 HTTP 헤더 이름
+    fn signature_header(&self) -> &'static str;
+    async fn create_checkout(&self, request: &PaymentRequest) -> Result<PaymentCheckout, String>;
+    // 같은 결제에 대한 환불 재요청은 게이트웨이에서 한 번만 처리되어야 함
+    async fn refund(&self, provider_payment_id: &str, amount: i64) -> Result<(), String>;
+    // 콜백 서명 검증 + 게이트웨이 형식 payload → 결제 결과
+    fn parse_callback(&self, body: &str, signature: Option<&str>) -> Result<PaymentCallback, String>;
+}
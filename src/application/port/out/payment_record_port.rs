@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{payment::{Payment, PaymentConfirmation, PaymentStatus}, reservation_history::AuditContext};
+
+#[async_trait]
+pub trait PaymentRecordPort: Send + Sync {
+    async fn save_payment(&self, payment: &Payment) -> Result<i64, String>;
+    async fn attach_checkout(&self, payment_id: i64, provider_payment_id: &str, checkout_url: &str) -> Result<(), String>;
+    async fn load_payment_by_provider_id(&self, provider: &str, provider_payment_id: &str) -> Result<Option<Payment>, String>;
+    async fn load_latest_payment(&self, reservation_id: i32) -> Result<Option<Payment>, String>;
+    async fn confirm_payment(&self, payment_id: i64, now: DateTime<Utc>, audit: &AuditContext) -> Result<PaymentConfirmation, String>;
+    async fn release_unpaid_reservation(&self, reservation_id: i32, payment_status: PaymentStatus, failure_reason: Option<&str>, audit: &AuditContext) -> Result<bool, String>;
+    async fn load_overdue_reservation_ids(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, String>;
+    async fn request_refund(&self, payment_id: i64, amount: i64) -> Result<bool, String>;
+    async fn complete_refund(&self, payment_id: i64, now: DateTime<Utc>) -> Result<(), String>;
+    async fn load_refund_pending(&self, limit: i64) -> Result<Vec<Payment>, String>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::pricing::{ContentPrice, PricingDiscount};
+
+#[async_trait]
+pub trait PricingPort: Send + Sync {
+    async fn load_content_prices(&self, content_id: u64) -> Result<Vec<ContentPrice>, String>;
+    async fn save_content_prices(&self, content_id: u64, prices: &[ContentPrice]) -> Result<(), String>;
+    async fn load_applicable_discounts(&self, content_id: u64) -> Result<Vec<PricingDiscount>, String>;
+    async fn load_discounts(&self) -> Result<Vec<PricingDiscount>, String>;
+    async fn save_discount(&self, discount: &PricingDiscount) -> Result<i64, String>;
+    async fn deactivate_discount(&self, discount_id: i64) -> Result<bool, String>;
+    async fn is_member(&self, user_id: &str, now: DateTime<Utc>) -> Result<bool, String>;
+    async fn save_membership(&self, user_id: &str, expires_at: Option<DateTime<Utc>>) -> Result<(), String>;
+    async fn delete_membership(&self, user_id: &str) -> Result<bool, String>;
+}
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::reminder::PushNotification;
+
+// 푸시 알림 발송 (알림 gRPC 서비스 / 로그 구현 교체 가능)
+#[async_trait]
+pub trait PushNotificationPort: Send + Sync {
+    async fn send_push(&self, notification: &PushNotification) -> Result<(), String>;
+}
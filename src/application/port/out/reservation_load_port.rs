@@ -1,15 +1,20 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
-use crate::{domain::reservation::Reservation, dto::reservation_chk_dto::ReservationLimits};
+use crate::{common::page::{Page, PageRequest}, domain::{reservation::Reservation, reservation_history::ReservationHistory, schedule_availability::ScheduleAvailability}, dto::{reservation_chk_dto::ReservationLimits, reservation_query_dto::ReservationFilter, schedule_filter_dto::ScheduleAvailabilityFilter}};
 
 #[async_trait]
 pub trait ReservationLoadPort: Send + Sync {
-    async fn load_reservation(&self, reservation_id: i32) -> Option<Reservation>;
-    async fn load_reservations_by_user(&self, user_id: &str) -> Result<Vec<Reservation>, String>;
-    async fn load_reservations_by_date(&self, start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Result<Vec<Reservation>,String>;
-    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64)-> Result<Vec<Reservation>, String>;  
+    async fn load_reservation(&self, reservation_id: i32) -> Result<Option<Reservation>, String>;
+    async fn load_reservations_by_user(&self, user_id: &str, page: PageRequest) -> Result<Page<Reservation>, String>;
+    async fn load_reservations_by_date(&self, start_time: DateTime<Utc>, end_time: DateTime<Utc>, page: PageRequest) -> Result<Page<Reservation>,String>;
+    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64, page: PageRequest)-> Result<Page<Reservation>, String>;  
+    async fn load_reservation_history(&self, reservation_id: i32) -> Result<Vec<ReservationHistory>, String>;
     async fn check_reservation_for_user_count(&self, user_id: &str, schedule_id: u64) -> Result<ReservationLimits, String>;
     async fn check_schedule_and_reservation(&self, user_id: &str, schedule_id: u64) -> Result<bool,String>;
     async fn check_user_reservation_for_content(&self, user_id: &str, schedule_id: u64) -> Result<bool, String>;
+    async fn load_schedule_availability(&self, schedule_id: u64) -> Result<Option<ScheduleAvailability>, String>;
+    async fn load_schedules_availability(&self, filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String>;
+    async fn count_user_no_shows(&self, user_id: &str, since: DateTime<Utc>) -> Result<i64, String>;
+    async fn search_reservations(&self, filter: ReservationFilter, page: PageRequest) -> Result<Page<Reservation>, String>;
 }
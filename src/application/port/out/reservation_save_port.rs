@@ -1,10 +1,14 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
-use crate::domain::reservation::{Reservation, ReservationStatus};
+use crate::domain::{reservation::{Reservation, ReservationStatus}, pricing::ReservationPrice, reservation_history::AuditContext, ticket_category::PartyComposition};
 
 #[async_trait]
 pub trait ReservationSavePort: Send + Sync {
-    async fn save_reservation(&self, reservation: Reservation) -> Result<(), String>;
-    async fn update_status(&self, reservation_id: i32, status: ReservationStatus) -> Result<(), String>;
-    async fn update_reservaiton_user_count(&self, reservation_id: i32, ad_cnt:i32, cd_cnt:i32) -> Result<(), String>;
+    async fn save_reservation(&self, reservation: Reservation, audit: &AuditContext) -> Result<i32, String>;
+    async fn update_status(&self, reservation_id: i32, status: ReservationStatus, audit: &AuditContext) -> Result<(), String>;
+    async fn update_reservaiton_user_count(&self, reservation_id: i32, party: &PartyComposition, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>;
+    async fn check_in_party(&self, reservation_id: i32, content_schedule_id: u64, ad_cnt: i32, cd_cnt: i32, arriving_ad_cnt: i32, arriving_cd_cnt: i32, audit: &AuditContext) -> Result<bool, String>;
+    async fn move_reservation(&self, reservation_id: i32, new_schedule_id: u64, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>;
+    async fn mark_no_shows(&self, cutoff: DateTime<Utc>, audit: &AuditContext) -> Result<u64, String>;
 }
\ No newline at end of file
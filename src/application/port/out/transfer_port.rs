@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{reservation_history::AuditContext, reservation_transfer::{ReservationTransfer, TransferStatus}};
+
+#[async_trait]
+pub trait TransferPort: Send + Sync {
+    async fn save_transfer(&self, transfer: &ReservationTransfer, now: DateTime<Utc>) -> Result<i64, String>;
+    async fn load_transfer(&self, transfer_id: i64) -> Result<Option<ReservationTransfer>, String>;
+    async fn load_user_transfers(&self, user_id: &str, limit: i64) -> Result<Vec<ReservationTransfer>, String>;
+    async fn accept_transfer(&self, transfer_id: i64, max_adult: i32, max_child: i32, now: DateTime<Utc>, audit: &AuditContext) -> Result<(), String>;
+    async fn close_transfer(&self, transfer_id: i64, status: TransferStatus, now: DateTime<Utc>) -> Result<bool, String>;
+}
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::notification::Locale;
+
+// 사용자별 알림 언어 조회 (User-Service 설정, 없으면 None → 기본 언어)
+#[async_trait]
+pub trait UserLocalePort: Send + Sync {
+    async fn load_locale(&self, user_id: &str) -> Result<Option<Locale>, String>;
+}
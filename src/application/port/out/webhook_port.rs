@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::webhook::{DeliveryOutcome, PendingWebhookDelivery, WebhookDelivery, WebhookSubscription};
+
+#[async_trait]
+pub trait WebhookPort: Send + Sync {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<i64, String>;
+    async fn update_subscription(&self, subscription: &WebhookSubscription) -> Result<bool, String>;
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<bool, String>;
+    async fn load_subscription(&self, subscription_id: i64) -> Result<Option<WebhookSubscription>, String>;
+    async fn load_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String>;
+    async fn enqueue_deliveries(&self, event_id: i64, event_type: &str, body: &str) -> Result<u64, String>;
+    async fn claim_pending_deliveries(&self, now: DateTime<Utc>, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<PendingWebhookDelivery>, String>;
+    async fn record_delivery_outcome(&self, delivery_id: i64, outcome: &DeliveryOutcome) -> Result<(), String>;
+    async fn load_dead_deliveries(&self, subscription_id: Option<i64>, limit: i64) -> Result<Vec<WebhookDelivery>, String>;
+    async fn replay_delivery(&self, delivery_id: i64) -> Result<bool, String>;
+}
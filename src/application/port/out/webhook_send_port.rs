@@ -0,0 +1,7 @@
+use async_trait::async_trait;
+
+// 웹훅 HTTP 전송 결과 (연결 실패 / 타임아웃은 Err)
+#[async_trait]
+pub trait WebhookSendPort: Send + Sync {
+    async fn send(&self, url: &str, headers: &[(&str, String)], body: &str) -> Result<u16, String>;
+}
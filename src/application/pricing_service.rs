@@ -0,0 +1,120 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::{common::clock::Clock, domain::{pricing::{ContentPrice, DiscountType, PriceQuote, PricingConditions, PricingDiscount}, ticket_category::PartyComposition}};
+
+use super::port::{r#in::pricing_usecase::PricingUseCase, out::{pricing_port::PricingPort, reservation_load_port::ReservationLoadPort}};
+
+// Use Case Implementation
+pub struct PricingService {
+    pricing_port: Arc<dyn PricingPort + Send + Sync>,
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    timezone: Tz,
+    currency: String,
+}
+
+impl PricingService {
+    pub fn new(
+        pricing_port: Arc<dyn PricingPort + Send + Sync>,
+        load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        timezone: Tz,
+        currency: String,
+    ) -> Self {
+        Self { pricing_port, load_port, clock, timezone, currency }
+    }
+
+    /// 할인 규칙 입력값 검증 (종류별 필수 값)
+    fn validate_discount(&self, discount: &PricingDiscount) -> Result<(), String> {
+        if discount.rate_percent <= 0 || discount.rate_percent > 100 {
+            return Err("rate_percent는 1~100 사이여야 합니다.".to_string());
+        }
+        match discount.discount_type {
+            DiscountType::Member => Ok(()),
+            DiscountType::Group => match discount.min_party_size {
+                Some(min_party_size) if min_party_size > 0 => Ok(()),
+                _ => Err("단체 할인은 min_party_size(1 이상)가 필요합니다.".to_string()),
+            },
+            DiscountType::TimeOfDay => match (discount.start_hour, discount.end_hour) {
+                (Some(start), Some(end)) if start < 24 && end < 24 && start != end => Ok(()),
+                _ => Err("시간대 할인은 서로 다른 start_hour / end_hour(0~23)가 필요합니다.".to_string()),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl PricingUseCase for PricingService {
+    async fn quote(&self, user_id: &str, schedule_id: u64, party: &PartyComposition) -> Result<PriceQuote, String> {
+        if party.has_negative() || party.total() == 0 {
+            return Err("인원은 1명 이상이어야 합니다.".to_string());
+        }
+        let schedule = self.load_port
+            .load_schedule_availability(schedule_id)
+            .await?
+            .ok_or(format!("스케줄을 찾을 수 없습니다! ID: {}", schedule_id))?;
+
+        let prices = self.pricing_port.load_content_prices(schedule.content_id).await?;
+        let discounts = self.pricing_port.load_applicable_discounts(schedule.content_id).await?;
+        let conditions = PricingConditions {
+            is_member: self.pricing_port.is_member(user_id, self.clock.now()).await?,
+            party_size: party.total(),
+            local_start_hour: schedule.start_time.map(|start_time| start_time.with_timezone(&self.timezone).hour()),
+        };
+
+        PriceQuote::calculate(schedule.content_id, schedule_id, party, &prices, &discounts, &conditions, &self.currency)
+    }
+
+    async fn show_content_prices(&self, content_id: u64) -> Result<Vec<ContentPrice>, String> {
+        self.pricing_port.load_content_prices(content_id).await
+    }
+
+    async fn update_content_prices(&self, content_id: u64, prices: Vec<ContentPrice>) -> Result<(), String> {
+        let mut categories = HashSet::new();
+        for price in &prices {
+            if !categories.insert(price.category) {
+                return Err(format!("권종 가격이 중복되었습니다: {}", price.category));
+            }
+            if price.unit_price < 0 {
+                return Err(format!("{} 권종 단가는 0 이상이어야 합니다.", price.category));
+            }
+        }
+        self.pricing_port.save_content_prices(content_id, &prices).await
+    }
+
+    async fn show_discounts(&self) -> Result<Vec<PricingDiscount>, String> {
+        self.pricing_port.load_discounts().await
+    }
+
+    async fn create_discount(&self, mut discount: PricingDiscount) -> Result<PricingDiscount, String> {
+        self.validate_discount(&discount)?;
+        discount.active = true;
+        discount.id = self.pricing_port.save_discount(&discount).await?;
+        Ok(discount)
+    }
+
+    async fn deactivate_discount(&self, discount_id: i64) -> Result<(), String> {
+        if !self.pricing_port.deactivate_discount(discount_id).await? {
+            return Err(format!("활성화된 할인을 찾을 수 없습니다! ID: {}", discount_id));
+        }
+        Ok(())
+    }
+
+    async fn register_member(&self, user_id: &str, expires_at: Option<DateTime<Utc>>) -> Result<(), String> {
+        if expires_at.is_some_and(|expires_at| expires_at <= self.clock.now()) {
+            return Err("만료일은 현재 시각 이후여야 합니다.".to_string());
+        }
+        self.pricing_port.save_membership(user_id, expires_at).await
+    }
+
+    async fn remove_member(&self, user_id: &str) -> Result<(), String> {
+        if !self.pricing_port.delete_membership(user_id).await? {
+            return Err(format!("회원을 찾을 수 없습니다! ID: {}", user_id));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use chrono_tz::Tz;
+use sha2::{Digest, Sha256};
+
+use crate::{common::clock::Clock, domain::reminder::{PushNotification, ReminderTarget, ReminderTemplate}};
+
+use super::port::{r#in::reminder_usecase::ReminderUseCase, out::{push_notification_port::PushNotificationPort, reminder_port::ReminderPort}};
+
+// 발송 선점 후 이 시간이 지나도 완료되지 않으면 (인스턴스 중단 등) 다시 발송 대상
+const REMINDER_LEASE_SECS: i64 = 5 * 60;
+
+// Use Case Implementation
+pub struct ReminderService {
+    reminder_port: Arc<dyn ReminderPort + Send + Sync>,
+    notifier: Arc<dyn PushNotificationPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    timezone: Tz,
+    minutes_before: i64,
+    template: ReminderTemplate,
+}
+
+impl ReminderService {
+    pub fn new(
+        reminder_port: Arc<dyn ReminderPort + Send + Sync>,
+        notifier: Arc<dyn PushNotificationPort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        timezone: Tz,
+        minutes_before: i64,
+        template: ReminderTemplate,
+    ) -> Self {
+        Self { reminder_port, notifier, clock, timezone, minutes_before, template }
+    }
+
+    // 선점한 예약의 사용자에게 보낼 알림 (dedup_key 는 회차 / 시점 / 예약 id 목록으로 결정 → 같은 대상 재시도는 같은 키)
+    fn build_notification(&self, target: &ReminderTarget, reservation_ids: &[i32]) -> PushNotification {
+        let start_time = target.start_time.with_timezone(&self.timezone).format("%H:%M").to_string(); // 영업 시간대 기준
+        let content_title = target.content_title.as_deref().unwrap_or("예약하신 컨텐츠");
+        let (title, body) = self.template.render(content_title, &start_time, self.minutes_before);
+
+        let mut user_ids: Vec<String> = target.recipients
+            .iter()
+            .filter(|recipient| reservation_ids.contains(&recipient.reservation_id))
+            .map(|recipient| recipient.user_id.clone())
+            .collect();
+        user_ids.sort();
+        user_ids.dedup();
+
+        let mut digest = Sha256::new();
+        for reservation_id in reservation_ids {
+            digest.update(reservation_id.to_be_bytes());
+        }
+        let batch: String = digest.finalize().iter().take(8).map(|byte| format!("{:02x}", byte)).collect();
+
+        PushNotification {
+            user_ids,
+            title,
+            body,
+            data: vec![
+                ("type".to_string(), "SCHEDULE_REMINDER".to_string()),
+                ("content_schedule_id".to_string(), target.content_schedule_id.to_string()),
+                ("content_id".to_string(), target.content_id.to_string()),
+                ("start_time".to_string(), target.start_time.to_rfc3339()),
+            ],
+            dedup_key: format!("reminder:{}:{}:{}", target.content_schedule_id, self.minutes_before, batch),
+        }
+    }
+}
+
+#[async_trait]
+impl ReminderUseCase for ReminderService {
+    async fn send_due_reminders(&self) -> Result<usize, String> {
+        let now = self.clock.now();
+        let stale_before = now - Duration::seconds(REMINDER_LEASE_SECS);
+        let targets = self.reminder_port.load_due_reminders(now, self.minutes_before, stale_before).await?;
+
+        let mut sent = 0;
+        for target in &targets {
+            // 다른 인스턴스가 먼저 선점한 예약은 제외
+            let reservation_ids: Vec<i32> = target.recipients.iter().map(|recipient| recipient.reservation_id).collect();
+            let claimed = self.reminder_port.claim_reminders(&reservation_ids, self.minutes_before, stale_before).await?;
+            if claimed.is_empty() {
+                continue;
+            }
+
+            match self.notifier.send_push(&self.build_notification(target, &claimed)).await {
+                Ok(()) => {
+                    self.reminder_port.complete_reminders(&claimed, self.minutes_before).await?;
+                    sent += 1;
+                },
+                Err(err) => {
+                    eprintln!("회차 알림 발송 실패 (content_schedule_id={}): {}", target.content_schedule_id, err);
+                    self.reminder_port.release_reminders(&claimed, self.minutes_before).await?;
+                },
+            }
+        }
+
+        if sent > 0 {
+            println!("회차 시작 {}분 전 알림 {}건 발송", self.minutes_before, sent);
+        }
+        Ok(sent)
+    }
+}
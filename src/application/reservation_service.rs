@@ -1,164 +1,476 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::Duration;
+use chrono_tz::Tz;
 
-use crate::{common::date::get_today_start_end_date, domain::reservation::{Reservation, ReservationStatus}, dto::reservation_chk_dto::ReservationLimits};
+use crate::{common::{clock::Clock, date::{get_day_start_end_date, get_today_start_end_date}, page::{Page, PageRequest}, ticket::{TicketClaims, TicketSigner}}, domain::{booking_window::{BookingAction, BookingWindow}, check_in::{CheckInOutcome, CheckInWindow}, notification::NotificationKind, policy_violation::{BookingOutcome, PolicyViolation}, reservation::{Reservation, ReservationStatus}, reservation_history::{AuditChannel, AuditContext, ReservationHistory}, schedule_availability::ScheduleAvailability, pricing::ReservationPrice, ticket_category::PartyComposition}, dto::{reservation_query_dto::ReservationFilter, schedule_filter_dto::ScheduleAvailabilityFilter}};
 
-use super::port::{r#in::reservation_usecase::ReservationUseCase, out::{reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort}};
+use super::booking_notifier::BookingNotifier;
+use super::policy::booking_policy::{BookingContext, BookingPolicy};
+use super::port::{r#in::{payment_usecase::PaymentUseCase, pricing_usecase::PricingUseCase, reservation_usecase::ReservationUseCase}, out::{reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort}};
+
+// 단체 좌석을 사용하는 예약은 개별 변경 시 회차 인원 카운터가 어긋나므로 단체 예약 단위로만 처리
+const GROUP_BOOKING_LOCKED: &str = "단체 예약으로 생성된 예약은 단체 예약에서 취소 / 변경해야 합니다.";
 
 // Use Case Implementation
 pub struct ReservationService {
     save_port: Arc<dyn ReservationSavePort + Send + Sync>,
     load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    policy: Arc<BookingPolicy>,
+    clock: Arc<dyn Clock>,
+    timezone: Tz,
+    window: BookingWindow,
+    ticket_signer: Option<Arc<TicketSigner>>, // 없으면 티켓 발급 / 티켓 입장 안 함
+    check_in_window: CheckInWindow,
+    notifier: Arc<BookingNotifier>,
+    pricing: Arc<dyn PricingUseCase + Send + Sync>,
+    payment: Arc<dyn PaymentUseCase + Send + Sync>,
 }
 
 impl ReservationService {
-    pub fn new(save_port: Arc<dyn ReservationSavePort + Send + Sync>, load_port: Arc<dyn ReservationLoadPort + Send + Sync>) -> Self {
-        Self { save_port, load_port }
-    }
-    fn is_reservation_available(&self, count: ReservationLimits, max_adult: i32, max_child: i32) -> bool {
-        // 로그: 입력값 출력
-        println!(
-            "is_reservation_available 호출됨 | total_adults: {:?}, total_children: {:?}, max_adult: {}, max_child: {}",
-            count.total_adults, count.total_children, max_adult, max_child
-        );
-    
-        // 타입 변환 후 값
-        let total_adults = count.total_adults.map(|v| v as i64).unwrap_or(-1);
-        let total_children = count.total_children.map(|v| v as i64).unwrap_or(-1);
-    
-        // 로그: 변환된 값 출력
-        println!(
-            "변환된 값 | total_adults: {}, total_children: {}",
-            total_adults, total_children
-        );
-    
-        // 인원 초과 체크
-        if total_adults > max_adult.into() {
-            println!("예약 불가: 성인 수 초과 ({}명 > {}명)", total_adults, max_adult);
-            return false;
+    pub fn new(
+        save_port: Arc<dyn ReservationSavePort + Send + Sync>,
+        load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+        policy: Arc<BookingPolicy>,
+        clock: Arc<dyn Clock>,
+        timezone: Tz,
+        window: BookingWindow,
+        ticket_signer: Option<Arc<TicketSigner>>,
+        check_in_window: CheckInWindow,
+        notifier: Arc<BookingNotifier>,
+        pricing: Arc<dyn PricingUseCase + Send + Sync>,
+        payment: Arc<dyn PaymentUseCase + Send + Sync>,
+    ) -> Self {
+        Self { save_port, load_port, policy, clock, timezone, window, ticket_signer, check_in_window, notifier, pricing, payment }
+    }
+
+    /// 예약자 / 회차 / 권종 구성 기준 금액 산정 (예약에 저장되는 금액)
+    async fn price_for(&self, user_id: &str, schedule_id: u64, party: &PartyComposition) -> Result<ReservationPrice, String> {
+        let quote = self.pricing.quote(user_id, schedule_id, party).await?;
+        println!("예약 금액 산정: schedule_id={}, 정가={}, 결제 금액={} {}", schedule_id, quote.list_price, quote.total_price, quote.currency);
+        Ok(quote.to_reservation_price())
+    }
+
+    /// 차액 결제 / 부분 환불은 지원하지 않으므로 결제 금액이 달라지는 변경은 취소 후 다시 예약해야 함
+    fn ensure_same_charge(charged: i64, price: &ReservationPrice) -> Result<(), String> {
+        if price.total_price != charged {
+            return Err(format!(
+                "결제 금액이 달라지는 변경은 할 수 없습니다. 취소 후 다시 예약해 주세요. (결제 금액 {}, 변경 후 금액 {} {})",
+                charged, price.total_price, price.currency
+            ));
         }
-        if total_children > max_child.into() {
-            println!("예약 불가: 어린이 수 초과 ({}명 > {}명)", total_children, max_child);
-            return false;
+        Ok(())
+    }
+
+    /// 커밋된 예약을 다시 읽어 알림 발송 (회차 정보 / 변경된 인원 반영)
+    async fn notify_committed(&self, kind: NotificationKind, reservation_id: i32) {
+        match self.load_port.load_reservation(reservation_id).await {
+            Ok(Some(reservation)) => self.notifier.notify(kind, &reservation),
+            Ok(None) => eprintln!("알림 대상 예약을 찾을 수 없습니다! ID: {}", reservation_id),
+            Err(e) => eprintln!("알림 대상 예약 조회 실패 (ID: {}): {}", reservation_id, e),
         }
-        true
     }
-    /// 예약 입력값을 검증하는 함수
-    fn validate_reservation_input_count(&self, ad_cnt: i32, cd_cnt: i32, max_adult: i32, max_child: i32) -> bool {
-        // 성인 인원 초과 검사
-        if ad_cnt > max_adult && ad_cnt > 0{
-            return  false;
+
+    /// 본인 예약만 조회하는 함수 (다른 사용자 예약은 존재 여부도 노출하지 않음)
+    async fn load_own_reservation(&self, user_id: &str, reservation_id: i32) -> Result<Reservation, String> {
+        self.load_port
+            .load_reservation(reservation_id)
+            .await?
+            .filter(|reservation| reservation.user_id == user_id)
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id))
+    }
+
+    /// 조회한 예약을 취소하고 환불 / 알림 처리 (예약자 본인 / 직원 취소 공통)
+    async fn cancel_loaded(&self, reservation: Reservation, audit: &AuditContext) -> Result<(), String> {
+        if reservation.is_group_booking() {
+            return Err(GROUP_BOOKING_LOCKED.to_string());
+        }
+        if reservation.is_partially_checked_in() {
+            return Err("입장이 시작된 예약은 취소할 수 없습니다.".to_string());
+        }
+        self.ensure_window_open(BookingAction::Cancel, reservation.content_schedule_id).await?;
+
+        // 결제 전 예약은 좌석 반환 후 결제 종료
+        if reservation.is_pending_payment() {
+            self.payment.release_unpaid(&reservation, audit).await?;
+            self.notifier.notify(NotificationKind::Cancelled, &reservation);
+            return Ok(());
         }
 
-        // 어린이 인원 초과 검사
-        if cd_cnt > max_child && cd_cnt > 0{
-            return false;
+        let cancel_status = ReservationStatus::Cancelled;
+        self.save_port.update_status(reservation.id, cancel_status, audit).await?;
+        // 취소는 이미 반영되었으므로 환불 실패는 기록만 하고 재시도 작업에 맡김
+        if let Err(e) = self.payment.refund_for_cancellation(&reservation).await {
+            eprintln!("환불 처리 실패 (예약 ID: {}): {}", reservation.id, e);
+        }
+        self.notifier.notify(NotificationKind::Cancelled, &reservation);
+        Ok(())
+    }
+
+    /// 회차 시작 시간 기준으로 작업 가능 시간인지 검사하는 함수
+    async fn ensure_window_open(&self, action: BookingAction, schedule_id: u64) -> Result<(), String> {
+        let schedule = self.load_port
+            .load_schedule_availability(schedule_id)
+            .await?
+            .ok_or(format!("스케줄을 찾을 수 없습니다! ID: {}", schedule_id))?;
+
+        match schedule.start_time {
+            Some(start_time) => self.window.check(action, start_time, self.clock.now()),
+            None => Ok(()), // 시작 시간이 없는 스케줄은 제한 없음
         }
-        true
+    }
+
+    /// 조회 시점 기준 취소 / 사용 / 수정 가능 여부를 채우는 함수
+    fn with_flags(&self, mut reservation: Reservation) -> Reservation {
+        reservation.flags = Some(reservation.flags(&self.window, self.clock.now()));
+        reservation
+    }
+
+    /// 가능 여부와 입장 티켓을 채우는 함수 (예약자 본인 조회에만 사용)
+    fn with_ticket(&self, reservation: Reservation) -> Reservation {
+        let mut reservation = self.with_flags(reservation);
+        let usable = reservation.flags.is_some_and(|flags| flags.usable);
+        let start_time = reservation.schedule.as_ref().and_then(|schedule| schedule.start_time);
+
+        // 사용 가능한 예약만 티켓 발급 (입장 마감 시각에 만료)
+        if let (true, Some(start_time), Some(ticket_signer)) = (usable, start_time, &self.ticket_signer) {
+            reservation.ticket_token = Some(ticket_signer.sign(&TicketClaims {
+                reservation_id: reservation.id,
+                content_schedule_id: reservation.content_schedule_id,
+                ad_cnt: reservation.ad_cnt,
+                cd_cnt: reservation.cd_cnt,
+                expires_at: self.check_in_window.closes_at(start_time),
+                user_id: reservation.user_id.clone(),
+            }));
+        }
+        reservation
     }
 }
 
 #[async_trait]
 impl ReservationUseCase for ReservationService {
-    async fn create_reservation(&self, reservation: Reservation) -> Result<(), String> {
-        self.save_port.save_reservation(reservation).await
+    async fn create_reservation(&self, mut reservation: Reservation, max_adult: i32, max_child: i32, audit: &AuditContext) -> Result<BookingOutcome, String> {
+        self.ensure_window_open(BookingAction::Create, reservation.content_schedule_id).await?;
+
+        // 예약 정책 검사 (위반된 규칙을 모두 반환)
+        let violations = self.evaluate_booking(reservation.user_id.clone(), reservation.content_schedule_id, reservation.party.clone(), max_adult, max_child).await?;
+        if !violations.is_empty() {
+            return Ok(BookingOutcome::Rejected(violations));
+        }
+        let price = self.price_for(&reservation.user_id, reservation.content_schedule_id, &reservation.party).await?;
+
+        // 유료 예약은 결제 완료 전까지 결제 대기 (직원이 등록하는 현장 예약은 현장 결제, 등록 직원이 없으면 결제 대기)
+        let on_site_payment = audit.channel == AuditChannel::Manual && audit.actor_id.is_some();
+        let awaiting_payment = price.total_price > 0 && !on_site_payment;
+        if awaiting_payment {
+            reservation.status = Some(ReservationStatus::PendingPayment);
+            reservation.payment_due_at = Some(self.payment.payment_due_at());
+        }
+        reservation.price = Some(price);
+
+        let reservation_id = self.save_port.save_reservation(reservation, audit).await?;
+        // 결제 대기 예약은 결제 완료 시 예약 완료 알림 발송
+        if !awaiting_payment {
+            self.notify_committed(NotificationKind::Confirmed, reservation_id).await;
+        }
+        Ok(BookingOutcome::Created)
+    }
+
+    async fn show_reservation(&self, user_id: &str, reservation_id: i32) -> Result<Option<Reservation>, String> {
+        // 다른 사용자 예약은 존재 여부도 노출하지 않음
+        let reservation = self.load_port.load_reservation(reservation_id).await?;
+        Ok(reservation
+            .filter(|reservation| reservation.user_id == user_id)
+            .map(|reservation| self.with_ticket(reservation)))
     }
 
-    async fn show_reservation(&self, reservation_id: i32) -> Result<Reservation, String> {
+    async fn show_reservation_history(&self, reservation_id: i32) -> Result<Vec<ReservationHistory>, String> {
         self.load_port
-            .load_reservation(reservation_id)
+            .load_reservation_history(reservation_id)
             .await
-            .ok_or("Reservation not found".to_string())
     }
 
-    async fn show_today_reservations(&self) -> Result<Vec<Reservation>,String> {
-        let (start_time, end_time) = get_today_start_end_date();
+    async fn show_today_reservations(&self, page: PageRequest) -> Result<Page<Reservation>,String> {
+        let (start_time, end_time) = get_today_start_end_date(self.clock.now(), &self.timezone);
          println!("Start Time: {}", start_time);
         println!("End Time: {}", end_time);
         self.load_port
-            .load_reservations_by_date(start_time, end_time)
+            .load_reservations_by_date(start_time, end_time, page)
             .await
+            .map(|page| page.map(|reservation| self.with_flags(reservation)))
     }
 
-    async fn show_user_reservations(&self, user_id: &str) -> Result<Vec<Reservation>,String>{
+    async fn search_reservations(&self, mut filter: ReservationFilter, page: PageRequest) -> Result<Page<Reservation>,String> {
+        // 조회 조건이 없으면 기존과 동일하게 오늘 예약 조회
+        if filter.has_no_condition() {
+            let (start_time, end_time) = get_today_start_end_date(self.clock.now(), &self.timezone);
+            filter.from = Some(start_time);
+            filter.to = Some(end_time);
+        }
         self.load_port
-            .load_reservations_by_user(user_id)
+            .search_reservations(filter, page)
             .await
+            .map(|page| page.map(|reservation| self.with_flags(reservation)))
+    }
+
+    async fn show_user_reservations(&self, user_id: &str, page: PageRequest) -> Result<Page<Reservation>,String>{
+        self.load_port
+            .load_reservations_by_user(user_id, page)
+            .await
+            .map(|page| page.map(|reservation| self.with_ticket(reservation)))
     }
 
     async fn check_reservation(&self, user_id: String, schedule_id: u64, ad_cnt: i32, cd_cnt: i32, max_adult:i32,max_child:i32) -> Result<bool, String> {
-        // 사용자 입력 데이터 검증
-        if !self.validate_reservation_input_count(ad_cnt, cd_cnt, max_adult, max_child) {
-            return Ok(false);
+        let violations = self.evaluate_booking(user_id, schedule_id, PartyComposition::from_legacy(ad_cnt, cd_cnt), max_adult, max_child).await?;
+        Ok(violations.is_empty()) // 위반 규칙이 없으면 예약 가능 → true 반환
+    }
+
+    async fn evaluate_booking(&self, user_id: String, schedule_id: u64, party: PartyComposition, max_adult: i32, max_child: i32) -> Result<Vec<PolicyViolation>, String> {
+        let ctx = BookingContext {
+            user_id,
+            schedule_id,
+            party,
+            max_adult,
+            max_child,
+            current: None,
+        };
+        self.policy.evaluate(&ctx).await
+    }
+    
+    //티켓 스캔 입장 처리
+    async fn check_in(&self, token: &str, schedule_id: u64, ad_cnt: Option<i32>, cd_cnt: Option<i32>, audit: &AuditContext) -> Result<CheckInOutcome, String> {
+        let Some(ticket_signer) = &self.ticket_signer else {
+            return Ok(CheckInOutcome::InvalidTicket("티켓 입장이 설정되지 않았습니다.".to_string()));
+        };
+        let claims = match ticket_signer.verify(token, self.clock.now()) {
+            Ok(claims) => claims,
+            Err(e) => return Ok(CheckInOutcome::InvalidTicket(e)),
+        };
+        if claims.content_schedule_id != schedule_id {
+            return Ok(CheckInOutcome::WrongSchedule);
         }
 
-        let dup_reservation = self.load_port.check_user_reservation_for_content(&user_id, schedule_id).await?;
-        if dup_reservation
+        let Some(reservation) = self.load_port.load_reservation(claims.reservation_id).await? else {
+            return Ok(CheckInOutcome::InvalidTicket(format!("예약을 찾을 수 없습니다! ID: {}", claims.reservation_id)));
+        };
+        // 양도 등으로 소유자가 바뀌었으면 이전 소유자의 티켓은 사용 불가
+        if reservation.user_id != claims.user_id {
+            return Ok(CheckInOutcome::InvalidTicket("예약자가 변경된 티켓입니다. 현재 예약자의 티켓으로 입장해 주세요.".to_string()));
+        }
+        if reservation.is_cancelled() {
+            return Ok(CheckInOutcome::Cancelled);
+        }
+        if reservation.is_no_show() {
+            return Ok(CheckInOutcome::InvalidTicket("노쇼 처리된 예약입니다.".to_string()));
+        }
+        if reservation.is_pending_payment() {
+            return Ok(CheckInOutcome::InvalidTicket("결제가 완료되지 않은 예약입니다.".to_string()));
+        }
+        if reservation.is_used() {
+            return Ok(CheckInOutcome::AlreadyUsed);
+        }
+        // 티켓 발급 후 회차 / 인원이 변경되었으면 이전 티켓은 사용 불가
+        if reservation.content_schedule_id != claims.content_schedule_id
+            || reservation.ad_cnt != claims.ad_cnt
+            || reservation.cd_cnt != claims.cd_cnt
         {
-            let limits = self.load_port.check_reservation_for_user_count(&user_id, schedule_id).await?;
+            return Ok(CheckInOutcome::InvalidTicket("예약 정보가 변경되었습니다. 티켓을 다시 발급받아 주세요.".to_string()));
+        }
 
-            // Private 함수 호출
-            if !self.is_reservation_available(limits, max_adult, max_child) {
-                return Ok(false);
+        if let Some(start_time) = reservation.schedule.as_ref().and_then(|schedule| schedule.start_time) {
+            if let Err(e) = self.check_in_window.check(start_time, self.clock.now()) {
+                return Ok(CheckInOutcome::OutsideWindow(e));
             }
         }
-    
-        Ok(true) // 예약 가능 → true 반환
+
+        // 입장 인원을 지정하지 않으면 남은 인원 전원 입장
+        let arriving_ad_cnt = ad_cnt.unwrap_or(reservation.remaining_adults());
+        let arriving_cd_cnt = cd_cnt.unwrap_or(reservation.remaining_children());
+        if arriving_ad_cnt < 0 || arriving_cd_cnt < 0 || arriving_ad_cnt + arriving_cd_cnt == 0 {
+            return Ok(CheckInOutcome::InvalidPartySize("입장 인원은 1명 이상이어야 합니다.".to_string()));
+        }
+        if arriving_ad_cnt > reservation.remaining_adults() || arriving_cd_cnt > reservation.remaining_children() {
+            return Ok(CheckInOutcome::InvalidPartySize(format!(
+                "남은 입장 인원을 초과했습니다. (성인 {}명, 어린이 {}명 남음)",
+                reservation.remaining_adults(), reservation.remaining_children()
+            )));
+        }
+
+        // 동시에 같은 티켓이 스캔되어도 예약 인원을 넘겨 입장 처리되지 않음
+        if !self.save_port.check_in_party(claims.reservation_id, claims.content_schedule_id, claims.ad_cnt, claims.cd_cnt, arriving_ad_cnt, arriving_cd_cnt, audit).await? {
+            return Ok(CheckInOutcome::AlreadyUsed);
+        }
+
+        let reservation = self.load_port
+            .load_reservation(claims.reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", claims.reservation_id))?;
+        Ok(CheckInOutcome::CheckedIn(self.with_flags(reservation)))
     }
-    
-    //예약 사용하기 
-    async fn use_reservation(&self, reservation_id: i32) -> Result<(), String> {
-        let use_status = ReservationStatus::Confirmed;
-        self.save_port.update_status(reservation_id, use_status).await
+
+    //입장 마감(회차 시작 + 입장 허용 시간)이 지나도록 아무도 입장하지 않은 예약 노쇼 처리
+    async fn mark_no_shows(&self) -> Result<u64, String> {
+        let cutoff = self.clock.now() - Duration::minutes(self.check_in_window.closes_minutes_after);
+        let marked = self.save_port.mark_no_shows(cutoff, &AuditContext::system()).await?;
+        if marked > 0 {
+            println!("노쇼 처리: {}건 (회차 시작 < {})", marked, cutoff.to_rfc3339());
+        }
+        Ok(marked)
     }
 
     //예약 취소하기
-    async fn cancel_reservation(&self, reservation_id: i32) -> Result<(), String> {
-        let cancel_status = ReservationStatus::Cancelled;
-        self.save_port.update_status(reservation_id, cancel_status).await
+    async fn cancel_reservation(&self, user_id: &str, reservation_id: i32, audit: &AuditContext) -> Result<(), String> {
+        // 본인 예약만 취소 가능
+        let reservation = self.load_own_reservation(user_id, reservation_id).await?;
+        self.cancel_loaded(reservation, audit).await
+    }
+
+    //직원 예약 취소하기 (예약자와 무관하게 취소)
+    async fn cancel_reservation_by_staff(&self, reservation_id: i32, audit: &AuditContext) -> Result<(), String> {
+        let reservation = self.load_port
+            .load_reservation(reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id))?;
+        self.cancel_loaded(reservation, audit).await
     }
 
     //예약 수정하기
-    async fn update_reservation(&self, reservation_id: i32, ad_cnt: i32, cd_cnt: i32, max_adult: i32, max_child: i32) -> Result<(), String> {  
-        // 사용자 입력 데이터 검증
-        if !self.validate_reservation_input_count(ad_cnt, cd_cnt, max_adult, max_child) {
-        return Err("예약 불가".to_string());
+    async fn update_reservation(&self, user_id: &str, reservation_id: i32, party: PartyComposition, max_adult: i32, max_child: i32, audit: &AuditContext) -> Result<(), String> {  
+        // 본인 예약만 수정 가능 (한도도 예약자 본인 기준)
+        let reservation = self.load_own_reservation(user_id, reservation_id).await?;
+        if reservation.is_group_booking() {
+            return Err(GROUP_BOOKING_LOCKED.to_string());
+        }
+        if reservation.is_cancelled() || reservation.is_no_show() || reservation.is_used() {
+            return Err("취소 / 미방문 / 사용된 예약은 인원을 변경할 수 없습니다.".to_string());
         }
+        if reservation.is_partially_checked_in() {
+            return Err("입장이 시작된 예약은 인원을 변경할 수 없습니다.".to_string());
+        }
+        if reservation.is_pending_payment() {
+            return Err("결제가 완료된 뒤에 인원을 변경할 수 있습니다.".to_string());
+        }
+        self.ensure_window_open(BookingAction::ChangeCount, reservation.content_schedule_id).await?;
 
-        let my_reservation = self.load_port.load_reservation(reservation_id).await;
+        // 예약 정책 검사 (기존 예약 인원은 새 인원으로 대체)
+        let user_id = reservation.user_id.clone();
+        let schedule_id = reservation.content_schedule_id;
+        let charged = reservation.price.as_ref().map_or(0, |price| price.total_price);
+        let ctx = BookingContext {
+            user_id: user_id.clone(),
+            schedule_id,
+            party: party.clone(),
+            max_adult,
+            max_child,
+            current: Some(reservation),
+        };
+        let violations = self.policy.evaluate(&ctx).await?;
+        if !violations.is_empty() {
+            let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            return Err(format!("예약 불가: {}", reasons.join(", ")));
+        }
 
-        if let Some(reservation) = my_reservation {  
-            let schedule_id = reservation.content_schedule_id;  
-            let user_id = reservation.user_id.clone(); 
+        // 변경된 인원 기준으로 금액 재산정
+        let price = self.price_for(&user_id, schedule_id, &party).await?;
+        Self::ensure_same_charge(charged, &price)?;
 
+        // 인원 업데이트 실행
+        match self.save_port.update_reservaiton_user_count(reservation_id, &party, &price, audit).await {
+            Ok(_) => println!("예약 인원 업데이트 성공"),
+            Err(e) => {
+                return Err(format!("예약 인원 업데이트 실패: {}", e));
+            }
+        };
+        self.notify_committed(NotificationKind::Changed, reservation_id).await;
+        Ok(()) 
+    }
 
-            let chk_val = match self.load_port.check_reservation_for_user_count(&user_id, schedule_id).await {
-                Ok(val) => {
-                    val
-                }
-                Err(e) => {
-                    return Err(format!("현재 예약 인원 조회 실패: {}", e));
-                }
-            };
+    //다른 회차로 예약 이동 (같은 컨텐츠의 회차만 가능)
+    async fn move_reservation(&self, user_id: &str, reservation_id: i32, new_schedule_id: u64, max_adult: i32, max_child: i32, audit: &AuditContext) -> Result<(), String> {
+        // 본인 예약만 이동 가능 (한도도 예약자 본인 기준)
+        let reservation = self.load_own_reservation(user_id, reservation_id).await?;
+        if reservation.is_group_booking() {
+            return Err(GROUP_BOOKING_LOCKED.to_string());
+        }
+        if reservation.is_cancelled() || reservation.is_no_show() || reservation.is_used() || reservation.is_partially_checked_in() {
+            return Err("취소 / 사용 / 입장이 시작된 예약은 이동할 수 없습니다.".to_string());
+        }
+        if reservation.is_pending_payment() {
+            return Err("결제가 완료된 뒤에 회차를 변경할 수 있습니다.".to_string());
+        }
+        if reservation.content_schedule_id == new_schedule_id {
+            return Err("현재와 같은 회차로는 이동할 수 없습니다.".to_string());
+        }
 
-            // 예약 가능 여부 체크
-            if !self.is_reservation_available(chk_val, max_adult, max_child) {
-                return Err("예약 불가: 인원 초과".to_string()); 
-            }
+        let new_schedule = self.load_port
+            .load_schedule_availability(new_schedule_id)
+            .await?
+            .ok_or(format!("스케줄을 찾을 수 없습니다! ID: {}", new_schedule_id))?;
+        let current_content_id = reservation.schedule.as_ref().map(|schedule| schedule.content_id);
+        if current_content_id != Some(new_schedule.content_id) {
+            return Err("같은 컨텐츠의 회차로만 이동할 수 있습니다.".to_string());
+        }
 
-            // 인원 업데이트 실행
-            match self.save_port.update_reservaiton_user_count(reservation_id, ad_cnt, cd_cnt).await {
-                Ok(_) => println!("예약 인원 업데이트 성공"),
-                Err(e) => {
-                    return Err(format!("예약 인원 업데이트 실패: {}", e));
-                }
-            };
-            Ok(()) 
+        // 기존 회차는 인원 변경 마감, 새 회차는 예약 마감 기준
+        self.ensure_window_open(BookingAction::ChangeCount, reservation.content_schedule_id).await?;
+        self.ensure_window_open(BookingAction::Create, new_schedule_id).await?;
 
-    } else {
-        Err(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id)) 
+        // 예약 정책 검사 (새 회차 기준, 기존 예약 인원은 그대로 이동)
+        let user_id = reservation.user_id.clone();
+        let party = reservation.party.clone();
+        let charged = reservation.price.as_ref().map_or(0, |price| price.total_price);
+        let ctx = BookingContext {
+            user_id: user_id.clone(),
+            schedule_id: new_schedule_id,
+            party: party.clone(),
+            max_adult,
+            max_child,
+            current: Some(reservation),
+        };
+        let violations = self.policy.evaluate(&ctx).await?;
+        if !violations.is_empty() {
+            let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            return Err(format!("예약 불가: {}", reasons.join(", ")));
+        }
+
+        // 새 회차 기준으로 금액 재산정 (시간대 할인 등)
+        let price = self.price_for(&user_id, new_schedule_id, &party).await?;
+        Self::ensure_same_charge(charged, &price)?;
+
+        self.save_port
+            .move_reservation(reservation_id, new_schedule_id, &price, audit)
+            .await?;
+        self.notify_committed(NotificationKind::Moved, reservation_id).await;
+        Ok(())
     }
+
+    //스케줄 잔여 좌석 조회
+    async fn show_schedule_availability(&self, user_id: String, schedule_id: u64, max_adult: i32, max_child: i32) -> Result<Option<ScheduleAvailability>, String> {
+        let Some(mut availability) = self.load_port.load_schedule_availability(schedule_id).await? else {
+            return Ok(None);
+        };
+
+        // 잔여 좌석이 있고, 예약 정책상 최소 1명(성인 또는 어린이)을 더 예약할 수 있는 경우에만 예약 가능
+        let can_book = !availability.is_sold_out()
+            && (self.check_reservation(user_id.clone(), schedule_id, 1, 0, max_adult, max_child).await?
+                || self.check_reservation(user_id, schedule_id, 0, 1, max_adult, max_child).await?);
+        availability.can_book = Some(can_book);
+
+        Ok(Some(availability))
+    }
+
+    //스케줄 잔여 좌석 일괄 조회
+    async fn show_schedules_availability(&self, mut filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String> {
+        // 날짜 → 영업 시간대 기준 하루 범위 [00:00, 다음날 00:00)
+        if let Some(date) = filter.date {
+            let (start_time, end_time) = get_day_start_end_date(date, &self.timezone);
+            filter.start_time = Some(start_time);
+            filter.end_time = Some(end_time);
+        }
+        self.load_port
+            .load_schedules_availability(filter)
+            .await
     }
 
     // async fn delete_reservation(&self, reservation_id: i32) -> Result<(), String> {
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::{common::{clock::Clock, page::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT}}, domain::{booking_window::{BookingAction, BookingWindow}, notification::NotificationKind, reservation::Reservation, reservation_history::{AuditChannel, AuditContext}, reservation_transfer::{ReservationTransfer, TransferStatus}}};
+
+use super::booking_notifier::BookingNotifier;
+use super::policy::booking_policy::{BookingContext, BookingPolicy};
+use super::port::{r#in::transfer_usecase::TransferUseCase, out::{reservation_load_port::ReservationLoadPort, transfer_port::TransferPort}};
+
+// Use Case Implementation
+pub struct TransferService {
+    transfer_port: Arc<dyn TransferPort + Send + Sync>,
+    load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+    policy: Arc<BookingPolicy>,
+    clock: Arc<dyn Clock>,
+    window: BookingWindow,
+    notifier: Arc<BookingNotifier>,
+    expiry: Duration,
+}
+
+impl TransferService {
+    pub fn new(
+        transfer_port: Arc<dyn TransferPort + Send + Sync>,
+        load_port: Arc<dyn ReservationLoadPort + Send + Sync>,
+        policy: Arc<BookingPolicy>,
+        clock: Arc<dyn Clock>,
+        window: BookingWindow,
+        notifier: Arc<BookingNotifier>,
+        expiry_hours: i64,
+    ) -> Self {
+        Self { transfer_port, load_port, policy, clock, window, notifier, expiry: Duration::hours(expiry_hours) }
+    }
+
+    async fn load_reservation(&self, reservation_id: i32) -> Result<Reservation, String> {
+        self.load_port
+            .load_reservation(reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id))
+    }
+
+    /// 보낸 사용자 / 받는 사용자만 조회 가능 (다른 사용자에게는 없는 요청으로 응답)
+    async fn load_transfer(&self, user_id: &str, transfer_id: i64) -> Result<ReservationTransfer, String> {
+        let not_found = format!("양도 요청을 찾을 수 없습니다! ID: {}", transfer_id);
+        let mut transfer = self.transfer_port.load_transfer(transfer_id).await?.ok_or(not_found.clone())?;
+        if transfer.from_user_id != user_id && transfer.to_user_id != user_id {
+            return Err(not_found);
+        }
+        transfer.status = transfer.effective_status(self.clock.now());
+        Ok(transfer)
+    }
+
+    /// 양도 가능한 예약인지 검사 (입장 전, 인원 변경 마감 이전)
+    fn ensure_transferable(&self, reservation: &Reservation) -> Result<(), String> {
+        if reservation.is_group_booking() {
+            return Err("단체 예약으로 생성된 예약은 양도할 수 없습니다.".to_string());
+        }
+        if reservation.is_cancelled() || reservation.is_no_show() || reservation.is_used() || reservation.is_partially_checked_in() {
+            return Err("취소 / 사용 / 입장이 시작된 예약은 양도할 수 없습니다.".to_string());
+        }
+        if reservation.is_pending_payment() {
+            return Err("결제가 완료되지 않은 예약은 양도할 수 없습니다.".to_string());
+        }
+        let start_time = reservation.schedule.as_ref().and_then(|schedule| schedule.start_time);
+        match start_time {
+            Some(start_time) => self.window.check(BookingAction::ChangeCount, start_time, self.clock.now()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl TransferUseCase for TransferService {
+    async fn request_transfer(&self, user_id: &str, reservation_id: i32, to_user_id: &str) -> Result<ReservationTransfer, String> {
+        let to_user_id = to_user_id.trim();
+        if to_user_id.is_empty() || to_user_id == user_id {
+            return Err("다른 사용자에게만 양도할 수 있습니다.".to_string());
+        }
+        let reservation = self.load_reservation(reservation_id).await?;
+        if reservation.user_id != user_id {
+            return Err(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id));
+        }
+        self.ensure_transferable(&reservation)?;
+
+        // 응답 기한은 회차 시작 시각을 넘지 않음
+        let now = self.clock.now();
+        let mut expires_at = now + self.expiry;
+        if let Some(start_time) = reservation.schedule.as_ref().and_then(|schedule| schedule.start_time) {
+            expires_at = expires_at.min(start_time);
+        }
+
+        let mut transfer = ReservationTransfer {
+            id: 0,
+            reservation_id,
+            from_user_id: user_id.to_string(),
+            to_user_id: to_user_id.to_string(),
+            status: TransferStatus::Pending,
+            created_at: Some(now),
+            expires_at,
+            responded_at: None,
+        };
+        transfer.id = self.transfer_port.save_transfer(&transfer, now).await?;
+        println!("예약 양도 요청: transfer_id={}, reservation_id={}, {} → {}", transfer.id, reservation_id, user_id, to_user_id);
+        self.notifier.notify_user(transfer.to_user_id.clone(), NotificationKind::TransferRequested, &reservation);
+        Ok(transfer)
+    }
+
+    async fn accept_transfer(&self, user_id: &str, transfer_id: i64, max_adult: i32, max_child: i32) -> Result<ReservationTransfer, String> {
+        let transfer = self.load_transfer(user_id, transfer_id).await?;
+        if transfer.to_user_id != user_id {
+            return Err("양도받는 사용자만 수락할 수 있습니다.".to_string());
+        }
+        if transfer.status != TransferStatus::Pending {
+            return Err(format!("수락할 수 없는 양도 요청입니다. (현재 상태: {})", transfer.status));
+        }
+        let reservation = self.load_reservation(transfer.reservation_id).await?;
+        self.ensure_transferable(&reservation)?;
+
+        // 받는 사용자 기준으로 신규 예약과 같은 정책 검사 (인원 한도 / 컨텐츠당 1건 / 동일 시간대 등)
+        // 인원 한도 / 컨텐츠당 1건은 동시 수락에 대비해 수락 트랜잭션 안에서 한 번 더 검사
+        let ctx = BookingContext {
+            user_id: user_id.to_string(),
+            schedule_id: reservation.content_schedule_id,
+            party: reservation.party.clone(),
+            max_adult,
+            max_child,
+            current: None,
+        };
+        let violations = self.policy.evaluate(&ctx).await?;
+        if !violations.is_empty() {
+            let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            return Err(format!("양도 불가: {}", reasons.join(", ")));
+        }
+
+        let audit = AuditContext::new(Some(user_id.to_string()), AuditChannel::Rest);
+        self.transfer_port.accept_transfer(transfer_id, max_adult, max_child, self.clock.now(), &audit).await?;
+        println!("예약 양도 완료: transfer_id={}, reservation_id={}", transfer_id, transfer.reservation_id);
+
+        match self.load_port.load_reservation(transfer.reservation_id).await {
+            Ok(Some(reservation)) => self.notifier.notify(NotificationKind::Transferred, &reservation),
+            Ok(None) => eprintln!("알림 대상 예약을 찾을 수 없습니다! ID: {}", transfer.reservation_id),
+            Err(e) => eprintln!("알림 대상 예약 조회 실패 (ID: {}): {}", transfer.reservation_id, e),
+        }
+        self.load_transfer(user_id, transfer_id).await
+    }
+
+    async fn decline_transfer(&self, user_id: &str, transfer_id: i64) -> Result<ReservationTransfer, String> {
+        let transfer = self.load_transfer(user_id, transfer_id).await?;
+        if transfer.to_user_id != user_id {
+            return Err("양도받는 사용자만 거절할 수 있습니다.".to_string());
+        }
+        if transfer.status != TransferStatus::Pending || !self.transfer_port.close_transfer(transfer_id, TransferStatus::Declined, self.clock.now()).await? {
+            return Err(format!("이미 처리된 양도 요청입니다. (현재 상태: {})", transfer.status));
+        }
+        self.load_transfer(user_id, transfer_id).await
+    }
+
+    async fn cancel_transfer(&self, user_id: &str, transfer_id: i64) -> Result<ReservationTransfer, String> {
+        let transfer = self.load_transfer(user_id, transfer_id).await?;
+        if transfer.from_user_id != user_id {
+            return Err("양도를 요청한 사용자만 철회할 수 있습니다.".to_string());
+        }
+        if transfer.status != TransferStatus::Pending || !self.transfer_port.close_transfer(transfer_id, TransferStatus::Cancelled, self.clock.now()).await? {
+            return Err(format!("이미 처리된 양도 요청입니다. (현재 상태: {})", transfer.status));
+        }
+        self.load_transfer(user_id, transfer_id).await
+    }
+
+    async fn show_user_transfers(&self, user_id: &str, limit: Option<u32>) -> Result<Vec<ReservationTransfer>, String> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            return Err(format!("limit은 1~{} 사이여야 합니다.", MAX_PAGE_LIMIT));
+        }
+        let now = self.clock.now();
+        let mut transfers = self.transfer_port.load_user_transfers(user_id, limit.into()).await?;
+        for transfer in transfers.iter_mut() {
+            transfer.status = transfer.effective_status(now);
+        }
+        Ok(transfers)
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::{common::{clock::Clock, network::{ensure_public_host, url_host}, page::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT}, webhook_signature::{self, SIGNATURE_HEADER}}, domain::{reservation_event::ReservationEventType, webhook::{DeliveryOutcome, PendingWebhookDelivery, WebhookDelivery, WebhookSubscription}}};
+
+use super::{outbox_relay_service::RetryBackoff, port::{r#in::webhook_usecase::WebhookUseCase, out::{webhook_port::WebhookPort, webhook_send_port::WebhookSendPort}}};
+
+// 선점한 전달을 이 시간 안에 처리하지 못하면 다른 인스턴스가 다시 가져감
+const DELIVERY_LEASE_SECS: i64 = 120;
+const MIN_SECRET_LEN: usize = 16;
+const MAX_SECRET_LEN: usize = 255;
+const MAX_URL_LEN: usize = 500;
+
+// 웹훅 전송 설정
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookDeliveryConfig {
+    pub batch_size: i64,
+    pub max_attempts: i32, // 이 횟수만큼 실패하면 DEAD (수동 재전송 대상)
+    pub backoff: RetryBackoff,
+    pub allow_private_targets: bool, // 개발용: 루프백 / 내부망 주소 허용
+}
+
+// Use Case Implementation
+pub struct WebhookService {
+    webhook_port: Arc<dyn WebhookPort + Send + Sync>,
+    sender: Arc<dyn WebhookSendPort + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    config: WebhookDeliveryConfig,
+}
+
+impl WebhookService {
+    pub fn new(
+        webhook_port: Arc<dyn WebhookPort + Send + Sync>,
+        sender: Arc<dyn WebhookSendPort + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        config: WebhookDeliveryConfig,
+    ) -> Self {
+        Self { webhook_port, sender, clock, config }
+    }
+
+    // 구독 입력값 검증
+    fn validate_subscription(&self, url: &str, secret: &str) -> Result<(), String> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) || url.len() > MAX_URL_LEN {
+            return Err(format!("url은 http:// 또는 https:// 로 시작하는 {}자 이하의 주소여야 합니다.", MAX_URL_LEN));
+        }
+        // 내부망 / 루프백 주소 거부 (도메인이 내부 주소로 풀리는 경우는 전송 시 다시 거부)
+        let host = url_host(url).ok_or("url에 호스트가 없습니다.".to_string())?;
+        if !self.config.allow_private_targets {
+            ensure_public_host(host)?;
+        }
+        if secret.len() < MIN_SECRET_LEN || secret.len() > MAX_SECRET_LEN {
+            return Err(format!("secret은 {}~{}자여야 합니다.", MIN_SECRET_LEN, MAX_SECRET_LEN));
+        }
+        Ok(())
+    }
+
+    // 서명 후 전송 → 성공 / 재시도 / DEAD 판정
+    async fn deliver(&self, pending: &PendingWebhookDelivery) -> DeliveryOutcome {
+        let delivery = &pending.delivery;
+        let headers = [
+            ("X-Webhook-Id", delivery.id.to_string()),
+            ("X-Event-Id", delivery.event_id.to_string()),
+            ("X-Event-Type", delivery.event_type.clone()),
+            (SIGNATURE_HEADER, webhook_signature::sign(&pending.secret, self.clock.now(), &delivery.body)),
+        ];
+
+        let (status_code, error) = match self.sender.send(&pending.url, &headers, &delivery.body).await {
+            Ok(status_code) if (200..300).contains(&status_code) => return DeliveryOutcome::Delivered { status_code },
+            Ok(status_code) => (Some(status_code), format!("HTTP {}", status_code)),
+            Err(err) => (None, err),
+        };
+
+        let attempts = delivery.attempts + 1;
+        println!("웹훅 전달 실패 (delivery_id={}, 시도 {}회): {}", delivery.id, attempts, error);
+        if attempts >= self.config.max_attempts {
+            DeliveryOutcome::Dead { status_code, error }
+        } else {
+            DeliveryOutcome::Retry { status_code, error, next_attempt_at: self.clock.now() + self.config.backoff.delay(attempts) }
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookUseCase for WebhookService {
+    async fn create_subscription(&self, url: String, secret: String, event_types: Vec<ReservationEventType>) -> Result<WebhookSubscription, String> {
+        self.validate_subscription(&url, &secret)?;
+        let mut subscription = WebhookSubscription { id: 0, url, secret, event_types, active: true, created_at: None };
+        subscription.id = self.webhook_port.save_subscription(&subscription).await?;
+        Ok(subscription)
+    }
+
+    // secret 을 생략하면 기존 값 유지
+    async fn update_subscription(&self, subscription_id: i64, url: String, secret: Option<String>, event_types: Vec<ReservationEventType>, active: bool) -> Result<WebhookSubscription, String> {
+        let existing = self.webhook_port.load_subscription(subscription_id).await?
+            .ok_or(format!("웹훅 구독을 찾을 수 없습니다! ID: {}", subscription_id))?;
+        let subscription = WebhookSubscription {
+            url,
+            secret: secret.unwrap_or(existing.secret),
+            event_types,
+            active,
+            ..existing
+        };
+        self.validate_subscription(&subscription.url, &subscription.secret)?;
+        if !self.webhook_port.update_subscription(&subscription).await? {
+            return Err(format!("웹훅 구독을 찾을 수 없습니다! ID: {}", subscription_id));
+        }
+        Ok(subscription)
+    }
+
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<(), String> {
+        if !self.webhook_port.delete_subscription(subscription_id).await? {
+            return Err(format!("웹훅 구독을 찾을 수 없습니다! ID: {}", subscription_id));
+        }
+        Ok(())
+    }
+
+    async fn show_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String> {
+        self.webhook_port.load_subscriptions().await
+    }
+
+    async fn show_dead_deliveries(&self, subscription_id: Option<i64>, limit: Option<u32>) -> Result<Vec<WebhookDelivery>, String> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            return Err(format!("limit은 1~{} 사이여야 합니다.", MAX_PAGE_LIMIT));
+        }
+        self.webhook_port.load_dead_deliveries(subscription_id, limit.into()).await
+    }
+
+    async fn replay_delivery(&self, delivery_id: i64) -> Result<(), String> {
+        if !self.webhook_port.replay_delivery(delivery_id).await? {
+            return Err(format!("재전송할 수 없는 전달입니다. (없거나 전달 중) ID: {}", delivery_id));
+        }
+        println!("웹훅 수동 재전송 예약: delivery_id={}", delivery_id);
+        Ok(())
+    }
+
+    async fn deliver_pending(&self) -> Result<usize, String> {
+        let now = self.clock.now();
+        let deliveries = self.webhook_port
+            .claim_pending_deliveries(now, self.config.batch_size.max(1), now + Duration::seconds(DELIVERY_LEASE_SECS))
+            .await?;
+
+        for pending in &deliveries {
+            let outcome = self.deliver(pending).await;
+            if let DeliveryOutcome::Dead { error, .. } = &outcome {
+                eprintln!("웹훅 전달 최종 실패 → DEAD (delivery_id={}): {}", pending.delivery.id, error);
+            }
+            self.webhook_port.record_delivery_outcome(pending.delivery.id, &outcome).await?;
+        }
+        Ok(deliveries.len())
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+/// 현재 시각 제공자 (테스트에서 시간을 고정할 수 있도록 주입)
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// 영업 시간대 기준 오늘 날짜
+    fn today(&self, tz: &Tz) -> NaiveDate {
+        self.now().with_timezone(tz).date_naive()
+    }
+}
+
+/// 시스템 시계
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 고정 시계 (테스트용)
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn today_follows_business_timezone_across_utc_midnight() {
+        let seoul: Tz = "Asia/Seoul".parse().unwrap();
+
+        // UTC 14:59:59 = 서울 23:59:59, UTC 15:00 = 서울 다음날 00:00
+        let before_midnight = FixedClock(Utc.with_ymd_and_hms(2025, 3, 1, 14, 59, 59).unwrap());
+        let at_midnight = FixedClock(Utc.with_ymd_and_hms(2025, 3, 1, 15, 0, 0).unwrap());
+
+        assert_eq!(before_midnight.today(&seoul), NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(at_midnight.today(&seoul), NaiveDate::from_ymd_opt(2025, 3, 2).unwrap());
+        assert_eq!(at_midnight.today(&chrono_tz::UTC), NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+    }
+}
@@ -1,20 +1,96 @@
-use chrono::{DateTime, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 
-pub fn get_today_start_end_date() -> (DateTime<Utc>, DateTime<Utc>) {
-    let now = Utc::now();
-    let today = now.date_naive(); // 오늘 날짜 (NaiveDate)
+/// 영업 시간대 기준 오늘 하루 범위 [00:00, 다음날 00:00) 를 UTC로 반환
+pub fn get_today_start_end_date(now: DateTime<Utc>, tz: &Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let today = now.with_timezone(tz).date_naive(); // 영업 시간대 기준 오늘 날짜 (NaiveDate)
 
-    // 00:00:00 (시작 시간)
-    let start_time = Utc.from_utc_datetime(&NaiveDateTime::new(
-        today, 
-        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
-    ));
+    get_day_start_end_date(today, tz)
+}
 
-    // 23:59:59 (끝 시간)
-    let end_time = Utc.from_utc_datetime(&NaiveDateTime::new(
-        today, 
-        NaiveTime::from_hms_opt(23, 59, 59).unwrap()
-    ));
+/// 영업 시간대 기준 특정 날짜 범위 [00:00, 다음날 00:00) 를 UTC로 반환 (끝 시간 미포함)
+pub fn get_day_start_end_date(date: NaiveDate, tz: &Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start_time = local_midnight_to_utc(date, tz);
+    let end_time = local_midnight_to_utc(date + Duration::days(1), tz);
 
     (start_time, end_time)
-}
\ No newline at end of file
+}
+
+// 현지 00:00 → UTC (서머타임으로 00:00이 없는 날은 그 날의 가장 이른 시각 사용)
+fn local_midnight_to_utc(date: NaiveDate, tz: &Tz) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    tz.from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(midnight + Duration::hours(1))).earliest())
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+}
+
+/// 설정 문자열("Asia/Seoul")을 시간대로 변환
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.parse::<Tz>().map_err(|_| format!("알 수 없는 시간대입니다: {}", name))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn today_switches_at_local_midnight() {
+        let seoul = parse_timezone("Asia/Seoul").unwrap();
+
+        // 서울 23:59:59 → 그 날, 서울 00:00 → 다음날
+        let (start, end) = get_today_start_end_date(utc(2025, 3, 1, 14, 59, 59), &seoul);
+        assert_eq!((start, end), (utc(2025, 2, 28, 15, 0, 0), utc(2025, 3, 1, 15, 0, 0)));
+
+        let (next_start, next_end) = get_today_start_end_date(utc(2025, 3, 1, 15, 0, 0), &seoul);
+        assert_eq!((next_start, next_end), (utc(2025, 3, 1, 15, 0, 0), utc(2025, 3, 2, 15, 0, 0)));
+    }
+
+    #[test]
+    fn consecutive_days_are_half_open_without_gap_or_overlap() {
+        let seoul = parse_timezone("Asia/Seoul").unwrap();
+        let (_, first_end) = get_day_start_end_date(date(2025, 12, 31), &seoul);
+        let (second_start, _) = get_day_start_end_date(date(2026, 1, 1), &seoul);
+
+        assert_eq!(first_end, second_start);
+    }
+
+    #[test]
+    fn dst_days_are_23_and_25_hours_long() {
+        let new_york = parse_timezone("America/New_York").unwrap();
+
+        // 서머타임 시작 (02:00 → 03:00)
+        let (start, end) = get_day_start_end_date(date(2025, 3, 9), &new_york);
+        assert_eq!((start, end), (utc(2025, 3, 9, 5, 0, 0), utc(2025, 3, 10, 4, 0, 0)));
+        assert_eq!(end - start, Duration::hours(23));
+
+        // 서머타임 종료 (02:00 → 01:00)
+        let (start, end) = get_day_start_end_date(date(2025, 11, 2), &new_york);
+        assert_eq!((start, end), (utc(2025, 11, 2, 4, 0, 0), utc(2025, 11, 3, 5, 0, 0)));
+        assert_eq!(end - start, Duration::hours(25));
+    }
+
+    #[test]
+    fn missing_local_midnight_uses_first_valid_time() {
+        // 2018-11-04 상파울루는 00:00 에 서머타임 시작 (00:00 → 01:00, 자정이 없음)
+        let sao_paulo = parse_timezone("America/Sao_Paulo").unwrap();
+        let (start, end) = get_day_start_end_date(date(2018, 11, 4), &sao_paulo);
+
+        assert_eq!(start, utc(2018, 11, 4, 3, 0, 0)); // 01:00 -02:00
+        assert_eq!(end, utc(2018, 11, 5, 2, 0, 0));
+        assert_eq!(get_day_start_end_date(date(2018, 11, 3), &sao_paulo).1, start);
+    }
+
+    #[test]
+    fn unknown_timezone_is_rejected() {
+        assert!(parse_timezone("Asia/Nowhere").is_err());
+    }
+}
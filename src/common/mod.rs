@@ -1,2 +1,7 @@
 pub mod valid;
-pub mod date;
\ No newline at end of file
+pub mod date;
+pub mod clock;
+pub mod page;
+pub mod ticket;
+pub mod webhook_signature;
+pub mod network;
\ No newline at end of file
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+
+pub const DEFAULT_PAGE_LIMIT: u32 = 50;
+pub const MAX_PAGE_LIMIT: u32 = 200;
+
+/// 정렬 값이 NULL 인 항목의 정렬 / 커서 값 (SQL 정렬식의 COALESCE 기본값과 같아야 함)
+pub const NULL_SORT_VALUE: DateTime<Utc> = DateTime::<Utc>::UNIX_EPOCH;
+
+/// 키셋 페이지네이션 커서 (마지막 항목의 정렬 값 + id)
+/// 인코딩: 시간 정렬이면 "{마이크로초}_{id}", id 정렬이면 "{id}"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    pub sort_value: Option<DateTime<Utc>>,
+    pub id: i32,
+}
+
+impl PageCursor {
+    pub fn encode(&self) -> String {
+        match self.sort_value {
+            Some(value) => format!("{}_{}", value.timestamp_micros(), self.id),
+            None => self.id.to_string(),
+        }
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, String> {
+        let invalid = || format!("잘못된 커서입니다: {}", cursor);
+        match cursor.split_once('_') {
+            Some((micros, id)) => {
+                let micros: i64 = micros.parse().map_err(|_| invalid())?;
+                Ok(Self {
+                    sort_value: Some(DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?),
+                    id: id.parse().map_err(|_| invalid())?,
+                })
+            }
+            None => Ok(Self {
+                sort_value: None,
+                id: cursor.parse().map_err(|_| invalid())?,
+            }),
+        }
+    }
+}
+
+/// 페이지 요청 (limit 이 None 이면 전체 조회)
+#[derive(Debug, Clone, Default)]
+pub struct PageRequest {
+    pub limit: Option<u32>,
+    pub cursor: Option<PageCursor>,
+}
+
+impl PageRequest {
+    pub fn new(limit: Option<u32>, cursor: Option<&str>) -> Result<Self, String> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            return Err(format!("limit은 1~{} 사이여야 합니다.", MAX_PAGE_LIMIT));
+        }
+        let cursor = cursor
+            .filter(|c| !c.trim().is_empty())
+            .map(|c| PageCursor::decode(c.trim()))
+            .transpose()?;
+        Ok(Self { limit: Some(limit), cursor })
+    }
+
+    pub fn unbounded() -> Self {
+        Self::default()
+    }
+}
+
+/// 페이지 결과 (next_cursor 가 None 이면 마지막 페이지)
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
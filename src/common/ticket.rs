@@ -0,0 +1,174 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const TICKET_VERSION: &str = "v2";
+
+/// 티켓 토큰에 서명되는 값 (예약 / 회차 / 인원 / 만료 시각 / 발급 당시 소유자)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TicketClaims {
+    pub reservation_id: i32,
+    pub content_schedule_id: u64,
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+    pub expires_at: DateTime<Utc>,
+    pub user_id: String,
+}
+
+impl TicketClaims {
+    fn to_payload(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}:{}",
+            TICKET_VERSION, self.reservation_id, self.content_schedule_id, self.ad_cnt, self.cd_cnt, self.expires_at.timestamp(), self.user_id
+        )
+    }
+
+    fn from_payload(payload: &str) -> Option<Self> {
+        // 사용자 ID 에 ':' 가 들어가도 되도록 마지막 필드로 통째로 받음
+        let mut parts = payload.splitn(7, ':');
+        if parts.next()? != TICKET_VERSION {
+            return None;
+        }
+        let claims = Self {
+            reservation_id: parts.next()?.parse().ok()?,
+            content_schedule_id: parts.next()?.parse().ok()?,
+            ad_cnt: parts.next()?.parse().ok()?,
+            cd_cnt: parts.next()?.parse().ok()?,
+            expires_at: DateTime::from_timestamp(parts.next()?.parse().ok()?, 0)?,
+            user_id: parts.next()?.to_string(),
+        };
+        (!claims.user_id.is_empty()).then_some(claims)
+    }
+}
+
+/// HMAC-SHA256 티켓 서명 / 검증기
+/// 토큰 형식: base64url(payload) + "." + base64url(HMAC(payload))
+pub struct TicketSigner {
+    secret: Vec<u8>,
+}
+
+impl TicketSigner {
+    pub fn new(secret: &str) -> Result<Self, String> {
+        if secret.len() < 32 {
+            return Err("티켓 서명 키는 32자 이상이어야 합니다.".to_string());
+        }
+        Ok(Self { secret: secret.as_bytes().to_vec() })
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.secret).expect("HMAC은 모든 키 길이를 허용")
+    }
+
+    pub fn sign(&self, claims: &TicketClaims) -> String {
+        let payload = claims.to_payload();
+        let mut mac = self.mac();
+        mac.update(payload.as_bytes());
+        let signature = mac.finalize().into_bytes();
+
+        format!("{}.{}", URL_SAFE_NO_PAD.encode(payload), URL_SAFE_NO_PAD.encode(signature))
+    }
+
+    /// 서명과 만료 시각을 검증하고 서명된 값을 반환
+    pub fn verify(&self, token: &str, now: DateTime<Utc>) -> Result<TicketClaims, String> {
+        let invalid = || "유효하지 않은 티켓입니다.".to_string();
+
+        let (payload, signature) = token.trim().split_once('.').ok_or_else(invalid)?;
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid())?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+
+        // 서명 비교는 상수 시간으로 수행
+        let mut mac = self.mac();
+        mac.update(&payload);
+        mac.verify_slice(&signature).map_err(|_| invalid())?;
+
+        let payload = String::from_utf8(payload).map_err(|_| invalid())?;
+        let claims = TicketClaims::from_payload(&payload).ok_or_else(invalid)?;
+        if now >= claims.expires_at {
+            return Err(format!("만료된 티켓입니다. (만료 {})", claims.expires_at.to_rfc3339()));
+        }
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const SECRET: &str = "ticket-secret-for-tests-0123456789";
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn claims() -> TicketClaims {
+        TicketClaims {
+            reservation_id: 42,
+            content_schedule_id: 7,
+            ad_cnt: 2,
+            cd_cnt: 1,
+            expires_at: utc(2025, 5, 1, 11, 0),
+            user_id: "user-1".to_string(),
+        }
+    }
+
+    // 서명은 그대로 두고 payload 만 바꿔치기한 토큰
+    fn with_payload(token: &str, payload: &str) -> String {
+        let (_, signature) = token.split_once('.').unwrap();
+        format!("{}.{}", URL_SAFE_NO_PAD.encode(payload), signature)
+    }
+
+    #[test]
+    fn signed_ticket_round_trips() {
+        let signer = TicketSigner::new(SECRET).unwrap();
+        let token = signer.sign(&claims());
+
+        assert_eq!(signer.verify(&token, utc(2025, 5, 1, 10, 0)), Ok(claims()));
+    }
+
+    #[test]
+    fn tampered_payload_is_rejected() {
+        let signer = TicketSigner::new(SECRET).unwrap();
+        let token = signer.sign(&claims());
+
+        // 인원을 늘린 payload
+        let tampered = with_payload(&token, "v2:42:7:5:1:1746097200:user-1");
+        assert!(signer.verify(&tampered, utc(2025, 5, 1, 10, 0)).is_err());
+        assert!(signer.verify("not-a-ticket", utc(2025, 5, 1, 10, 0)).is_err());
+    }
+
+    #[test]
+    fn ticket_signed_with_another_secret_is_rejected() {
+        let signer = TicketSigner::new(SECRET).unwrap();
+        let other = TicketSigner::new("another-ticket-secret-0123456789ab").unwrap();
+
+        assert!(other.verify(&signer.sign(&claims()), utc(2025, 5, 1, 10, 0)).is_err());
+    }
+
+    #[test]
+    fn ticket_is_bound_to_owner_and_schedule() {
+        let signer = TicketSigner::new(SECRET).unwrap();
+        let token = signer.sign(&claims());
+
+        // 다른 사용자 / 다른 회차로 바꾼 payload 는 서명 불일치
+        assert!(signer.verify(&with_payload(&token, "v2:42:7:2:1:1746097200:user-2"), utc(2025, 5, 1, 10, 0)).is_err());
+        assert!(signer.verify(&with_payload(&token, "v2:42:8:2:1:1746097200:user-1"), utc(2025, 5, 1, 10, 0)).is_err());
+
+        // ':' 가 들어간 사용자 ID 도 그대로 복원
+        let owner = TicketClaims { user_id: "org:team:1".to_string(), ..claims() };
+        let verified = signer.verify(&signer.sign(&owner), utc(2025, 5, 1, 10, 0)).unwrap();
+        assert_eq!((verified.user_id.as_str(), verified.content_schedule_id), ("org:team:1", 7));
+    }
+
+    #[test]
+    fn expired_ticket_and_short_secret_are_rejected() {
+        let signer = TicketSigner::new(SECRET).unwrap();
+        let token = signer.sign(&claims());
+
+        assert!(signer.verify(&token, utc(2025, 5, 1, 11, 0)).is_err());
+        assert!(TicketSigner::new("too-short").is_err());
+    }
+}
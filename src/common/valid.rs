@@ -1,4 +1,5 @@
 use actix_web::HttpResponse;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use crate::grpc_client::GrpcClients;
@@ -24,4 +25,67 @@ pub async fn validate_user_token(
             Err(HttpResponse::InternalServerError().json("Auth Service Error"))
         }
     }
+}
+
+/// 직원 / 티켓 스캐너 권한 (설정의 사용자 ID 목록 기준)
+#[derive(Debug, Clone, Default)]
+pub struct StaffRoles {
+    staff: HashSet<String>,
+    scanners: HashSet<String>,
+}
+
+impl StaffRoles {
+    /// 쉼표로 구분된 사용자 ID 목록
+    pub fn new(staff_user_ids: &str, scanner_user_ids: &str) -> Self {
+        let staff = Self::parse_ids(staff_user_ids);
+        if staff.is_empty() {
+            eprintln!("⚠️ staff_user_ids 가 비어 있어 관리자 API 를 사용할 수 없습니다.");
+        }
+        Self { staff, scanners: Self::parse_ids(scanner_user_ids) }
+    }
+
+    fn parse_ids(user_ids: &str) -> HashSet<String> {
+        user_ids
+            .split(',')
+            .map(|user_id| user_id.trim().to_string())
+            .filter(|user_id| !user_id.is_empty())
+            .collect()
+    }
+
+    pub fn is_staff(&self, user_id: &str) -> bool {
+        self.staff.contains(user_id)
+    }
+
+    /// 입장 티켓 스캔 가능 여부 (스캐너 계정 또는 직원)
+    pub fn can_scan(&self, user_id: &str) -> bool {
+        self.scanners.contains(user_id) || self.is_staff(user_id)
+    }
+}
+
+/// 토큰 검증 후 직원 여부까지 확인하는 공통 함수 (관리자 API)
+pub async fn validate_staff_token(
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+    roles: &StaffRoles,
+    token: &str
+) -> Result<String, HttpResponse> {
+    let user_id = validate_user_token(grpc_clients, token).await?;
+    if !roles.is_staff(&user_id) {
+        println!("직원이 아닌 사용자의 관리자 API 요청: {}", user_id);
+        return Err(HttpResponse::Forbidden().json("Staff Only"));
+    }
+    Ok(user_id)
+}
+
+/// 토큰 검증 후 티켓 스캔 권한까지 확인하는 공통 함수 (입장 처리)
+pub async fn validate_scanner_token(
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+    roles: &StaffRoles,
+    token: &str
+) -> Result<String, HttpResponse> {
+    let user_id = validate_user_token(grpc_clients, token).await?;
+    if !roles.can_scan(&user_id) {
+        println!("스캔 권한이 없는 사용자의 입장 처리 요청: {}", user_id);
+        return Err(HttpResponse::Forbidden().json("Scanner Only"));
+    }
+    Ok(user_id)
 }
\ No newline at end of file
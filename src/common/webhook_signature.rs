@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+// 웹훅 서명 헤더 값 생성
+// 형식: "t={unix 초},v1={hex(HMAC-SHA256(secret, "{t}.{body}"))}"
+// 수신 측은 같은 방식으로 계산해 비교하고, t 로 오래된 요청(재전송 공격)을 거부
+pub fn sign(secret: &str, timestamp: DateTime<Utc>, body: &str) -> String {
+    let t = timestamp.timestamp();
+    format!("t={},v1={}", t, to_hex(&mac(secret, t, body).finalize().into_bytes()))
+}
+
+// 서명 헤더 검증 (tolerance_secs 보다 오래된 서명은 거부)
+pub fn verify(secret: &str, header: &str, body: &str, now: DateTime<Utc>, tolerance_secs: i64) -> bool {
+    let mut timestamp = None;
+    let mut signature = None;
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => signature = from_hex(value),
+            _ => {},
+        }
+    }
+    let (Some(t), Some(signature)) = (timestamp, signature) else {
+        return false;
+    };
+    if (now.timestamp() - t).abs() > tolerance_secs {
+        return false;
+    }
+    // 서명 비교는 상수 시간으로 수행
+    mac(secret, t, body).verify_slice(&signature).is_ok()
+}
+
+fn mac(secret: &str, timestamp: i64, body: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC은 모든 키 길이를 허용");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    mac
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(value: &str) -> Option<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const SECRET: &str = "whsec_test_secret";
+    const BODY: &str = r#"{"event":"reservation.created","reservation_id":42}"#;
+
+    #[test]
+    fn signature_matches_fixed_hmac_vector() {
+        // 수신 측 검증용 기준값: HMAC-SHA256("whsec_test_secret", "1746093600." + BODY)
+        let timestamp = Utc.with_ymd_and_hms(2025, 5, 1, 10, 0, 0).unwrap();
+
+        assert_eq!(
+            sign(SECRET, timestamp, BODY),
+            "t=1746093600,v1=9dcbbcfaa27f3f6048f6cff6b6ca6f95d3d24c13855d9bbaf8b913254e97b57d"
+        );
+    }
+
+    #[test]
+    fn verify_accepts_own_signature_and_rejects_changes() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 5, 1, 10, 0, 0).unwrap();
+        let header = sign(SECRET, timestamp, BODY);
+
+        assert!(verify(SECRET, &header, BODY, timestamp, 300));
+        assert!(!verify(SECRET, &header, r#"{"event":"reservation.created","reservation_id":43}"#, timestamp, 300));
+        assert!(!verify("another_secret", &header, BODY, timestamp, 300));
+        assert!(!verify(SECRET, &header, BODY, timestamp + chrono::Duration::seconds(301), 300));
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+
+// 시작 시간 기준으로 제한되는 예약 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookingAction {
+    Create,
+    ChangeCount,
+    Cancel,
+}
+
+impl fmt::Display for BookingAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action_str = match self {
+            BookingAction::Create => "예약",
+            BookingAction::ChangeCount => "인원 변경",
+            BookingAction::Cancel => "예약 취소",
+        };
+        write!(f, "{}", action_str)
+    }
+}
+
+// 작업별 마감 시각 (시작 N분 전, 음수면 시작 후 N분까지 허용)
+#[derive(Debug, Clone, Copy)]
+pub struct BookingWindow {
+    pub create_close_minutes_before: i64,
+    pub change_count_close_minutes_before: i64,
+    pub cancel_close_minutes_before: i64,
+}
+
+impl BookingWindow {
+    pub fn closes_at(&self, action: BookingAction, start_time: DateTime<Utc>) -> DateTime<Utc> {
+        let minutes_before = match action {
+            BookingAction::Create => self.create_close_minutes_before,
+            BookingAction::ChangeCount => self.change_count_close_minutes_before,
+            BookingAction::Cancel => self.cancel_close_minutes_before,
+        };
+        start_time - Duration::minutes(minutes_before)
+    }
+
+    // 마감 시각 이전이면 허용
+    pub fn check(&self, action: BookingAction, start_time: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), String> {
+        let closes_at = self.closes_at(action, start_time);
+        if now >= closes_at {
+            return Err(format!(
+                "{} 가능 시간이 지났습니다. (회차 시작 {}, 마감 {})",
+                action, start_time.to_rfc3339(), closes_at.to_rfc3339()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::common::clock::{Clock, FixedClock};
+
+    use super::*;
+
+    fn window() -> BookingWindow {
+        BookingWindow {
+            create_close_minutes_before: 30,
+            change_count_close_minutes_before: 60,
+            cancel_close_minutes_before: -10, // 시작 후 10분까지 취소 허용
+        }
+    }
+
+    fn start_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 10, 0, 0).unwrap()
+    }
+
+    fn check_at(action: BookingAction, clock: &dyn Clock) -> Result<(), String> {
+        window().check(action, start_time(), clock.now())
+    }
+
+    #[test]
+    fn create_is_allowed_until_close_time() {
+        let before_close = FixedClock(start_time() - Duration::minutes(30) - Duration::seconds(1));
+        let at_close = FixedClock(start_time() - Duration::minutes(30));
+
+        assert!(check_at(BookingAction::Create, &before_close).is_ok());
+        assert!(check_at(BookingAction::Create, &at_close).is_err()); // 마감 시각 자체는 불가
+    }
+
+    #[test]
+    fn each_action_uses_its_own_close_time() {
+        let clock = FixedClock(start_time() - Duration::minutes(45));
+
+        assert!(check_at(BookingAction::Create, &clock).is_ok());
+        assert!(check_at(BookingAction::ChangeCount, &clock).is_err());
+        assert!(check_at(BookingAction::Cancel, &clock).is_ok());
+    }
+
+    #[test]
+    fn negative_close_minutes_allow_action_after_start() {
+        let after_start = FixedClock(start_time() + Duration::minutes(9));
+        let after_close = FixedClock(start_time() + Duration::minutes(10));
+
+        assert!(check_at(BookingAction::Cancel, &after_start).is_ok());
+        assert!(check_at(BookingAction::Cancel, &after_close).is_err());
+        assert_eq!(window().closes_at(BookingAction::Cancel, start_time()), start_time() + Duration::minutes(10));
+    }
+}
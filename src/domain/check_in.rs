@@ -0,0 +1,44 @@
+use chrono::{DateTime, Duration, Utc};
+
+use super::reservation::Reservation;
+
+// 입장 가능 시간 (회차 시작 N분 전 ~ 시작 후 N분)
+#[derive(Debug, Clone, Copy)]
+pub struct CheckInWindow {
+    pub opens_minutes_before: i64,
+    pub closes_minutes_after: i64,
+}
+
+impl CheckInWindow {
+    pub fn opens_at(&self, start_time: DateTime<Utc>) -> DateTime<Utc> {
+        start_time - Duration::minutes(self.opens_minutes_before)
+    }
+
+    pub fn closes_at(&self, start_time: DateTime<Utc>) -> DateTime<Utc> {
+        start_time + Duration::minutes(self.closes_minutes_after)
+    }
+
+    pub fn check(&self, start_time: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), String> {
+        let opens_at = self.opens_at(start_time);
+        let closes_at = self.closes_at(start_time);
+        if now < opens_at {
+            return Err(format!("입장은 {}부터 가능합니다.", opens_at.to_rfc3339()));
+        }
+        if now >= closes_at {
+            return Err(format!("입장 가능 시간이 지났습니다. (마감 {})", closes_at.to_rfc3339()));
+        }
+        Ok(())
+    }
+}
+
+// 체크인 결과
+#[derive(Debug, Clone)]
+pub enum CheckInOutcome {
+    CheckedIn(Reservation),    // 입장 처리 완료 (부분 입장 포함)
+    InvalidTicket(String),     // 서명 불일치 / 만료 / 예약 정보 변경
+    InvalidPartySize(String),  // 입장 인원이 0명이거나 남은 인원 초과
+    WrongSchedule,           // 다른 회차의 티켓
+    OutsideWindow(String),   // 입장 가능 시간 외
+    AlreadyUsed,             // 이미 사용된 티켓
+    Cancelled,               // 취소된 예약
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Duration, Utc};
+
+use super::ticket_category::TicketCategory;
+
+// 컨텐츠별 예약 규칙 (None 이면 해당 규칙 미적용)
+#[derive(Debug, Clone, Default)]
+pub struct ContentBookingRule {
+    pub content_id: u64,
+    pub children_only: bool,
+    pub max_party_size: Option<i32>,
+    pub max_adults: Option<i32>,
+    pub max_children: Option<i32>,
+    pub booking_opens_days_before: Option<i32>,
+    pub booking_closes_minutes_before: Option<i32>,
+    pub categories: Vec<CategoryRule>,
+}
+
+// 컨텐츠별 권종 규칙 (None 이면 해당 규칙 미적용, 0 이면 해당 권종 예약 불가)
+#[derive(Debug, Clone, Copy)]
+pub struct CategoryRule {
+    pub category: TicketCategory,
+    pub max_per_reservation: Option<i32>,
+    pub max_per_schedule: Option<i32>,
+}
+
+impl ContentBookingRule {
+    // 예약 가능 시작 시각 (시작 시간 기준 N일 전)
+    pub fn booking_opens_at(&self, start_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.booking_opens_days_before
+            .map(|days| start_time - Duration::days(days.into()))
+    }
+
+    // 예약 마감 시각 (시작 시간 기준 N분 전)
+    pub fn booking_closes_at(&self, start_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.booking_closes_minutes_before
+            .map(|minutes| start_time - Duration::minutes(minutes.into()))
+    }
+
+    pub fn category_rule(&self, category: TicketCategory) -> Option<&CategoryRule> {
+        self.categories.iter().find(|rule| rule.category == category)
+    }
+}
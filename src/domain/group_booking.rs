@@ -0,0 +1,138 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+
+// 단체 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrganisationType {
+    School,
+    Agency,
+    Other,
+}
+
+impl fmt::Display for OrganisationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_str = match self {
+            OrganisationType::School => "SCHOOL",
+            OrganisationType::Agency => "AGENCY",
+            OrganisationType::Other => "OTHER",
+        };
+        write!(f, "{}", type_str)
+    }
+}
+
+impl FromStr for OrganisationType {
+    type Err = ();
+
+    fn from_str(org_type: &str) -> Result<Self, Self::Err> {
+        match org_type {
+            "SCHOOL" => Ok(Self::School),
+            "AGENCY" => Ok(Self::Agency),
+            "OTHER" => Ok(Self::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+// 단체 계정 (소속 사용자가 단체 예약을 신청)
+#[derive(Debug, Clone)]
+pub struct Organisation {
+    pub id: i64,
+    pub name: String,
+    pub org_type: OrganisationType,
+    pub max_party_size: i32, // 회차별 최대 인원 (개인 예약의 인원 제한 대신 적용)
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+// 회차별 단체 전용 좌석
+#[derive(Debug, Clone)]
+pub struct GroupQuota {
+    pub content_schedule_id: u64,
+    pub quota_seats: i32,
+    pub held_seats: i32, // 승인 대기 + 승인된 단체 예약 인원
+}
+
+impl GroupQuota {
+    pub fn seats_remaining(&self) -> i32 {
+        (self.quota_seats - self.held_seats).max(0)
+    }
+}
+
+// 단체 예약 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupReservationStatus {
+    Requested, // 직원 승인 대기 (단체 좌석은 이미 확보)
+    Approved,  // 회차별 예약 생성 완료
+    Rejected,
+    Cancelled,
+}
+
+impl GroupReservationStatus {
+    // 좌석을 점유하고 있는 상태인지
+    pub fn holds_seats(&self) -> bool {
+        matches!(self, Self::Requested | Self::Approved)
+    }
+}
+
+impl fmt::Display for GroupReservationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            GroupReservationStatus::Requested => "REQUESTED",
+            GroupReservationStatus::Approved => "APPROVED",
+            GroupReservationStatus::Rejected => "REJECTED",
+            GroupReservationStatus::Cancelled => "CANCELLED",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+impl FromStr for GroupReservationStatus {
+    type Err = ();
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "REQUESTED" => Ok(Self::Requested),
+            "APPROVED" => Ok(Self::Approved),
+            "REJECTED" => Ok(Self::Rejected),
+            "CANCELLED" => Ok(Self::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+// 단체 예약의 회차별 인원
+#[derive(Debug, Clone)]
+pub struct GroupReservationItem {
+    pub content_schedule_id: u64,
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+    pub reservation_id: Option<i32>, // 승인 후 생성된 예약
+}
+
+impl GroupReservationItem {
+    pub fn party_size(&self) -> i32 {
+        self.ad_cnt + self.cd_cnt
+    }
+}
+
+// 단체 예약 (여러 회차를 묶어 한 번에 승인 / 반려)
+#[derive(Debug, Clone)]
+pub struct GroupReservation {
+    pub id: i64,
+    pub organisation_id: i64,
+    pub requested_by: String,
+    pub status: GroupReservationStatus,
+    pub note: Option<String>,
+    pub reviewed_by: Option<String>,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub items: Vec<GroupReservationItem>,
+}
+
+impl GroupReservation {
+    pub fn total_party_size(&self) -> i32 {
+        self.items.iter().map(|item| item.party_size()).sum()
+    }
+}
@@ -0,0 +1,15 @@
+// 멱등성 키로 저장된 최초 응답
+#[derive(Debug, Clone)]
+pub struct IdempotentResponse {
+    pub status_code: u16,
+    pub message: String,
+}
+
+// 멱등성 키 선점 결과
+#[derive(Debug, Clone)]
+pub enum IdempotencyClaim {
+    Acquired,                     // 최초 요청 → 처리 진행
+    Replay(IdempotentResponse),   // 이미 처리 완료 → 저장된 응답 반환
+    InProgress,                   // 동일 키 요청이 처리 중
+    Mismatch,                     // 동일 키로 다른 내용의 요청
+}
@@ -1 +1,17 @@
-pub mod reservation;
\ No newline at end of file
+pub mod reservation;
+pub mod schedule_availability;
+pub mod idempotency;
+pub mod policy_violation;
+pub mod content_rule;
+pub mod booking_window;
+pub mod check_in;
+pub mod reservation_history;
+pub mod reservation_event;
+pub mod webhook;
+pub mod reminder;
+pub mod notification;
+pub mod group_booking;
+pub mod reservation_transfer;
+pub mod ticket_category;
+pub mod pricing;
+pub mod payment;
\ No newline at end of file
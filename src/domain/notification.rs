@@ -0,0 +1,170 @@
+use std::{fmt, str::FromStr};
+
+// 예약 알림 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    Confirmed, // 예약 완료
+    Cancelled,
+    Changed,   // 인원 변경
+    Moved,     // 회차 변경
+    TransferRequested, // 양도 요청 (받는 사용자에게)
+    Transferred,       // 양도 완료 (새 소유자에게)
+    PaymentExpired,    // 결제 실패 / 기한 초과로 예약 취소
+    Refunded,          // 취소 후 환불 완료
+}
+
+impl fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind_str = match self {
+            NotificationKind::Confirmed => "RESERVATION_CONFIRMED",
+            NotificationKind::Cancelled => "RESERVATION_CANCELLED",
+            NotificationKind::Changed => "RESERVATION_CHANGED",
+            NotificationKind::Moved => "RESERVATION_MOVED",
+            NotificationKind::TransferRequested => "RESERVATION_TRANSFER_REQUESTED",
+            NotificationKind::Transferred => "RESERVATION_TRANSFERRED",
+            NotificationKind::PaymentExpired => "RESERVATION_PAYMENT_EXPIRED",
+            NotificationKind::Refunded => "RESERVATION_REFUNDED",
+        };
+        write!(f, "{}", kind_str)
+    }
+}
+
+// 알림 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    Ko,
+    En,
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(locale: &str) -> Result<Self, Self::Err> {
+        match locale.to_ascii_lowercase().as_str() {
+            "ko" | "ko-kr" => Ok(Self::Ko),
+            "en" | "en-us" => Ok(Self::En),
+            _ => Err(format!("지원하지 않는 알림 언어입니다: {}", locale)),
+        }
+    }
+}
+
+// 템플릿에 채울 값
+#[derive(Debug, Clone)]
+pub struct NotificationVars {
+    pub reservation_id: i32,
+    pub content_title: Option<String>,
+    pub start_time: Option<String>, // 영업 시간대 기준 "YYYY-MM-DD HH:MM"
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+}
+
+// 발송할 예약 알림 (사용자 1명)
+#[derive(Debug, Clone)]
+pub struct NotificationMessage {
+    pub user_id: String,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub body: String,
+    pub data: Vec<(String, String)>,
+    pub dedup_key: String,
+}
+
+impl NotificationMessage {
+    pub fn render(user_id: String, kind: NotificationKind, locale: Locale, vars: &NotificationVars, dedup_key: String) -> Self {
+        let (title, body) = template(kind, locale);
+        let (default_title, default_time) = match locale {
+            Locale::Ko => ("예약하신 컨텐츠", "예정된"),
+            Locale::En => ("your session", "the scheduled"),
+        };
+        let fill = |template: &str| {
+            template
+                .replace("{content_title}", vars.content_title.as_deref().unwrap_or(default_title))
+                .replace("{start_time}", vars.start_time.as_deref().unwrap_or(default_time))
+                .replace("{ad_cnt}", &vars.ad_cnt.to_string())
+                .replace("{cd_cnt}", &vars.cd_cnt.to_string())
+        };
+
+        Self {
+            user_id,
+            kind,
+            title: fill(title),
+            body: fill(body),
+            data: vec![
+                ("type".to_string(), kind.to_string()),
+                ("reservation_id".to_string(), vars.reservation_id.to_string()),
+            ],
+            dedup_key,
+        }
+    }
+}
+
+// 알림 문구 (제목, 본문)
+fn template(kind: NotificationKind, locale: Locale) -> (&'static str, &'static str) {
+    match (locale, kind) {
+        (Locale::Ko, NotificationKind::Confirmed) => (
+            "예약이 완료되었습니다",
+            "{content_title} {start_time} 회차 예약이 완료되었습니다. (성인 {ad_cnt}명, 어린이 {cd_cnt}명)",
+        ),
+        (Locale::Ko, NotificationKind::Cancelled) => (
+            "예약이 취소되었습니다",
+            "{content_title} {start_time} 회차 예약이 취소되었습니다.",
+        ),
+        (Locale::Ko, NotificationKind::Changed) => (
+            "예약 인원이 변경되었습니다",
+            "{content_title} {start_time} 회차 예약 인원이 성인 {ad_cnt}명, 어린이 {cd_cnt}명으로 변경되었습니다.",
+        ),
+        (Locale::Ko, NotificationKind::Moved) => (
+            "예약 회차가 변경되었습니다",
+            "예약이 {content_title} {start_time} 회차로 변경되었습니다. (성인 {ad_cnt}명, 어린이 {cd_cnt}명)",
+        ),
+        (Locale::Ko, NotificationKind::TransferRequested) => (
+            "예약 양도 요청이 도착했습니다",
+            "{content_title} {start_time} 회차 예약(성인 {ad_cnt}명, 어린이 {cd_cnt}명)을 양도받으시겠어요? 기한 내에 수락해 주세요.",
+        ),
+        (Locale::Ko, NotificationKind::Transferred) => (
+            "예약을 양도받았습니다",
+            "{content_title} {start_time} 회차 예약(성인 {ad_cnt}명, 어린이 {cd_cnt}명)이 회원님 소유로 변경되었습니다.",
+        ),
+        (Locale::Ko, NotificationKind::PaymentExpired) => (
+            "결제가 완료되지 않아 예약이 취소되었습니다",
+            "{content_title} {start_time} 회차 예약의 결제가 완료되지 않아 예약이 취소되었습니다. 다시 예약해 주세요.",
+        ),
+        (Locale::Ko, NotificationKind::Refunded) => (
+            "환불이 완료되었습니다",
+            "취소하신 {content_title} {start_time} 회차 예약의 환불이 완료되었습니다.",
+        ),
+        (Locale::En, NotificationKind::Confirmed) => (
+            "Your reservation is confirmed",
+            "Your reservation for {content_title} at {start_time} is confirmed. ({ad_cnt} adults, {cd_cnt} children)",
+        ),
+        (Locale::En, NotificationKind::Cancelled) => (
+            "Your reservation was cancelled",
+            "Your reservation for {content_title} at {start_time} was cancelled.",
+        ),
+        (Locale::En, NotificationKind::Changed) => (
+            "Your party size was updated",
+            "Your reservation for {content_title} at {start_time} is now for {ad_cnt} adults and {cd_cnt} children.",
+        ),
+        (Locale::En, NotificationKind::Moved) => (
+            "Your session was changed",
+            "Your reservation was moved to {content_title} at {start_time}. ({ad_cnt} adults, {cd_cnt} children)",
+        ),
+        (Locale::En, NotificationKind::TransferRequested) => (
+            "A reservation is being transferred to you",
+            "Someone wants to transfer their reservation for {content_title} at {start_time} ({ad_cnt} adults, {cd_cnt} children) to you. Accept it before it expires.",
+        ),
+        (Locale::En, NotificationKind::Transferred) => (
+            "A reservation was transferred to you",
+            "The reservation for {content_title} at {start_time} ({ad_cnt} adults, {cd_cnt} children) is now yours.",
+        ),
+        (Locale::En, NotificationKind::PaymentExpired) => (
+            "Your reservation was cancelled",
+            "Payment for your reservation for {content_title} at {start_time} was not completed, so the reservation was cancelled. Please book again.",
+        ),
+        (Locale::En, NotificationKind::Refunded) => (
+            "Your refund is complete",
+            "The refund for your cancelled reservation for {content_title} at {start_time} is complete.",
+        ),
+    }
+}
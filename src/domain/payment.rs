@@ -0,0 +1,170 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+
+// 결제 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentStatus {
+    Pending,       // 결제창 생성, 결제 결과 대기
+    Paid,
+    Failed,        // 게이트웨이에서 결제 실패 통보
+    Expired,       // 결제 기한 초과 / 결제 전 예약 취소
+    RefundPending, // 환불 요청 (게이트웨이 환불 실패 시 재시도)
+    Refunded,
+}
+
+impl fmt::Display for PaymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            PaymentStatus::Pending => "PENDING",
+            PaymentStatus::Paid => "PAID",
+            PaymentStatus::Failed => "FAILED",
+            PaymentStatus::Expired => "EXPIRED",
+            PaymentStatus::RefundPending => "REFUND_PENDING",
+            PaymentStatus::Refunded => "REFUNDED",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+impl FromStr for PaymentStatus {
+    type Err = ();
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "PENDING" => Ok(Self::Pending),
+            "PAID" => Ok(Self::Paid),
+            "FAILED" => Ok(Self::Failed),
+            "EXPIRED" => Ok(Self::Expired),
+            "REFUND_PENDING" => Ok(Self::RefundPending),
+            "REFUNDED" => Ok(Self::Refunded),
+            _ => Err(()),
+        }
+    }
+}
+
+// PAYMENT 한 건
+#[derive(Debug, Clone)]
+pub struct Payment {
+    pub id: i64,
+    pub reservation_id: i32,
+    pub user_id: String,
+    pub provider: String,
+    pub provider_payment_id: Option<String>,
+    pub checkout_url: Option<String>,
+    pub amount: i64,
+    pub currency: String,
+    pub status: PaymentStatus,
+    pub refund_amount: Option<i64>,
+    pub failure_reason: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub refunded_at: Option<DateTime<Utc>>,
+}
+
+// 게이트웨이 결제창 생성 요청 (payment_id 는 게이트웨이 측 주문 번호 / 멱등성 키로 사용)
+#[derive(Debug, Clone)]
+pub struct PaymentRequest {
+    pub payment_id: i64,
+    pub reservation_id: i32,
+    pub user_id: String,
+    pub amount: i64,
+    pub currency: String,
+}
+
+// 게이트웨이가 생성한 결제창
+#[derive(Debug, Clone)]
+pub struct PaymentCheckout {
+    pub provider_payment_id: String,
+    pub checkout_url: String,
+}
+
+// 게이트웨이 결제 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentResult {
+    Paid,
+    Failed(String), // 실패 사유
+}
+
+// 서명 검증을 마친 게이트웨이 콜백
+#[derive(Debug, Clone)]
+pub struct PaymentCallback {
+    pub provider_payment_id: String,
+    pub amount: Option<i64>, // 게이트웨이가 알려준 결제 금액 (결제 완료 시 필수)
+    pub result: PaymentResult,
+}
+
+// 결제 완료 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentConfirmation {
+    Confirmed,        // 결제 대기 예약 → 예약 완료
+    AlreadyProcessed, // 같은 콜백 재전송
+    Late,             // 결제 기한이 지나 예약이 이미 해제됨 → 전액 환불 대상
+}
+
+// 취소 시점별 환불 정책 (회차 시작 기준)
+#[derive(Debug, Clone, Copy)]
+pub struct RefundPolicy {
+    pub full_refund_minutes_before: i64,    // 이 시간 이전 취소는 전액 환불
+    pub partial_refund_minutes_before: i64, // 이 시간 이전 취소는 부분 환불, 이후는 환불 없음
+    pub partial_refund_percent: i32,
+}
+
+impl RefundPolicy {
+    // 시작 시간이 없는 회차는 전액 환불
+    pub fn refund_amount(&self, paid_amount: i64, start_time: Option<DateTime<Utc>>, now: DateTime<Utc>) -> i64 {
+        let Some(start_time) = start_time else {
+            return paid_amount;
+        };
+        let minutes_before = (start_time - now).num_minutes();
+        if minutes_before >= self.full_refund_minutes_before {
+            paid_amount
+        } else if minutes_before >= self.partial_refund_minutes_before {
+            paid_amount * i64::from(self.partial_refund_percent.clamp(0, 100)) / 100
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    fn policy() -> RefundPolicy {
+        RefundPolicy {
+            full_refund_minutes_before: 24 * 60, // 하루 전까지 전액
+            partial_refund_minutes_before: 60,   // 1시간 전까지 부분 환불
+            partial_refund_percent: 30,
+        }
+    }
+
+    fn start_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 5, 10, 1, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn refund_tier_depends_on_minutes_before_start() {
+        let refund_at = |before: Duration| policy().refund_amount(10_000, Some(start_time()), start_time() - before);
+
+        assert_eq!(refund_at(Duration::days(2)), 10_000);
+        assert_eq!(refund_at(Duration::minutes(24 * 60)), 10_000); // 경계는 전액
+        assert_eq!(refund_at(Duration::minutes(24 * 60 - 1)), 3_000);
+        assert_eq!(refund_at(Duration::minutes(60)), 3_000); // 경계는 부분 환불
+        assert_eq!(refund_at(Duration::minutes(59)), 0);
+        assert_eq!(refund_at(Duration::minutes(-10)), 0); // 시작 이후
+    }
+
+    #[test]
+    fn schedule_without_start_time_is_fully_refunded() {
+        assert_eq!(policy().refund_amount(10_000, None, start_time()), 10_000);
+    }
+
+    #[test]
+    fn partial_refund_percent_is_clamped() {
+        let policy = RefundPolicy { partial_refund_percent: 150, ..policy() };
+        assert_eq!(policy.refund_amount(10_000, Some(start_time()), start_time() - Duration::minutes(90)), 10_000);
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+
+// 예약 정책 위반 내역 (어떤 규칙이 왜 거절했는지)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyViolation {
+    pub rule: &'static str,
+    pub message: String,
+}
+
+impl PolicyViolation {
+    pub fn new(rule: &'static str, message: impl Into<String>) -> Self {
+        Self { rule, message: message.into() }
+    }
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.rule, self.message)
+    }
+}
+
+// 예약 생성 결과 (정책 위반은 오류가 아닌 거절 결과로 반환)
+#[derive(Debug, Clone)]
+pub enum BookingOutcome {
+    Created,
+    Rejected(Vec<PolicyViolation>), // 위반된 규칙 전체
+}
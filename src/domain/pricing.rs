@@ -0,0 +1,278 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+
+use super::ticket_category::{PartyComposition, TicketCategory};
+
+// 컨텐츠별 권종 단가
+#[derive(Debug, Clone, Copy)]
+pub struct ContentPrice {
+    pub category: TicketCategory,
+    pub unit_price: i64,
+}
+
+// 할인 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscountType {
+    Member,     // 회원
+    Group,      // 일정 인원 이상
+    TimeOfDay,  // 회차 시작 시각 (조조 / 야간 등)
+}
+
+impl fmt::Display for DiscountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let discount_str = match self {
+            DiscountType::Member => "MEMBER",
+            DiscountType::Group => "GROUP",
+            DiscountType::TimeOfDay => "TIME_OF_DAY",
+        };
+        write!(f, "{}", discount_str)
+    }
+}
+
+impl FromStr for DiscountType {
+    type Err = ();
+
+    fn from_str(discount_type: &str) -> Result<Self, Self::Err> {
+        match discount_type {
+            "MEMBER" => Ok(Self::Member),
+            "GROUP" => Ok(Self::Group),
+            "TIME_OF_DAY" => Ok(Self::TimeOfDay),
+            _ => Err(()),
+        }
+    }
+}
+
+// 할인 규칙 (content_id 가 None 이면 전체 컨텐츠)
+#[derive(Debug, Clone)]
+pub struct PricingDiscount {
+    pub id: i64,
+    pub content_id: Option<u64>,
+    pub discount_type: DiscountType,
+    pub rate_percent: i32,
+    pub min_party_size: Option<i32>,
+    pub start_hour: Option<u32>,
+    pub end_hour: Option<u32>,
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+// 할인 적용 여부 판단에 필요한 값
+#[derive(Debug, Clone, Copy)]
+pub struct PricingConditions {
+    pub is_member: bool,
+    pub party_size: i32,
+    pub local_start_hour: Option<u32>, // 영업 시간대 기준 회차 시작 시각 (시작 시간이 없는 회차는 None)
+}
+
+impl PricingDiscount {
+    pub fn applies(&self, conditions: &PricingConditions) -> bool {
+        if !self.active {
+            return false;
+        }
+        match self.discount_type {
+            DiscountType::Member => conditions.is_member,
+            DiscountType::Group => self.min_party_size.is_some_and(|min| conditions.party_size >= min),
+            DiscountType::TimeOfDay => match (self.start_hour, self.end_hour, conditions.local_start_hour) {
+                // 시작 > 종료 이면 자정을 넘는 구간 (예: 22시 ~ 6시)
+                (Some(start), Some(end), Some(hour)) if start <= end => start <= hour && hour < end,
+                (Some(start), Some(end), Some(hour)) => hour >= start || hour < end,
+                _ => false,
+            },
+        }
+    }
+
+    pub fn amount_off(&self, list_price: i64) -> i64 {
+        list_price * i64::from(self.rate_percent.clamp(0, 100)) / 100
+    }
+}
+
+// 권종별 금액
+#[derive(Debug, Clone, Copy)]
+pub struct PriceLine {
+    pub category: TicketCategory,
+    pub count: i32,
+    pub unit_price: i64,
+    pub amount: i64,
+}
+
+// 적용된 할인
+#[derive(Debug, Clone, Copy)]
+pub struct AppliedDiscount {
+    pub discount_id: i64,
+    pub discount_type: DiscountType,
+    pub rate_percent: i32,
+    pub amount: i64,
+}
+
+// 예약 금액 견적
+#[derive(Debug, Clone)]
+pub struct PriceQuote {
+    pub content_id: u64,
+    pub content_schedule_id: u64,
+    pub lines: Vec<PriceLine>,
+    pub list_price: i64,
+    pub discount: Option<AppliedDiscount>,
+    pub total_price: i64,
+    pub currency: String,
+}
+
+impl PriceQuote {
+    // 권종별 단가 합계에서 할인 금액이 가장 큰 규칙 1개만 적용 (중복 할인 없음)
+    // 가격이 하나도 등록되지 않은 컨텐츠는 무료, 일부만 등록된 경우 미등록 권종은 예약 불가
+    pub fn calculate(
+        content_id: u64,
+        content_schedule_id: u64,
+        party: &PartyComposition,
+        prices: &[ContentPrice],
+        discounts: &[PricingDiscount],
+        conditions: &PricingConditions,
+        currency: &str,
+    ) -> Result<Self, String> {
+        let mut lines = Vec::new();
+        for (category, count) in party.iter() {
+            let unit_price = if prices.is_empty() {
+                0
+            } else {
+                prices
+                    .iter()
+                    .find(|price| price.category == category)
+                    .map(|price| price.unit_price)
+                    .ok_or(format!("{} 권종은 가격이 등록되지 않아 예약할 수 없습니다.", category.label()))?
+            };
+            lines.push(PriceLine { category, count, unit_price, amount: unit_price * i64::from(count) });
+        }
+
+        let list_price: i64 = lines.iter().map(|line| line.amount).sum();
+        let discount = discounts
+            .iter()
+            .filter(|discount| discount.applies(conditions))
+            .map(|discount| AppliedDiscount {
+                discount_id: discount.id,
+                discount_type: discount.discount_type,
+                rate_percent: discount.rate_percent,
+                amount: discount.amount_off(list_price),
+            })
+            .filter(|discount| discount.amount > 0)
+            .max_by_key(|discount| discount.amount);
+
+        Ok(Self {
+            content_id,
+            content_schedule_id,
+            total_price: list_price - discount.map(|discount| discount.amount).unwrap_or(0),
+            lines,
+            list_price,
+            discount,
+            currency: currency.to_string(),
+        })
+    }
+
+    pub fn to_reservation_price(&self) -> ReservationPrice {
+        ReservationPrice {
+            list_price: self.list_price,
+            total_price: self.total_price,
+            discount_type: self.discount.map(|discount| discount.discount_type),
+            currency: self.currency.clone(),
+        }
+    }
+}
+
+// 예약에 저장되는 금액 (예약 시점 견적)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservationPrice {
+    pub list_price: i64,
+    pub total_price: i64,
+    pub discount_type: Option<DiscountType>,
+    pub currency: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices() -> Vec<ContentPrice> {
+        vec![
+            ContentPrice { category: TicketCategory::Adult, unit_price: 10_000 },
+            ContentPrice { category: TicketCategory::Child, unit_price: 6_000 },
+        ]
+    }
+
+    fn discount(id: i64, discount_type: DiscountType, rate_percent: i32) -> PricingDiscount {
+        PricingDiscount {
+            id,
+            content_id: None,
+            discount_type,
+            rate_percent,
+            min_party_size: None,
+            start_hour: None,
+            end_hour: None,
+            active: true,
+            created_at: None,
+        }
+    }
+
+    fn discounts() -> Vec<PricingDiscount> {
+        vec![
+            discount(1, DiscountType::Member, 10),
+            PricingDiscount { min_party_size: Some(10), ..discount(2, DiscountType::Group, 20) },
+            PricingDiscount { start_hour: Some(22), end_hour: Some(6), ..discount(3, DiscountType::TimeOfDay, 30) }, // 야간 (자정 넘김)
+            PricingDiscount { start_hour: Some(6), end_hour: Some(10), ..discount(4, DiscountType::TimeOfDay, 15) },  // 조조
+            PricingDiscount { active: false, ..discount(5, DiscountType::Member, 50) },
+        ]
+    }
+
+    fn quote(party: PartyComposition, is_member: bool, local_start_hour: Option<u32>, prices: &[ContentPrice]) -> Result<PriceQuote, String> {
+        let conditions = PricingConditions { is_member, party_size: party.total(), local_start_hour };
+        PriceQuote::calculate(1, 7, &party, prices, &discounts(), &conditions, "KRW")
+    }
+
+    #[test]
+    fn applies_largest_single_discount() {
+        // (설명, 성인, 어린이, 회원, 시작 시각, 정가, 결제 금액, 적용 할인)
+        let cases = [
+            ("할인 없음", 2, 1, false, Some(14), 26_000, 26_000, None),
+            ("회원", 2, 1, true, Some(14), 26_000, 23_400, Some(1)),
+            ("단체 최소 인원 미달", 9, 0, false, Some(14), 90_000, 90_000, None),
+            ("단체", 10, 0, false, Some(14), 100_000, 80_000, Some(2)),
+            ("야간", 2, 1, false, Some(23), 26_000, 18_200, Some(3)),
+            ("야간 자정 이후", 2, 1, false, Some(5), 26_000, 18_200, Some(3)),
+            ("조조 시작 경계", 2, 1, false, Some(6), 26_000, 22_100, Some(4)),
+            ("조조 종료 경계", 2, 1, false, Some(10), 26_000, 26_000, None),
+            ("시작 시간 없는 회차", 2, 1, false, None, 26_000, 26_000, None),
+            ("회원 + 단체 → 단체만", 10, 0, true, Some(14), 100_000, 80_000, Some(2)),
+            ("회원 + 야간 → 야간만", 2, 1, true, Some(23), 26_000, 18_200, Some(3)),
+            ("회원 + 단체 + 조조 → 단체만", 8, 2, true, Some(7), 92_000, 73_600, Some(2)),
+        ];
+
+        for (name, adults, children, is_member, hour, list_price, total_price, discount_id) in cases {
+            let quote = quote(PartyComposition::from_legacy(adults, children), is_member, hour, &prices()).unwrap();
+            assert_eq!(quote.list_price, list_price, "{}", name);
+            assert_eq!(quote.total_price, total_price, "{}", name);
+            assert_eq!(quote.discount.map(|discount| discount.discount_id), discount_id, "{}", name);
+        }
+    }
+
+    #[test]
+    fn content_without_prices_is_free() {
+        let quote = quote(PartyComposition::from_legacy(2, 1), true, Some(23), &[]).unwrap();
+
+        assert_eq!((quote.list_price, quote.total_price), (0, 0));
+        assert!(quote.discount.is_none());
+        assert!(quote.lines.iter().all(|line| line.amount == 0));
+    }
+
+    #[test]
+    fn unpriced_category_is_rejected() {
+        let mut party = PartyComposition::from_legacy(2, 0);
+        party.set(TicketCategory::Senior, 1);
+
+        assert!(quote(party, false, Some(14), &prices()).is_err());
+    }
+
+    #[test]
+    fn discount_rate_is_clamped() {
+        let discount = discount(9, DiscountType::Member, 150);
+        assert_eq!(discount.amount_off(10_000), 10_000);
+        assert_eq!(PricingDiscount { rate_percent: -10, ..discount }.amount_off(10_000), 0);
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+
+// 알림 대상 회차 (아직 알림을 받지 않은 유효한 예약 목록 포함)
+#[derive(Debug, Clone)]
+pub struct ReminderTarget {
+    pub content_schedule_id: u64,
+    pub content_id: u64,
+    pub content_title: Option<String>,
+    pub start_time: DateTime<Utc>,
+    pub recipients: Vec<ReminderRecipient>,
+}
+
+// 알림 받을 예약 (발송 기록은 예약 단위)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderRecipient {
+    pub reservation_id: i32,
+    pub user_id: String,
+}
+
+// 발송할 푸시 알림
+#[derive(Debug, Clone)]
+pub struct PushNotification {
+    pub user_ids: Vec<String>,
+    pub title: String,
+    pub body: String,
+    pub data: Vec<(String, String)>,
+    pub dedup_key: String,
+}
+
+// 알림 문구 템플릿 ({content_title}, {start_time}, {minutes} 치환)
+#[derive(Debug, Clone)]
+pub struct ReminderTemplate {
+    pub title: String,
+    pub body: String,
+}
+
+impl ReminderTemplate {
+    pub fn render(&self, content_title: &str, start_time: &str, minutes: i64) -> (String, String) {
+        let fill = |template: &str| {
+            template
+                .replace("{content_title}", content_title)
+                .replace("{start_time}", start_time)
+                .replace("{minutes}", &minutes.to_string())
+        };
+        (fill(&self.title), fill(&self.body))
+    }
+}
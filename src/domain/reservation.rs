@@ -6,6 +6,10 @@ use sqlx::prelude::{FromRow, Type};
 
 use crate::reservation_proto::CreateReservationRequest;
 
+use super::booking_window::{BookingAction, BookingWindow};
+use super::pricing::ReservationPrice;
+use super::ticket_category::{PartyComposition, TicketCategory};
+
 #[derive(Debug, Clone ,FromRow)]
 pub struct Reservation {
     pub id: i32,
@@ -15,28 +19,115 @@ pub struct Reservation {
     pub status: Option<ReservationStatus>, 
     pub ad_cnt: i32,
     pub cd_cnt: i32, 
-    pub use_at: bool,
+    pub use_at: bool,                          // 전원 입장 완료 여부
+    pub checked_in_ad_cnt: i32,                // 입장한 성인 수
+    pub checked_in_cd_cnt: i32,                // 입장한 어린이 수
+    pub group_reservation_id: Option<i64>,     // 단체 예약으로 생성된 예약 (단체 좌석 사용)
+    pub payment_due_at: Option<DateTime<Utc>>, // 결제 대기 예약의 결제 기한
+    #[sqlx(skip)]
+    pub party: PartyComposition,               // 권종별 인원 (ad_cnt / cd_cnt 는 권종별 인원의 성인 / 어린이 합계)
+    #[sqlx(skip)]
+    pub price: Option<ReservationPrice>,       // 예약 시점 금액 (가격 산정 이전 예약은 None)
+    #[sqlx(skip)]
+    pub schedule: Option<ReservationSchedule>, // 조회 시 JOIN 된 회차 정보
+    #[sqlx(skip)]
+    pub flags: Option<ReservationFlags>,       // 조회 시점 기준 가능 작업
+    #[sqlx(skip)]
+    pub ticket_token: Option<String>,          // 입장용 서명 티켓 (사용 가능한 예약만 발급)
+    #[sqlx(skip)]
+    pub version: Option<i64>,                  // 마지막 변경 이력 ID (변경할 때마다 증가, 알림 중복 제거 키)
+}
+
+// 예약된 회차 / 컨텐츠 정보 (CONTENT_SCHEDULES + CONTENTS)
+#[derive(Debug, Clone, Default)]
+pub struct ReservationSchedule {
+    pub start_time: Option<DateTime<Utc>>,
+    pub content_id: u64,
+    pub content_title: Option<String>,
+}
+
+// 예약 상태와 회차 시작 시간으로 계산한 가능 작업
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReservationFlags {
+    pub cancellable: bool,
+    pub usable: bool,
+    pub editable: bool,
 }
 
 impl Reservation {
     pub fn is_valid_capacity(&self, new_ad_cnt: i32, new_cd_cnt: i32) -> bool {
         new_ad_cnt >= self.ad_cnt && new_cd_cnt >= self.cd_cnt
     }
+
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.status, Some(ReservationStatus::Cancelled))
+    }
+
+    pub fn is_no_show(&self) -> bool {
+        matches!(self.status, Some(ReservationStatus::NoShow))
+    }
+
+    pub fn is_pending_payment(&self) -> bool {
+        matches!(self.status, Some(ReservationStatus::PendingPayment))
+    }
+
+    // 전원 입장하면 use_at = true, CONFIRMED 로 변경됨
+    pub fn is_used(&self) -> bool {
+        self.use_at || matches!(self.status, Some(ReservationStatus::Confirmed))
+    }
+
+    pub fn remaining_adults(&self) -> i32 {
+        (self.ad_cnt - self.checked_in_ad_cnt).max(0)
+    }
+
+    pub fn remaining_children(&self) -> i32 {
+        (self.cd_cnt - self.checked_in_cd_cnt).max(0)
+    }
+
+    pub fn is_group_booking(&self) -> bool {
+        self.group_reservation_id.is_some()
+    }
+
+    pub fn is_partially_checked_in(&self) -> bool {
+        !self.is_used() && (self.checked_in_ad_cnt > 0 || self.checked_in_cd_cnt > 0)
+    }
+
+    // 취소 / 인원 변경은 회차별 마감 시각 이전, 입장 시작 전에만 가능 (회차 정보가 없으면 시간 제한 없음)
+    // 단체 예약은 단체 예약 단위로만 취소 / 변경, 결제 대기 예약은 취소만 가능 (입장 / 변경은 결제 완료 후)
+    pub fn flags(&self, window: &BookingWindow, now: DateTime<Utc>) -> ReservationFlags {
+        let active = !self.is_cancelled() && !self.is_no_show() && !self.is_used();
+        let untouched = active && !self.is_partially_checked_in() && !self.is_group_booking();
+        let start_time = self.schedule.as_ref().and_then(|schedule| schedule.start_time);
+        let is_open = |action: BookingAction| match start_time {
+            Some(start_time) => window.check(action, start_time, now).is_ok(),
+            None => true,
+        };
+
+        ReservationFlags {
+            cancellable: untouched && is_open(BookingAction::Cancel),
+            usable: active && !self.is_pending_payment(),
+            editable: untouched && !self.is_pending_payment() && is_open(BookingAction::ChangeCount),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Type, Deserialize)]
 #[sqlx(type_name = "VARCHAR")] 
 pub enum ReservationStatus {
+    PendingPayment, // 유료 예약 결제 대기 (좌석은 확보, 결제 기한이 지나면 취소)
     Pending,
     Confirmed,
     Cancelled,
+    NoShow,
 }
 impl fmt::Display for ReservationStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let status_str = match self {
+            ReservationStatus::PendingPayment => "PENDING_PAYMENT",
             ReservationStatus::Pending => "PENDING",
             ReservationStatus::Confirmed => "CONFIRMED",
             ReservationStatus::Cancelled => "CANCELLED",
+            ReservationStatus::NoShow => "NO_SHOW",
         };
         write!(f, "{}", status_str)
     }
@@ -44,9 +135,11 @@ impl fmt::Display for ReservationStatus {
 impl From<String> for ReservationStatus {
     fn from(s: String) -> Self {
         match s.as_str() {
+            "PendingPayment" => Self::PendingPayment,
             "Pending" => Self::Pending,
             "Confirmed" => Self::Confirmed,
             "Cancelled" => Self::Cancelled,
+            "NoShow" => Self::NoShow,
             _ => Self::Pending, 
         }
     }
@@ -56,28 +149,52 @@ impl FromStr for ReservationStatus {
 
     fn from_str(status: &str) -> Result<Self, Self::Err> {
         match status {
+            "PENDING_PAYMENT" => Ok(Self::PendingPayment),
             "PENDING" => Ok(Self::Pending),
             "CONFIRMED" => Ok(Self::Confirmed),
             "CANCELLED" => Ok(Self::Cancelled),
+            "NO_SHOW" => Ok(Self::NoShow),
             _ => Err(()),
         }
     }
 }
 
-// gRPC CreateReservationRequest를 Reservation으로 변환
-impl From<CreateReservationRequest> for Reservation {
-    fn from(req: CreateReservationRequest) -> Self {
-        Reservation {
+// gRPC CreateReservationRequest를 Reservation으로 변환 (party 가 비어 있으면 ad_cnt / cd_cnt 사용)
+impl TryFrom<CreateReservationRequest> for Reservation {
+    type Error = String;
+
+    fn try_from(req: CreateReservationRequest) -> Result<Self, Self::Error> {
+        let party = if req.party.is_empty() {
+            PartyComposition::from_legacy(req.ad_cnt, req.cd_cnt)
+        } else {
+            let mut party = PartyComposition::default();
+            for (category, count) in &req.party {
+                let category = TicketCategory::from_str(category.trim()).map_err(|_| format!("알 수 없는 권종입니다: {}", category))?;
+                party.set(category, *count);
+            }
+            party
+        };
+        Ok(Reservation {
             id: 0,
             user_id: req.user_id,
             content_schedule_id: req.content_schedule_id,
             reserved_at: None,
                 // .as_deref() // Option<String> → Option<&str>
                 // .and_then(|s| s.parse::<DateTime<Utc>>().ok()), // ✅ 변환 시도 (실패하면 None)
-            ad_cnt: req.ad_cnt,
-            cd_cnt: req.cd_cnt,
+            ad_cnt: party.adults(),
+            cd_cnt: party.children(),
             status: None,
             use_at: false,
-        }
+            checked_in_ad_cnt: 0,
+            checked_in_cd_cnt: 0,
+            group_reservation_id: None,
+            payment_due_at: None,
+            party,
+            price: None,
+            schedule: None,
+            flags: None,
+            ticket_token: None,
+            version: None,
+        })
     }
 }
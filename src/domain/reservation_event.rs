@@ -0,0 +1,70 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{reservation::ReservationStatus, reservation_history::{HistoryAction, ReservationSnapshot}};
+
+// 외부로 발행되는 예약 이벤트 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationEventType {
+    Created,
+    Updated,
+    Cancelled,
+    Used,
+}
+
+impl ReservationEventType {
+    // 이력 작업 + 변경 후 상태 → 이벤트 종류 (부분 입장은 Updated, 전원 입장 시 Used)
+    pub fn from_change(action: HistoryAction, after: Option<&ReservationSnapshot>) -> Self {
+        match action {
+            HistoryAction::Create => Self::Created,
+            HistoryAction::Cancel | HistoryAction::Delete => Self::Cancelled,
+            HistoryAction::CheckIn | HistoryAction::StatusChange => match after.and_then(|s| s.status.as_ref()) {
+                Some(ReservationStatus::Confirmed) => Self::Used,
+                Some(ReservationStatus::Cancelled) => Self::Cancelled,
+                _ => Self::Updated,
+            },
+            HistoryAction::ChangeCount | HistoryAction::Move | HistoryAction::NoShow | HistoryAction::Transfer | HistoryAction::Payment => Self::Updated,
+        }
+    }
+}
+
+impl fmt::Display for ReservationEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let event_str = match self {
+            ReservationEventType::Created => "RESERVATION_CREATED",
+            ReservationEventType::Updated => "RESERVATION_UPDATED",
+            ReservationEventType::Cancelled => "RESERVATION_CANCELLED",
+            ReservationEventType::Used => "RESERVATION_USED",
+        };
+        write!(f, "{}", event_str)
+    }
+}
+
+impl FromStr for ReservationEventType {
+    type Err = ();
+
+    fn from_str(event: &str) -> Result<Self, Self::Err> {
+        match event {
+            "RESERVATION_CREATED" => Ok(Self::Created),
+            "RESERVATION_UPDATED" => Ok(Self::Updated),
+            "RESERVATION_CANCELLED" => Ok(Self::Cancelled),
+            "RESERVATION_USED" => Ok(Self::Used),
+            _ => Err(()),
+        }
+    }
+}
+
+// RESERVATION_OUTBOX 한 건 (발행 단위)
+// 최소 1회 전달이므로 수신 측은 event_id 로 중복을 걸러야 함
+#[derive(Debug, Clone, Serialize)]
+pub struct OutboxEvent {
+    pub event_id: i64,
+    pub event_type: String,
+    pub reservation_id: i32,
+    pub payload: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+    #[serde(skip)]
+    pub attempts: i32,
+}
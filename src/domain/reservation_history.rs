@@ -0,0 +1,147 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+
+use super::reservation::ReservationStatus;
+
+// 변경 요청 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditChannel {
+    Rest,
+    Grpc,
+    Manual,
+    System, // 노쇼 처리 등 내부 작업
+}
+
+impl fmt::Display for AuditChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let channel_str = match self {
+            AuditChannel::Rest => "REST",
+            AuditChannel::Grpc => "GRPC",
+            AuditChannel::Manual => "MANUAL",
+            AuditChannel::System => "SYSTEM",
+        };
+        write!(f, "{}", channel_str)
+    }
+}
+
+impl FromStr for AuditChannel {
+    type Err = ();
+
+    fn from_str(channel: &str) -> Result<Self, Self::Err> {
+        match channel {
+            "REST" => Ok(Self::Rest),
+            "GRPC" => Ok(Self::Grpc),
+            "MANUAL" => Ok(Self::Manual),
+            "SYSTEM" => Ok(Self::System),
+            _ => Err(()),
+        }
+    }
+}
+
+// 변경 요청자 정보 (모든 예약 변경에 함께 전달되어 이력으로 기록)
+#[derive(Debug, Clone)]
+pub struct AuditContext {
+    pub actor_id: Option<String>, // 요청한 사용자 ID (인증 없는 경로 / 내부 작업이면 None)
+    pub channel: AuditChannel,
+}
+
+impl AuditContext {
+    pub fn new(actor_id: Option<String>, channel: AuditChannel) -> Self {
+        Self { actor_id, channel }
+    }
+
+    pub fn system() -> Self {
+        Self::new(None, AuditChannel::System)
+    }
+}
+
+// 이력 작업 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryAction {
+    Create,
+    ChangeCount,
+    Move,
+    CheckIn,
+    Cancel,
+    NoShow,
+    StatusChange,
+    Delete,
+    Transfer, // 예약 소유자 변경 (양도)
+    Payment,  // 결제 완료 (결제 대기 → 예약 완료)
+}
+
+impl HistoryAction {
+    // 상태 변경 → 이력 작업
+    pub fn from_status(status: &ReservationStatus) -> Self {
+        match status {
+            ReservationStatus::Cancelled => Self::Cancel,
+            ReservationStatus::NoShow => Self::NoShow,
+            ReservationStatus::Confirmed => Self::CheckIn,
+            ReservationStatus::PendingPayment | ReservationStatus::Pending => Self::StatusChange,
+        }
+    }
+}
+
+impl fmt::Display for HistoryAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action_str = match self {
+            HistoryAction::Create => "CREATE",
+            HistoryAction::ChangeCount => "CHANGE_COUNT",
+            HistoryAction::Move => "MOVE",
+            HistoryAction::CheckIn => "CHECK_IN",
+            HistoryAction::Cancel => "CANCEL",
+            HistoryAction::NoShow => "NO_SHOW",
+            HistoryAction::StatusChange => "STATUS_CHANGE",
+            HistoryAction::Delete => "DELETE",
+            HistoryAction::Transfer => "TRANSFER",
+            HistoryAction::Payment => "PAYMENT",
+        };
+        write!(f, "{}", action_str)
+    }
+}
+
+impl FromStr for HistoryAction {
+    type Err = ();
+
+    fn from_str(action: &str) -> Result<Self, Self::Err> {
+        match action {
+            "CREATE" => Ok(Self::Create),
+            "CHANGE_COUNT" => Ok(Self::ChangeCount),
+            "MOVE" => Ok(Self::Move),
+            "CHECK_IN" => Ok(Self::CheckIn),
+            "CANCEL" => Ok(Self::Cancel),
+            "NO_SHOW" => Ok(Self::NoShow),
+            "STATUS_CHANGE" => Ok(Self::StatusChange),
+            "DELETE" => Ok(Self::Delete),
+            "TRANSFER" => Ok(Self::Transfer),
+            "PAYMENT" => Ok(Self::Payment),
+            _ => Err(()),
+        }
+    }
+}
+
+// 변경 전 / 후 예약 상태
+#[derive(Debug, Clone)]
+pub struct ReservationSnapshot {
+    pub user_id: Option<String>, // 예약 소유자 (소유자 기록 이전 이력은 None)
+    pub content_schedule_id: u64,
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+    pub status: Option<ReservationStatus>,
+    pub checked_in_ad_cnt: i32,
+    pub checked_in_cd_cnt: i32,
+}
+
+// RESERVATION_HISTORY 한 건
+#[derive(Debug, Clone)]
+pub struct ReservationHistory {
+    pub id: i64,
+    pub reservation_id: i32,
+    pub action: HistoryAction,
+    pub actor_id: Option<String>,
+    pub channel: AuditChannel,
+    pub before: Option<ReservationSnapshot>, // 생성 시 None
+    pub after: Option<ReservationSnapshot>,  // 삭제 시 None
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,65 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+
+// 양도 요청 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStatus {
+    Pending,   // 받는 사용자 응답 대기
+    Accepted,  // 소유자 변경 완료
+    Declined,  // 받는 사용자가 거절
+    Cancelled, // 보낸 사용자가 철회
+    Expired,   // 응답 기한 초과
+}
+
+impl fmt::Display for TransferStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            TransferStatus::Pending => "PENDING",
+            TransferStatus::Accepted => "ACCEPTED",
+            TransferStatus::Declined => "DECLINED",
+            TransferStatus::Cancelled => "CANCELLED",
+            TransferStatus::Expired => "EXPIRED",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+impl FromStr for TransferStatus {
+    type Err = ();
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "PENDING" => Ok(Self::Pending),
+            "ACCEPTED" => Ok(Self::Accepted),
+            "DECLINED" => Ok(Self::Declined),
+            "CANCELLED" => Ok(Self::Cancelled),
+            "EXPIRED" => Ok(Self::Expired),
+            _ => Err(()),
+        }
+    }
+}
+
+// RESERVATION_TRANSFER 한 건
+#[derive(Debug, Clone)]
+pub struct ReservationTransfer {
+    pub id: i64,
+    pub reservation_id: i32,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub status: TransferStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub expires_at: DateTime<Utc>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl ReservationTransfer {
+    // 기한이 지난 PENDING 요청은 조회 시점에 EXPIRED 로 취급 (DB 는 다음 변경 시 갱신)
+    pub fn effective_status(&self, now: DateTime<Utc>) -> TransferStatus {
+        if self.status == TransferStatus::Pending && self.expires_at <= now {
+            TransferStatus::Expired
+        } else {
+            self.status
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+
+// 컨텐츠 스케줄별 잔여 좌석 현황 (조회 전용 모델)
+#[derive(Debug, Clone)]
+pub struct ScheduleAvailability {
+    pub content_schedule_id: u64,
+    pub content_id: u64,
+    pub start_time: Option<DateTime<Utc>>,
+    pub total_seats: i32,
+    pub booked_adults: i32,
+    pub booked_children: i32,
+    pub seats_remaining: i32,
+    pub can_book: Option<bool>, // 요청한 사용자 기준 예약 가능 여부 (사용자 정보가 없으면 None)
+}
+
+impl ScheduleAvailability {
+    pub fn new(
+        content_schedule_id: u64,
+        content_id: u64,
+        start_time: Option<DateTime<Utc>>,
+        total_seats: i32,
+        booked_adults: i32,
+        booked_children: i32,
+    ) -> Self {
+        let seats_remaining = (total_seats - booked_adults - booked_children).max(0);
+        Self {
+            content_schedule_id,
+            content_id,
+            start_time,
+            total_seats,
+            booked_adults,
+            booked_children,
+            seats_remaining,
+            can_book: None,
+        }
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        self.seats_remaining <= 0
+    }
+}
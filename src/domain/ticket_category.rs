@@ -0,0 +1,158 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+// 권종 (성인 / 어린이 외 유아 / 경로 / 장애인 / 동반자)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TicketCategory {
+    Adult,
+    Child,
+    Infant,
+    Senior,
+    Disabled,
+    Companion,
+}
+
+impl TicketCategory {
+    pub const ALL: [TicketCategory; 6] = [
+        TicketCategory::Adult,
+        TicketCategory::Child,
+        TicketCategory::Infant,
+        TicketCategory::Senior,
+        TicketCategory::Disabled,
+        TicketCategory::Companion,
+    ];
+
+    // 기존 ad_cnt / cd_cnt 집계 기준 (유아는 어린이, 나머지는 성인으로 집계)
+    pub fn is_adult(&self) -> bool {
+        !matches!(self, TicketCategory::Child | TicketCategory::Infant)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TicketCategory::Adult => "성인",
+            TicketCategory::Child => "어린이",
+            TicketCategory::Infant => "유아",
+            TicketCategory::Senior => "경로",
+            TicketCategory::Disabled => "장애인",
+            TicketCategory::Companion => "동반자",
+        }
+    }
+}
+
+impl fmt::Display for TicketCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category_str = match self {
+            TicketCategory::Adult => "ADULT",
+            TicketCategory::Child => "CHILD",
+            TicketCategory::Infant => "INFANT",
+            TicketCategory::Senior => "SENIOR",
+            TicketCategory::Disabled => "DISABLED",
+            TicketCategory::Companion => "COMPANION",
+        };
+        write!(f, "{}", category_str)
+    }
+}
+
+impl FromStr for TicketCategory {
+    type Err = ();
+
+    fn from_str(category: &str) -> Result<Self, Self::Err> {
+        match category {
+            "ADULT" => Ok(Self::Adult),
+            "CHILD" => Ok(Self::Child),
+            "INFANT" => Ok(Self::Infant),
+            "SENIOR" => Ok(Self::Senior),
+            "DISABLED" => Ok(Self::Disabled),
+            "COMPANION" => Ok(Self::Companion),
+            _ => Err(()),
+        }
+    }
+}
+
+// 권종별 인원 구성 (0명인 권종은 저장하지 않음)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartyComposition {
+    counts: BTreeMap<TicketCategory, i32>,
+}
+
+impl PartyComposition {
+    // 기존 성인 / 어린이 인원 → 권종 구성
+    pub fn from_legacy(ad_cnt: i32, cd_cnt: i32) -> Self {
+        let mut party = Self::default();
+        party.set(TicketCategory::Adult, ad_cnt);
+        party.set(TicketCategory::Child, cd_cnt);
+        party
+    }
+
+    pub fn set(&mut self, category: TicketCategory, count: i32) {
+        if count == 0 {
+            self.counts.remove(&category);
+        } else {
+            self.counts.insert(category, count);
+        }
+    }
+
+    pub fn count(&self, category: TicketCategory) -> i32 {
+        self.counts.get(&category).copied().unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (TicketCategory, i32)> + '_ {
+        self.counts.iter().map(|(category, count)| (*category, *count))
+    }
+
+    pub fn total(&self) -> i32 {
+        self.counts.values().sum()
+    }
+
+    // ad_cnt 로 집계되는 인원
+    pub fn adults(&self) -> i32 {
+        self.iter().filter(|(category, _)| category.is_adult()).map(|(_, count)| count).sum()
+    }
+
+    // cd_cnt 로 집계되는 인원
+    pub fn children(&self) -> i32 {
+        self.iter().filter(|(category, _)| !category.is_adult()).map(|(_, count)| count).sum()
+    }
+
+    pub fn has_negative(&self) -> bool {
+        self.counts.values().any(|count| *count < 0)
+    }
+
+    // 권종 간 동반 규칙 (유아는 보호자, 동반자는 장애인 1명당 1명)
+    pub fn companion_violations(&self) -> Vec<String> {
+        let mut reasons = Vec::new();
+        let guardians = self.count(TicketCategory::Adult) + self.count(TicketCategory::Senior) + self.count(TicketCategory::Disabled);
+        if self.count(TicketCategory::Infant) > 0 && guardians == 0 {
+            reasons.push("유아는 성인 / 경로 / 장애인 보호자와 함께 예약해야 합니다.".to_string());
+        }
+        if self.count(TicketCategory::Companion) > self.count(TicketCategory::Disabled) {
+            reasons.push(format!(
+                "동반자는 장애인 1명당 1명까지 예약할 수 있습니다. (장애인 {}명, 동반자 {}명)",
+                self.count(TicketCategory::Disabled), self.count(TicketCategory::Companion)
+            ));
+        }
+        reasons
+    }
+}
+
+impl fmt::Display for PartyComposition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.iter().map(|(category, count)| format!("{}:{}", category, count)).collect();
+        write!(f, "{}", parts.join(","))
+    }
+}
+
+// "ADULT:2,INFANT:1" → 권종 구성 (DB 집계 / 멱등성 fingerprint 와 같은 형식)
+impl FromStr for PartyComposition {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut party = Self::default();
+        for part in value.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (category, count) = part.split_once(':').ok_or(format!("권종 형식이 올바르지 않습니다: {}", part))?;
+            let category = TicketCategory::from_str(category.trim()).map_err(|_| format!("알 수 없는 권종입니다: {}", category))?;
+            let count = count.trim().parse::<i32>().map_err(|_| format!("권종 인원이 올바르지 않습니다: {}", part))?;
+            party.set(category, party.count(category) + count);
+        }
+        Ok(party)
+    }
+}
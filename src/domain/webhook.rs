@@ -0,0 +1,102 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+
+use super::reservation_event::ReservationEventType;
+
+// 웹훅 구독 (파트너 시스템 수신 URL)
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    pub id: i64,
+    pub url: String,
+    pub secret: String,                          // 서명 키 (응답에는 포함하지 않음)
+    pub event_types: Vec<ReservationEventType>,  // 비어 있으면 모든 이벤트 수신
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookSubscription {
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t.to_string() == event_type)
+    }
+
+    // DB 저장 형식 (쉼표 구분, 전체 수신이면 None)
+    pub fn event_types_column(&self) -> Option<String> {
+        if self.event_types.is_empty() {
+            return None;
+        }
+        Some(self.event_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(","))
+    }
+
+    pub fn parse_event_types_column(column: Option<&str>) -> Vec<ReservationEventType> {
+        column
+            .unwrap_or("")
+            .split(',')
+            .filter_map(|t| ReservationEventType::from_str(t.trim()).ok())
+            .collect()
+    }
+}
+
+// 전달 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Dead, // 최대 재시도 초과 (수동 재전송 대상)
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            DeliveryStatus::Pending => "PENDING",
+            DeliveryStatus::Delivered => "DELIVERED",
+            DeliveryStatus::Dead => "DEAD",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = ();
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "PENDING" => Ok(Self::Pending),
+            "DELIVERED" => Ok(Self::Delivered),
+            "DEAD" => Ok(Self::Dead),
+            _ => Err(()),
+        }
+    }
+}
+
+// WEBHOOK_DELIVERY 한 건 (구독 × 이벤트)
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub event_id: i64,
+    pub event_type: String,
+    pub body: String, // 전송할 JSON 본문 (서명 대상)
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+// 전달 대상 (수신 URL / 서명 키 포함)
+#[derive(Debug, Clone)]
+pub struct PendingWebhookDelivery {
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
+
+// 전달 결과
+#[derive(Debug, Clone)]
+pub enum DeliveryOutcome {
+    Delivered { status_code: u16 },
+    Retry { status_code: Option<u16>, error: String, next_attempt_at: DateTime<Utc> },
+    Dead { status_code: Option<u16>, error: String },
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+// 입장 스캐너 요청 (스캔한 티켓 + 스캐너가 담당하는 회차)
+// ad_cnt / cd_cnt 를 지정하면 일부 인원만 입장 (생략 시 남은 인원 전원)
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CheckInRequest {
+    pub ticket_token: String,
+    pub content_schedule_id: u64,
+    pub ad_cnt: Option<i32>,
+    pub cd_cnt: Option<i32>,
+}
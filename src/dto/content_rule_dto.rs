@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use crate::domain::{content_rule::{CategoryRule, ContentBookingRule}, ticket_category::TicketCategory};
+
+// ✅ CategoryRuleDTO 구조체 (권종별 최대 인원, 요청 / 응답 공용)
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CategoryRuleDTO {
+    pub category: String, // ADULT / CHILD / INFANT / SENIOR / DISABLED / COMPANION
+    pub max_per_reservation: Option<i32>,
+    pub max_per_schedule: Option<i32>,
+}
+
+// ✅ UpdateContentRuleRequest 구조체 (컨텐츠 예약 규칙 수정 요청)
+#[derive(Debug, Deserialize)]
+pub struct UpdateContentRuleRequest {
+    #[serde(default)]
+    pub children_only: bool,
+    pub max_party_size: Option<i32>,
+    pub max_adults: Option<i32>,
+    pub max_children: Option<i32>,
+    pub booking_opens_days_before: Option<i32>,
+    pub booking_closes_minutes_before: Option<i32>,
+    #[serde(default)]
+    pub categories: Vec<CategoryRuleDTO>, // 생략 시 권종 규칙 없음
+}
+
+impl UpdateContentRuleRequest {
+    pub fn into_rule(self, content_id: u64) -> Result<ContentBookingRule, String> {
+        let categories = self.categories
+            .iter()
+            .map(|rule| {
+                let category = TicketCategory::from_str(rule.category.trim()).map_err(|_| format!("알 수 없는 권종입니다: {}", rule.category))?;
+                Ok(CategoryRule {
+                    category,
+                    max_per_reservation: rule.max_per_reservation,
+                    max_per_schedule: rule.max_per_schedule,
+                })
+            })
+            .collect::<Result<Vec<CategoryRule>, String>>()?;
+
+        Ok(ContentBookingRule {
+            content_id,
+            children_only: self.children_only,
+            max_party_size: self.max_party_size,
+            max_adults: self.max_adults,
+            max_children: self.max_children,
+            booking_opens_days_before: self.booking_opens_days_before,
+            booking_closes_minutes_before: self.booking_closes_minutes_before,
+            categories,
+        })
+    }
+}
+
+// ✅ ContentRuleDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct ContentRuleDTO {
+    pub content_id: u64,
+    pub children_only: bool,
+    pub max_party_size: Option<i32>,
+    pub max_adults: Option<i32>,
+    pub max_children: Option<i32>,
+    pub booking_opens_days_before: Option<i32>,
+    pub booking_closes_minutes_before: Option<i32>,
+    pub categories: Vec<CategoryRuleDTO>,
+}
+
+// ✅ ContentBookingRule → ContentRuleDTO 변환 함수
+impl From<ContentBookingRule> for ContentRuleDTO {
+    fn from(rule: ContentBookingRule) -> Self {
+        ContentRuleDTO {
+            content_id: rule.content_id,
+            children_only: rule.children_only,
+            max_party_size: rule.max_party_size,
+            max_adults: rule.max_adults,
+            max_children: rule.max_children,
+            booking_opens_days_before: rule.booking_opens_days_before,
+            booking_closes_minutes_before: rule.booking_closes_minutes_before,
+            categories: rule.categories.into_iter().map(|rule| CategoryRuleDTO {
+                category: rule.category.to_string(),
+                max_per_reservation: rule.max_per_reservation,
+                max_per_schedule: rule.max_per_schedule,
+            }).collect(),
+        }
+    }
+}
@@ -1,9 +1,21 @@
 use serde::Deserialize;
+
+use crate::domain::ticket_category::PartyComposition;
+
+use super::party_dto::{resolve_party, PartyCounts};
+
 // ✅ CreateReservationRequest 구조체 (예약 생성 요청 - 예약 시간 포함)
 #[derive(Debug, Deserialize)]
 pub struct CreateReservationRequest {
     pub content_schedule_id: u64,
     pub reserved_at: Option<String>,
-    pub ad_cnt: i32,
-    pub cd_cnt: i32, 
+    pub ad_cnt: Option<i32>,
+    pub cd_cnt: Option<i32>, 
+    pub party: Option<PartyCounts>, // 권종별 인원 (생략 시 ad_cnt / cd_cnt 사용)
+}
+
+impl CreateReservationRequest {
+    pub fn party(&self) -> Result<PartyComposition, String> {
+        resolve_party(self.party.as_ref(), self.ad_cnt, self.cd_cnt)
+    }
 }
\ No newline at end of file
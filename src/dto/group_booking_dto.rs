@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use crate::domain::group_booking::{GroupQuota, GroupReservation, GroupReservationItem, GroupReservationStatus, Organisation, OrganisationType};
+
+// ✅ OrganisationRequest 구조체 (/admin/organisations 단체 등록 요청)
+#[derive(Debug, Deserialize)]
+pub struct OrganisationRequest {
+    pub name: String,
+    pub org_type: Option<String>, // SCHOOL / AGENCY / OTHER (생략 시 OTHER)
+    pub max_party_size: i32,
+}
+
+impl OrganisationRequest {
+    pub fn parse_org_type(&self) -> Result<OrganisationType, String> {
+        match self.org_type.as_deref() {
+            Some(org_type) => OrganisationType::from_str(org_type.trim()).map_err(|_| format!("알 수 없는 단체 종류입니다: {}", org_type)),
+            None => Ok(OrganisationType::Other),
+        }
+    }
+}
+
+// ✅ OrganisationMemberRequest 구조체 (/admin/organisations/{id}/members)
+#[derive(Debug, Deserialize)]
+pub struct OrganisationMemberRequest {
+    pub user_id: String,
+}
+
+// ✅ OrganisationDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct OrganisationDTO {
+    pub id: i64,
+    pub name: String,
+    pub org_type: String,
+    pub max_party_size: i32,
+    pub active: bool,
+}
+
+impl From<Organisation> for OrganisationDTO {
+    fn from(organisation: Organisation) -> Self {
+        OrganisationDTO {
+            id: organisation.id,
+            name: organisation.name,
+            org_type: organisation.org_type.to_string(),
+            max_party_size: organisation.max_party_size,
+            active: organisation.active,
+        }
+    }
+}
+
+// ✅ GroupQuotaRequest 구조체 (/admin/schedules/{id}/group-quota)
+#[derive(Debug, Deserialize)]
+pub struct GroupQuotaRequest {
+    pub quota_seats: i32,
+}
+
+// ✅ GroupQuotaDTO 구조체 (회차별 단체 좌석 현황)
+#[derive(Debug, Serialize)]
+pub struct GroupQuotaDTO {
+    pub content_schedule_id: u64,
+    pub quota_seats: i32,
+    pub held_seats: i32,
+    pub seats_remaining: i32,
+}
+
+impl From<GroupQuota> for GroupQuotaDTO {
+    fn from(quota: GroupQuota) -> Self {
+        GroupQuotaDTO {
+            content_schedule_id: quota.content_schedule_id,
+            quota_seats: quota.quota_seats,
+            held_seats: quota.held_seats,
+            seats_remaining: quota.seats_remaining(),
+        }
+    }
+}
+
+// ✅ GroupReservationItemRequest 구조체 (회차별 인원)
+#[derive(Debug, Deserialize)]
+pub struct GroupReservationItemRequest {
+    pub content_schedule_id: u64,
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+}
+
+// ✅ GroupReservationRequest 구조체 (/groups 단체 예약 신청)
+#[derive(Debug, Deserialize)]
+pub struct GroupReservationRequest {
+    pub items: Vec<GroupReservationItemRequest>,
+    pub note: Option<String>,
+}
+
+impl GroupReservationRequest {
+    pub fn to_items(&self) -> Vec<GroupReservationItem> {
+        self.items
+            .iter()
+            .map(|item| GroupReservationItem {
+                content_schedule_id: item.content_schedule_id,
+                ad_cnt: item.ad_cnt,
+                cd_cnt: item.cd_cnt,
+                reservation_id: None,
+            })
+            .collect()
+    }
+}
+
+// ✅ GroupReviewRequest 구조체 (/admin/groups/{id}/approve, reject)
+#[derive(Debug, Deserialize, Default)]
+pub struct GroupReviewRequest {
+    pub note: Option<String>,
+}
+
+// ✅ GroupReservationQuery 구조체 (/admin/groups?status=..&limit=..)
+#[derive(Debug, Deserialize)]
+pub struct GroupReservationQuery {
+    pub status: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl GroupReservationQuery {
+    pub fn parse_status(&self) -> Result<Option<GroupReservationStatus>, String> {
+        self.status
+            .as_deref()
+            .map(|status| GroupReservationStatus::from_str(status.trim()).map_err(|_| format!("알 수 없는 단체 예약 상태입니다: {}", status)))
+            .transpose()
+    }
+}
+
+// ✅ GroupReservationItemDTO 구조체
+#[derive(Debug, Serialize)]
+pub struct GroupReservationItemDTO {
+    pub content_schedule_id: u64,
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+    pub reservation_id: Option<i32>,
+}
+
+// ✅ GroupReservationDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct GroupReservationDTO {
+    pub id: i64,
+    pub organisation_id: i64,
+    pub requested_by: String,
+    pub status: String,
+    pub total_party_size: i32,
+    pub note: Option<String>,
+    pub reviewed_by: Option<String>,
+    pub review_note: Option<String>,
+    pub reviewed_at: Option<String>,
+    pub created_at: Option<String>,
+    pub items: Vec<GroupReservationItemDTO>,
+}
+
+impl From<GroupReservation> for GroupReservationDTO {
+    fn from(group: GroupReservation) -> Self {
+        GroupReservationDTO {
+            id: group.id,
+            organisation_id: group.organisation_id,
+            requested_by: group.requested_by.clone(),
+            status: group.status.to_string(),
+            total_party_size: group.total_party_size(),
+            note: group.note,
+            reviewed_by: group.reviewed_by,
+            review_note: group.review_note,
+            reviewed_at: group.reviewed_at.map(|dt| dt.to_rfc3339()),
+            created_at: group.created_at.map(|dt| dt.to_rfc3339()),
+            items: group.items.into_iter().map(|item| GroupReservationItemDTO {
+                content_schedule_id: item.content_schedule_id,
+                ad_cnt: item.ad_cnt,
+                cd_cnt: item.cd_cnt,
+                reservation_id: item.reservation_id,
+            }).collect(),
+        }
+    }
+}
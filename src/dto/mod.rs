@@ -4,3 +4,18 @@ pub mod reservation_response_dto;
 pub mod reservation_chk_dto;
 pub mod update_reservation_dto;
 pub mod update_status_dto;
+pub mod schedule_availability_dto;
+pub mod schedule_filter_dto;
+pub mod content_rule_dto;
+pub mod reservation_query_dto;
+pub mod page_dto;
+
+pub mod check_in_dto;
+pub mod move_reservation_dto;
+pub mod reservation_history_dto;
+pub mod webhook_dto;
+pub mod group_booking_dto;
+pub mod transfer_dto;
+pub mod party_dto;
+pub mod pricing_dto;
+pub mod payment_dto;
\ No newline at end of file
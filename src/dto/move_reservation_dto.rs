@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+// 예약 회차 이동 요청 (같은 컨텐츠의 다른 회차로)
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MoveReservationRequest {
+    pub reservation_id: i32,
+    pub content_schedule_id: u64,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::page::{Page, PageRequest};
+
+// ✅ PageQuery 구조체 (목록 조회 페이지 파라미터)
+#[derive(Debug, Deserialize)]
+pub struct PageQuery {
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+}
+
+impl PageQuery {
+    // limit / cursor 를 주지 않은 기존 클라이언트는 페이지 없이 전체 목록(JSON 배열)으로 응답하므로 None
+    pub fn page_request(&self) -> Result<Option<PageRequest>, String> {
+        let has_cursor = self.cursor.as_deref().is_some_and(|cursor| !cursor.trim().is_empty());
+        if self.limit.is_none() && !has_cursor {
+            return Ok(None);
+        }
+        PageRequest::new(self.limit, self.cursor.as_deref()).map(Some)
+    }
+}
+
+// ✅ PageDTO 구조체 (limit / cursor 를 준 목록 API 응답용)
+#[derive(Debug, Serialize)]
+pub struct PageDTO<T: Serialize> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+// ✅ Page<T> → PageDTO<U> 변환 함수
+impl<T, U: Serialize + From<T>> From<Page<T>> for PageDTO<U> {
+    fn from(page: Page<T>) -> Self {
+        PageDTO {
+            items: page.items.into_iter().map(U::from).collect(),
+            next_cursor: page.next_cursor,
+        }
+    }
+}
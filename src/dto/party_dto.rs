@@ -0,0 +1,34 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::domain::ticket_category::{PartyComposition, TicketCategory};
+
+// ✅ PartyCounts (권종별 인원, 요청 / 응답 공용 - 예: {"ADULT": 2, "INFANT": 1})
+pub type PartyCounts = BTreeMap<String, i32>;
+
+// 요청 인원 → 권종 구성
+// party 가 없으면 기존 클라이언트의 ad_cnt / cd_cnt 를 성인 / 어린이로 사용
+// 둘 다 보낸 경우 ad_cnt / cd_cnt 는 party 의 성인 / 어린이 합계와 같아야 함
+pub fn resolve_party(party: Option<&PartyCounts>, ad_cnt: Option<i32>, cd_cnt: Option<i32>) -> Result<PartyComposition, String> {
+    let Some(party) = party else {
+        return Ok(PartyComposition::from_legacy(ad_cnt.unwrap_or(0), cd_cnt.unwrap_or(0)));
+    };
+
+    let mut composition = PartyComposition::default();
+    for (category, count) in party {
+        let category = TicketCategory::from_str(category.trim()).map_err(|_| format!("알 수 없는 권종입니다: {}", category))?;
+        composition.set(category, *count);
+    }
+
+    if ad_cnt.is_some_and(|ad_cnt| ad_cnt != composition.adults()) || cd_cnt.is_some_and(|cd_cnt| cd_cnt != composition.children()) {
+        return Err(format!(
+            "ad_cnt / cd_cnt 가 권종별 인원 합계와 다릅니다. (성인 {}명, 어린이 {}명)",
+            composition.adults(), composition.children()
+        ));
+    }
+    Ok(composition)
+}
+
+// 권종 구성 → 응답용 권종별 인원
+pub fn party_counts(party: &PartyComposition) -> PartyCounts {
+    party.iter().map(|(category, count)| (category.to_string(), count)).collect()
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::payment::Payment;
+
+// ✅ StartPaymentRequest 구조체 (/payments 결제창 요청)
+#[derive(Debug, Deserialize)]
+pub struct StartPaymentRequest {
+    pub reservation_id: i32,
+}
+
+// ✅ PaymentDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct PaymentDTO {
+    pub id: i64,
+    pub reservation_id: i32,
+    pub provider: String,
+    pub provider_payment_id: Option<String>,
+    pub checkout_url: Option<String>, // 결제창 주소 (클라이언트가 이동)
+    pub amount: i64,
+    pub currency: String,
+    pub status: String, // PENDING / PAID / FAILED / EXPIRED / REFUND_PENDING / REFUNDED
+    pub refund_amount: Option<i64>,
+    pub failure_reason: Option<String>,
+    pub created_at: Option<String>,
+    pub paid_at: Option<String>,
+    pub refunded_at: Option<String>,
+}
+
+impl From<Payment> for PaymentDTO {
+    fn from(payment: Payment) -> Self {
+        PaymentDTO {
+            id: payment.id,
+            reservation_id: payment.reservation_id,
+            provider: payment.provider,
+            provider_payment_id: payment.provider_payment_id,
+            checkout_url: payment.checkout_url,
+            amount: payment.amount,
+            currency: payment.currency,
+            status: payment.status.to_string(),
+            refund_amount: payment.refund_amount,
+            failure_reason: payment.failure_reason,
+            created_at: payment.created_at.map(|dt| dt.to_rfc3339()),
+            paid_at: payment.paid_at.map(|dt| dt.to_rfc3339()),
+            refunded_at: payment.refunded_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{pricing::{ContentPrice, DiscountType, PriceQuote, PricingDiscount, ReservationPrice}, ticket_category::{PartyComposition, TicketCategory}};
+
+use super::party_dto::{resolve_party, PartyCounts};
+
+// ✅ QuoteRequest 구조체 (/quote 예약 전 금액 견적 요청)
+#[derive(Debug, Deserialize)]
+pub struct QuoteRequest {
+    pub content_schedule_id: u64,
+    pub ad_cnt: Option<i32>,
+    pub cd_cnt: Option<i32>,
+    pub party: Option<PartyCounts>, // 권종별 인원 (생략 시 ad_cnt / cd_cnt 사용)
+}
+
+impl QuoteRequest {
+    pub fn party(&self) -> Result<PartyComposition, String> {
+        resolve_party(self.party.as_ref(), self.ad_cnt, self.cd_cnt)
+    }
+}
+
+// ✅ PriceLineDTO 구조체 (권종별 금액)
+#[derive(Debug, Serialize)]
+pub struct PriceLineDTO {
+    pub category: String,
+    pub count: i32,
+    pub unit_price: i64,
+    pub amount: i64,
+}
+
+// ✅ AppliedDiscountDTO 구조체 (적용된 할인)
+#[derive(Debug, Serialize)]
+pub struct AppliedDiscountDTO {
+    pub discount_id: i64,
+    pub discount_type: String,
+    pub rate_percent: i32,
+    pub amount: i64,
+}
+
+// ✅ PriceQuoteDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct PriceQuoteDTO {
+    pub content_id: u64,
+    pub content_schedule_id: u64,
+    pub lines: Vec<PriceLineDTO>,
+    pub list_price: i64,
+    pub discount: Option<AppliedDiscountDTO>,
+    pub total_price: i64,
+    pub currency: String,
+}
+
+impl From<PriceQuote> for PriceQuoteDTO {
+    fn from(quote: PriceQuote) -> Self {
+        PriceQuoteDTO {
+            content_id: quote.content_id,
+            content_schedule_id: quote.content_schedule_id,
+            lines: quote.lines
+                .into_iter()
+                .map(|line| PriceLineDTO {
+                    category: line.category.to_string(),
+                    count: line.count,
+                    unit_price: line.unit_price,
+                    amount: line.amount,
+                })
+                .collect(),
+            list_price: quote.list_price,
+            discount: quote.discount.map(|discount| AppliedDiscountDTO {
+                discount_id: discount.discount_id,
+                discount_type: discount.discount_type.to_string(),
+                rate_percent: discount.rate_percent,
+                amount: discount.amount,
+            }),
+            total_price: quote.total_price,
+            currency: quote.currency,
+        }
+    }
+}
+
+// ✅ ReservationPriceDTO 구조체 (예약 응답에 포함되는 예약 시점 금액)
+#[derive(Debug, Serialize)]
+pub struct ReservationPriceDTO {
+    pub list_price: i64,
+    pub total_price: i64,
+    pub discount_type: Option<String>,
+    pub currency: String,
+}
+
+impl From<ReservationPrice> for ReservationPriceDTO {
+    fn from(price: ReservationPrice) -> Self {
+        ReservationPriceDTO {
+            list_price: price.list_price,
+            total_price: price.total_price,
+            discount_type: price.discount_type.map(|discount_type| discount_type.to_string()),
+            currency: price.currency,
+        }
+    }
+}
+
+// ✅ ContentPriceDTO 구조체 (권종별 단가, 요청 / 응답 공용)
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ContentPriceDTO {
+    pub category: String, // ADULT / CHILD / INFANT / SENIOR / DISABLED / COMPANION
+    pub unit_price: i64,
+}
+
+impl From<ContentPrice> for ContentPriceDTO {
+    fn from(price: ContentPrice) -> Self {
+        ContentPriceDTO {
+            category: price.category.to_string(),
+            unit_price: price.unit_price,
+        }
+    }
+}
+
+// ✅ UpdateContentPricesRequest 구조체 (컨텐츠 권종별 단가 수정 요청 - 전체 교체)
+#[derive(Debug, Deserialize)]
+pub struct UpdateContentPricesRequest {
+    #[serde(default)]
+    pub prices: Vec<ContentPriceDTO>, // 비우면 무료 컨텐츠
+}
+
+impl UpdateContentPricesRequest {
+    pub fn into_prices(self) -> Result<Vec<ContentPrice>, String> {
+        self.prices
+            .iter()
+            .map(|price| {
+                let category = TicketCategory::from_str(price.category.trim()).map_err(|_| format!("알 수 없는 권종입니다: {}", price.category))?;
+                Ok(ContentPrice { category, unit_price: price.unit_price })
+            })
+            .collect()
+    }
+}
+
+// ✅ DiscountRequest 구조체 (할인 규칙 등록 요청)
+#[derive(Debug, Deserialize)]
+pub struct DiscountRequest {
+    pub content_id: Option<u64>, // 생략 시 전체 컨텐츠
+    pub discount_type: String,   // MEMBER / GROUP / TIME_OF_DAY
+    pub rate_percent: i32,
+    pub min_party_size: Option<i32>, // GROUP 필수
+    pub start_hour: Option<u32>,     // TIME_OF_DAY 필수 (영업 시간대 기준, 시작 > 종료 이면 자정을 넘는 구간)
+    pub end_hour: Option<u32>,
+}
+
+impl DiscountRequest {
+    pub fn into_discount(self) -> Result<PricingDiscount, String> {
+        let discount_type = DiscountType::from_str(self.discount_type.trim()).map_err(|_| format!("알 수 없는 할인 종류입니다: {}", self.discount_type))?;
+        Ok(PricingDiscount {
+            id: 0,
+            content_id: self.content_id,
+            discount_type,
+            rate_percent: self.rate_percent,
+            min_party_size: self.min_party_size,
+            start_hour: self.start_hour,
+            end_hour: self.end_hour,
+            active: true,
+            created_at: None,
+        })
+    }
+}
+
+// ✅ DiscountDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct DiscountDTO {
+    pub id: i64,
+    pub content_id: Option<u64>,
+    pub discount_type: String,
+    pub rate_percent: i32,
+    pub min_party_size: Option<i32>,
+    pub start_hour: Option<u32>,
+    pub end_hour: Option<u32>,
+    pub active: bool,
+    pub created_at: Option<String>,
+}
+
+impl From<PricingDiscount> for DiscountDTO {
+    fn from(discount: PricingDiscount) -> Self {
+        DiscountDTO {
+            id: discount.id,
+            content_id: discount.content_id,
+            discount_type: discount.discount_type.to_string(),
+            rate_percent: discount.rate_percent,
+            min_party_size: discount.min_party_size,
+            start_hour: discount.start_hour,
+            end_hour: discount.end_hour,
+            active: discount.active,
+            created_at: discount.created_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
+
+// ✅ MembershipRequest 구조체 (회원 할인 대상 등록 요청)
+#[derive(Debug, Deserialize)]
+pub struct MembershipRequest {
+    pub expires_at: Option<String>, // RFC3339, 생략 시 만료 없음
+}
+
+impl MembershipRequest {
+    pub fn expires_at(&self) -> Result<Option<DateTime<Utc>>, String> {
+        self.expires_at
+            .as_deref()
+            .map(|expires_at| {
+                DateTime::parse_from_rfc3339(expires_at)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|_| format!("만료 시각 형식이 올바르지 않습니다: {}", expires_at))
+            })
+            .transpose()
+    }
+}
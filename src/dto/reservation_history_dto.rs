@@ -0,0 +1,57 @@
+use serde::Serialize;
+use crate::domain::reservation_history::{ReservationHistory, ReservationSnapshot};
+
+// ✅ 예약 변경 이력 (직원 조회용)
+#[derive(Debug, Serialize)]
+pub struct ReservationHistoryDTO {
+    pub id: i64,
+    pub reservation_id: i32,
+    pub action: String,
+    pub actor_id: Option<String>,
+    pub channel: String,
+    pub before: Option<ReservationSnapshotDTO>,
+    pub after: Option<ReservationSnapshotDTO>,
+    pub created_at: Option<String>,
+}
+
+// ✅ 변경 전 / 후 예약 상태
+#[derive(Debug, Serialize)]
+pub struct ReservationSnapshotDTO {
+    pub user_id: Option<String>,
+    pub content_schedule_id: u64,
+    pub ad_cnt: i32,
+    pub cd_cnt: i32,
+    pub status: Option<String>,
+    pub checked_in_ad_cnt: i32,
+    pub checked_in_cd_cnt: i32,
+}
+
+impl From<ReservationSnapshot> for ReservationSnapshotDTO {
+    fn from(snapshot: ReservationSnapshot) -> Self {
+        ReservationSnapshotDTO {
+            user_id: snapshot.user_id,
+            content_schedule_id: snapshot.content_schedule_id,
+            ad_cnt: snapshot.ad_cnt,
+            cd_cnt: snapshot.cd_cnt,
+            status: snapshot.status.map(|status| status.to_string()),
+            checked_in_ad_cnt: snapshot.checked_in_ad_cnt,
+            checked_in_cd_cnt: snapshot.checked_in_cd_cnt,
+        }
+    }
+}
+
+// ✅ ReservationHistory → ReservationHistoryDTO 변환 함수
+impl From<ReservationHistory> for ReservationHistoryDTO {
+    fn from(history: ReservationHistory) -> Self {
+        ReservationHistoryDTO {
+            id: history.id,
+            reservation_id: history.reservation_id,
+            action: history.action.to_string(),
+            actor_id: history.actor_id,
+            channel: history.channel.to_string(),
+            before: history.before.map(ReservationSnapshotDTO::from),
+            after: history.after.map(ReservationSnapshotDTO::from),
+            created_at: history.created_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::domain::reservation::ReservationStatus;
+
+// ✅ ReservationQuery 구조체 (예약 목록 조회 쿼리 파라미터)
+#[derive(Debug, Deserialize)]
+pub struct ReservationQuery {
+    pub from: Option<String>, // RFC3339 (예약 시각 기준, 포함)
+    pub to: Option<String>,   // RFC3339 (예약 시각 기준, 미포함)
+    pub schedule_id: Option<u64>,
+    pub content_id: Option<u64>,
+    pub status: Option<String>, // PENDING | CONFIRMED | CANCELLED | NO_SHOW
+    pub user_id: Option<String>,
+    pub sort: Option<String>,   // reserved_at | start_time | id
+    pub order: Option<String>,  // asc | desc
+}
+
+// 정렬 기준 (허용된 컬럼만 사용)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReservationSort {
+    #[default]
+    ReservedAt,
+    StartTime,
+    Id,
+}
+
+impl ReservationSort {
+    // 정렬 / 커서 비교식 (NULL 은 NULL_SORT_VALUE 로 취급해 ORDER BY 와 커서 조건이 같은 순서를 보도록 함)
+    pub fn column(&self) -> &'static str {
+        match self {
+            ReservationSort::ReservedAt => "COALESCE(r.reserved_at, TIMESTAMP('1970-01-01 00:00:00'))",
+            ReservationSort::StartTime => "COALESCE(cs.start_time, TIMESTAMP('1970-01-01 00:00:00'))",
+            ReservationSort::Id => "r.id",
+        }
+    }
+}
+
+// ✅ ReservationFilter 구조체 (저장소 조회 조건)
+#[derive(Debug, Clone, Default)]
+pub struct ReservationFilter {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub schedule_id: Option<u64>,
+    pub content_id: Option<u64>,
+    pub status: Option<ReservationStatus>,
+    pub user_id: Option<String>,
+    pub sort: ReservationSort,
+    pub descending: bool,
+}
+
+impl ReservationFilter {
+    // 조회 조건이 하나도 없는지 여부 (없으면 오늘 예약 조회)
+    pub fn has_no_condition(&self) -> bool {
+        self.from.is_none()
+            && self.to.is_none()
+            && self.schedule_id.is_none()
+            && self.content_id.is_none()
+            && self.status.is_none()
+            && self.user_id.is_none()
+    }
+}
+
+fn parse_datetime(name: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| format!("{}는 RFC3339 형식이어야 합니다: {}", name, value))
+}
+
+// ✅ 쿼리 파라미터 검증 및 변환
+impl TryFrom<ReservationQuery> for ReservationFilter {
+    type Error = String;
+
+    fn try_from(query: ReservationQuery) -> Result<Self, Self::Error> {
+        let from = query.from.as_deref().map(|v| parse_datetime("from", v)).transpose()?;
+        let to = query.to.as_deref().map(|v| parse_datetime("to", v)).transpose()?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from >= to {
+                return Err("from은 to보다 이전이어야 합니다.".to_string());
+            }
+        }
+
+        let status = query.status
+            .as_deref()
+            .map(|s| ReservationStatus::from_str(&s.to_uppercase()).map_err(|_| format!("알 수 없는 예약 상태입니다: {}", s)))
+            .transpose()?;
+
+        let sort = match query.sort.as_deref() {
+            None | Some("reserved_at") => ReservationSort::ReservedAt,
+            Some("start_time") => ReservationSort::StartTime,
+            Some("id") => ReservationSort::Id,
+            Some(other) => return Err(format!("지원하지 않는 정렬 기준입니다: {}", other)),
+        };
+        let descending = match query.order.as_deref() {
+            None | Some("asc") => false,
+            Some("desc") => true,
+            Some(other) => return Err(format!("order는 asc 또는 desc 여야 합니다: {}", other)),
+        };
+
+        Ok(Self {
+            from,
+            to,
+            schedule_id: query.schedule_id,
+            content_id: query.content_id,
+            status,
+            user_id: query.user_id.filter(|id| !id.trim().is_empty()),
+            sort,
+            descending,
+        })
+    }
+}
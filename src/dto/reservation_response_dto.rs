@@ -1,6 +1,8 @@
 use serde::Serialize;
 use crate::domain::reservation::Reservation;
 
+use super::{party_dto::{party_counts, PartyCounts}, pricing_dto::ReservationPriceDTO};
+
 // ✅ ReservationDTO 구조체 (API 응답용)
 #[derive(Debug, Serialize)]
 pub struct ReservationDTO {
@@ -8,22 +10,59 @@ pub struct ReservationDTO {
     pub user_id: String,
     pub content_schedule_id: u64,
     pub reserved_at: Option<String>,
+    pub status: Option<String>,
     pub ad_cnt: i32,
     pub cd_cnt: i32, 
+    pub party: PartyCounts, // 권종별 인원 (ad_cnt / cd_cnt 는 성인 / 어린이 합계)
     pub use_at: bool,
+    pub checked_in_ad_cnt: i32,
+    pub checked_in_cd_cnt: i32,
+    pub group_reservation_id: Option<i64>,
+    pub payment_due_at: Option<String>, // 결제 대기(PENDING_PAYMENT) 예약의 결제 기한
+    pub price: Option<ReservationPriceDTO>, // 예약 시점 금액 (가격 산정 이전 예약 / 단체 예약은 없음)
+    pub cancellable: bool,
+    pub usable: bool,
+    pub editable: bool,
+    pub schedule: Option<ReservationScheduleDTO>,
+    pub ticket_token: Option<String>,
+}
+
+// ✅ 예약된 회차 정보 (시작 시간 / 컨텐츠)
+#[derive(Debug, Serialize)]
+pub struct ReservationScheduleDTO {
+    pub start_time: Option<String>,
+    pub content_id: u64,
+    pub content_title: Option<String>,
 }
 
 // ✅ Reservation → ReservationDTO 변환 함수
 impl From<Reservation> for ReservationDTO {
     fn from(reservation: Reservation) -> Self {
+        let flags = reservation.flags.unwrap_or_default(); // 서비스에서 계산되지 않았으면 모두 false
         ReservationDTO {
             id: reservation.id,
             user_id: reservation.user_id,
             content_schedule_id: reservation.content_schedule_id,
             reserved_at: reservation.reserved_at.map(|dt| dt.to_rfc3339()), // ✅ `DateTime<Utc>`를 `String`으로 변환
+            status: reservation.status.map(|status| status.to_string()),
             ad_cnt: reservation.ad_cnt,
             cd_cnt: reservation.cd_cnt,
+            party: party_counts(&reservation.party),
             use_at: reservation.use_at,
+            checked_in_ad_cnt: reservation.checked_in_ad_cnt,
+            checked_in_cd_cnt: reservation.checked_in_cd_cnt,
+            group_reservation_id: reservation.group_reservation_id,
+            payment_due_at: reservation.payment_due_at.map(|dt| dt.to_rfc3339()),
+            price: reservation.price.map(ReservationPriceDTO::from),
+            cancellable: flags.cancellable,
+            usable: flags.usable,
+            editable: flags.editable,
+            schedule: reservation.schedule.map(|schedule| ReservationScheduleDTO {
+                start_time: schedule.start_time.map(|dt| dt.to_rfc3339()),
+                content_id: schedule.content_id,
+                content_title: schedule.content_title,
+            }),
+            ticket_token: reservation.ticket_token,
         }
     }
-}
\ No newline at end of file
+}
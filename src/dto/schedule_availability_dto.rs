@@ -0,0 +1,31 @@
+use serde::Serialize;
+use crate::domain::schedule_availability::ScheduleAvailability;
+
+// ✅ ScheduleAvailabilityDTO 구조체 (잔여 좌석 API 응답용)
+#[derive(Debug, Serialize)]
+pub struct ScheduleAvailabilityDTO {
+    pub content_schedule_id: u64,
+    pub content_id: u64,
+    pub start_time: Option<String>,
+    pub total_seats: i32,
+    pub booked_adults: i32,
+    pub booked_children: i32,
+    pub seats_remaining: i32,
+    pub can_book: Option<bool>,
+}
+
+// ✅ ScheduleAvailability → ScheduleAvailabilityDTO 변환 함수
+impl From<ScheduleAvailability> for ScheduleAvailabilityDTO {
+    fn from(availability: ScheduleAvailability) -> Self {
+        ScheduleAvailabilityDTO {
+            content_schedule_id: availability.content_schedule_id,
+            content_id: availability.content_id,
+            start_time: availability.start_time.map(|dt| dt.to_rfc3339()),
+            total_seats: availability.total_seats,
+            booked_adults: availability.booked_adults,
+            booked_children: availability.booked_children,
+            seats_remaining: availability.seats_remaining,
+            can_book: availability.can_book,
+        }
+    }
+}
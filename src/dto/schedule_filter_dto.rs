@@ -0,0 +1,58 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+// ✅ ScheduleAvailabilityQuery 구조체 (일괄 잔여 좌석 조회 쿼리 파라미터)
+#[derive(Debug, Deserialize)]
+pub struct ScheduleAvailabilityQuery {
+    pub date: Option<String>,         // YYYY-MM-DD
+    pub schedule_ids: Option<String>, // "1,2,3"
+    pub content_id: Option<u64>,
+}
+
+// ✅ ScheduleAvailabilityFilter 구조체 (저장소 조회 조건)
+// date는 서비스에서 영업 시간대 기준 [start_time, end_time) 범위로 변환
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleAvailabilityFilter {
+    pub date: Option<NaiveDate>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub schedule_ids: Vec<u64>,
+    pub content_id: Option<u64>,
+}
+
+// ✅ 쿼리 파라미터 검증 및 변환 (날짜 또는 스케줄 ID 목록 중 하나는 필수)
+impl TryFrom<ScheduleAvailabilityQuery> for ScheduleAvailabilityFilter {
+    type Error = String;
+
+    fn try_from(query: ScheduleAvailabilityQuery) -> Result<Self, Self::Error> {
+        let date = match query.date.as_deref() {
+            Some(date) => Some(
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .map_err(|_| format!("잘못된 날짜 형식입니다 (YYYY-MM-DD): {}", date))?,
+            ),
+            None => None,
+        };
+
+        let schedule_ids = match query.schedule_ids.as_deref() {
+            Some(ids) => ids
+                .split(',')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(|id| id.parse::<u64>().map_err(|_| format!("잘못된 스케줄 ID입니다: {}", id)))
+                .collect::<Result<Vec<u64>, String>>()?,
+            None => Vec::new(),
+        };
+
+        if date.is_none() && schedule_ids.is_empty() {
+            return Err("date 또는 schedule_ids 중 하나는 필수입니다.".to_string());
+        }
+
+        Ok(Self {
+            date,
+            start_time: None,
+            end_time: None,
+            schedule_ids,
+            content_id: query.content_id,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::reservation_transfer::ReservationTransfer;
+
+// ✅ TransferRequest 구조체 (/transfers 양도 요청)
+#[derive(Debug, Deserialize)]
+pub struct TransferRequest {
+    pub reservation_id: i32,
+    pub to_user_id: String,
+}
+
+// ✅ TransferQuery 구조체 (/transfers?limit=..)
+#[derive(Debug, Deserialize)]
+pub struct TransferQuery {
+    pub limit: Option<u32>,
+}
+
+// ✅ ReservationTransferDTO 구조체 (API 응답용)
+#[derive(Debug, Serialize)]
+pub struct ReservationTransferDTO {
+    pub id: i64,
+    pub reservation_id: i32,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub status: String,
+    pub created_at: Option<String>,
+    pub expires_at: String,
+    pub responded_at: Option<String>,
+}
+
+impl From<ReservationTransfer> for ReservationTransferDTO {
+    fn from(transfer: ReservationTransfer) -> Self {
+        ReservationTransferDTO {
+            id: transfer.id,
+            reservation_id: transfer.reservation_id,
+            from_user_id: transfer.from_user_id,
+            to_user_id: transfer.to_user_id,
+            status: transfer.status.to_string(),
+            created_at: transfer.created_at.map(|dt| dt.to_rfc3339()),
+            expires_at: transfer.expires_at.to_rfc3339(),
+            responded_at: transfer.responded_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
@@ -1,8 +1,19 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::ticket_category::PartyComposition;
+
+use super::party_dto::{resolve_party, PartyCounts};
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct UpdateReservationRequest {
     pub reservation_id: i32,
-    pub ad_cnt: i32,
-    pub cd_cnt: i32,
+    pub ad_cnt: Option<i32>,
+    pub cd_cnt: Option<i32>,
+    pub party: Option<PartyCounts>, // 권종별 인원 (생략 시 ad_cnt / cd_cnt 사용)
+}
+
+impl UpdateReservationRequest {
+    pub fn party(&self) -> Result<PartyComposition, String> {
+        resolve_party(self.party.as_ref(), self.ad_cnt, self.cd_cnt)
+    }
 }
\ No newline at end of file
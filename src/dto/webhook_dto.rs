@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use crate::domain::{reservation_event::ReservationEventType, webhook::{WebhookDelivery, WebhookSubscription}};
+
+// ✅ WebhookSubscriptionRequest 구조체 (웹훅 구독 등록 / 수정 요청)
+#[derive(Debug, Deserialize)]
+pub struct WebhookSubscriptionRequest {
+    pub url: String,
+    pub secret: Option<String>,           // 수정 시 생략하면 기존 값 유지
+    pub event_types: Option<Vec<String>>, // 생략하거나 비어 있으면 모든 이벤트
+    pub active: Option<bool>,             // 생략 시 활성
+}
+
+impl WebhookSubscriptionRequest {
+    pub fn parse_event_types(&self) -> Result<Vec<ReservationEventType>, String> {
+        self.event_types
+            .iter()
+            .flatten()
+            .map(|t| ReservationEventType::from_str(t.trim()).map_err(|_| format!("알 수 없는 이벤트 종류입니다: {}", t)))
+            .collect()
+    }
+}
+
+// ✅ DeadLetterQuery 구조체 (/admin/webhooks/dead-letters?subscription_id=..&limit=..)
+#[derive(Debug, Deserialize)]
+pub struct DeadLetterQuery {
+    pub subscription_id: Option<i64>,
+    pub limit: Option<u32>,
+}
+
+// ✅ WebhookSubscriptionDTO 구조체 (API 응답용, secret 제외)
+#[derive(Debug, Serialize)]
+pub struct WebhookSubscriptionDTO {
+    pub id: i64,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_at: Option<String>,
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionDTO {
+    fn from(subscription: WebhookSubscription) -> Self {
+        WebhookSubscriptionDTO {
+            id: subscription.id,
+            url: subscription.url,
+            event_types: subscription.event_types.iter().map(|t| t.to_string()).collect(),
+            active: subscription.active,
+            created_at: subscription.created_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
+
+// ✅ WebhookDeliveryDTO 구조체 (전달 / 데드레터 조회용)
+#[derive(Debug, Serialize)]
+pub struct WebhookDeliveryDTO {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub event_id: i64,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: i32,
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub body: serde_json::Value,
+    pub created_at: Option<String>,
+    pub delivered_at: Option<String>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDTO {
+    fn from(delivery: WebhookDelivery) -> Self {
+        WebhookDeliveryDTO {
+            id: delivery.id,
+            subscription_id: delivery.subscription_id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            status: delivery.status.to_string(),
+            attempts: delivery.attempts,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            body: serde_json::from_str(&delivery.body).unwrap_or(serde_json::Value::String(delivery.body)),
+            created_at: delivery.created_at.map(|dt| dt.to_rfc3339()),
+            delivered_at: delivery.delivered_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
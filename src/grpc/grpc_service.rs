@@ -1,9 +1,12 @@
 use tonic::{Request, Response, Status};
 use std::sync::Arc;
-use crate::{application::port::r#in::reservation_usecase::ReservationUseCase, domain::reservation::Reservation, reservation_proto::{reservation_service_server::ReservationService, CreateReservationRequest, CreateReservationResponse}};
+use tokio::sync::Mutex;
+use crate::{application::port::r#in::{idempotency_usecase::IdempotencyUseCase, reservation_usecase::ReservationUseCase}, domain::{idempotency::{IdempotencyClaim, IdempotentResponse}, policy_violation::BookingOutcome, reservation::Reservation, reservation_history::{AuditChannel, AuditContext}}, grpc_client::GrpcClients, reservation_proto::{reservation_service_server::ReservationService, CreateReservationRequest, CreateReservationResponse, ScheduleAvailabilityRequest, ScheduleAvailabilityResponse}};
 
 pub struct ReservationGrpcService {
-    reservation_service: Arc<dyn ReservationUseCase + Send + Sync>,  
+    reservation_service: Arc<dyn ReservationUseCase + Send + Sync>,
+    idempotency: Arc<dyn IdempotencyUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
 }
 
 #[tonic::async_trait]
@@ -15,28 +18,115 @@ impl ReservationService for ReservationGrpcService {
         let req = request.into_inner();
         println!("Received reservation request: {:?}", req);
 
-         
-        let reservation: Reservation = req.into();
+        // idempotency_key가 있으면 동일 키 재시도 시 최초 응답 반환
+        let user_id = req.user_id.clone();
+        let idempotency_key = Some(req.idempotency_key.trim().to_string()).filter(|key| !key.is_empty());
+        // party 가 비어 있으면 기존 클라이언트와 같이 ad_cnt / cd_cnt 사용
+        let reservation = Reservation::try_from(req).map_err(Status::invalid_argument)?;
+        if let Some(key) = &idempotency_key {
+            // 인원은 권종 구성 기준으로 비교
+            let fingerprint = format!("{}:{}", reservation.content_schedule_id, reservation.party);
+            match self.idempotency.begin(&user_id, key, &fingerprint).await {
+                Ok(IdempotencyClaim::Acquired) => {},
+                Ok(IdempotencyClaim::Replay(response)) => {
+                    return Ok(Response::new(CreateReservationResponse {
+                        success: true,
+                        message: response.message,
+                    }));
+                },
+                Ok(IdempotencyClaim::InProgress) => return Err(Status::aborted("Reservation with the same idempotency key is in progress")),
+                Ok(IdempotencyClaim::Mismatch) => return Err(Status::failed_precondition("Idempotency key was already used for a different request")),
+                Err(err) => return Err(Status::invalid_argument(err)),
+            }
+        }
+
+        // User-Service에서 사용자별 예약 한도 조회 (REST 와 같은 예약 정책 적용)
+        let user_info = {
+            let mut grpc_clients = self.grpc_clients.lock().await;
+            // 오류 타입이 Send 가 아니므로 잠금 블록 안에서 문자열로 변환
+            grpc_clients.get_user_info(user_id.clone()).await.map_err(|e| e.to_string())
+        };
+
         // 기존 서비스 (`ReservationService`) 사용
-        let result = self.reservation_service.create_reservation(reservation).await;
+        let result = match user_info {
+            Ok(user_info) => self.reservation_service
+                .create_reservation(reservation, user_info.ad_cnt, user_info.cd_cnt, &AuditContext::new(Some(user_id.clone()), AuditChannel::Grpc))
+                .await,
+            Err(err) => Err(format!("User Service Error: {}", err)),
+        };
 
         let response = match result {
-            Ok(_) => CreateReservationResponse {
+            Ok(BookingOutcome::Created) => CreateReservationResponse {
                 success: true,
                 message: "Reservation created successfully".to_string(),
             },
+            Ok(BookingOutcome::Rejected(violations)) => {
+                let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+                CreateReservationResponse {
+                    success: false,
+                    message: format!("Reservation rejected: {}", reasons.join(", ")),
+                }
+            },
             Err(err) => CreateReservationResponse {
                 success: false,
                 message: format!("Failed to create reservation: {}", err),
             },
         };
 
+        // 성공한 응답만 저장하고, 실패 시 키를 해제하여 재시도 허용
+        if let Some(key) = &idempotency_key {
+            let saved = if response.success {
+                self.idempotency.complete(&user_id, key, IdempotentResponse { status_code: 200, message: response.message.clone() }).await
+            } else {
+                self.idempotency.release(&user_id, key).await
+            };
+            if let Err(err) = saved {
+                println!("멱등성 키 상태 저장 실패: {}", err);
+            }
+        }
+
         Ok(Response::new(response))
     }
+
+    async fn get_schedule_availability(
+        &self,
+        request: Request<ScheduleAvailabilityRequest>,
+    ) -> Result<Response<ScheduleAvailabilityResponse>, Status> {
+        let req = request.into_inner();
+        println!("Received schedule availability request: {:?}", req);
+
+        // User-Service에서 사용자별 예약 한도 조회
+        let user_info = {
+            let mut grpc_clients = self.grpc_clients.lock().await;
+            grpc_clients
+                .get_user_info(req.user_id.clone())
+                .await
+                .map_err(|err| Status::unavailable(format!("User Service Error: {}", err)))?
+        };
+
+        let availability = self.reservation_service
+            .show_schedule_availability(req.user_id, req.content_schedule_id, user_info.ad_cnt, user_info.cd_cnt)
+            .await
+            .map_err(Status::internal)?
+            .ok_or_else(|| Status::not_found(format!("Schedule not found: {}", req.content_schedule_id)))?;
+
+        Ok(Response::new(ScheduleAvailabilityResponse {
+            content_schedule_id: availability.content_schedule_id,
+            total_seats: availability.total_seats,
+            booked_adults: availability.booked_adults,
+            booked_children: availability.booked_children,
+            seats_remaining: availability.seats_remaining,
+            can_book: availability.can_book.unwrap_or(false),
+        }))
+    }
 }
 
 impl ReservationGrpcService {
-    pub fn new(reservation_service: Arc<dyn ReservationUseCase + Send + Sync>) -> Self {
-        ReservationGrpcService { reservation_service }
+    pub fn new(
+        reservation_service: Arc<dyn ReservationUseCase + Send + Sync>,
+        idempotency: Arc<dyn IdempotencyUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>,
+    ) -> Self {
+        ReservationGrpcService { reservation_service, idempotency, grpc_clients }
     }
 }
\ No newline at end of file
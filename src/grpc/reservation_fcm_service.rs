@@ -3,7 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 // ✅ 기존 서비스 포트 import
-use crate::{application::port::out::reservation_load_port::ReservationLoadPort, reservationfcm_proto::{reservation_service_server::ReservationService, ContentScheduleRequest, UserList}};
+use crate::{application::port::out::reservation_load_port::ReservationLoadPort, common::page::PageRequest, reservationfcm_proto::{reservation_service_server::ReservationService, ContentScheduleRequest, UserList}};
 
 pub struct ReservationFcmGrpcService {
     reservation_port: Arc<dyn ReservationLoadPort + Send + Sync>,  
@@ -28,16 +28,26 @@ impl ReservationService for ReservationFcmGrpcService {
             Ok(id) => id,
             Err(_) => return Err(Status::invalid_argument("Invalid content_schedule_id format")),
         };
+
+        // limit 을 지정하지 않은 기존 클라이언트는 전체 조회
+        let page = if req.limit == 0 {
+            PageRequest::unbounded()
+        } else {
+            PageRequest::new(Some(req.limit), Some(req.cursor.as_str())).map_err(Status::invalid_argument)?
+        };
         
-        let reservations = match self.reservation_port.load_reservations_by_content_schedule(content_schedule_id).await {
+        let reservations = match self.reservation_port.load_reservations_by_content_schedule(content_schedule_id, page).await {
             Ok(reservations) => reservations,
             Err(err) => return Err(Status::internal(format!("Failed to load reservations: {}", err))),
         };
 
         // user_id 추출
-        let user_ids: Vec<String> = reservations.into_iter().map(|r| r.user_id.to_string()).collect();
+        let user_ids: Vec<String> = reservations.items.into_iter().map(|r| r.user_id.to_string()).collect();
 
-        let response = UserList { user_ids };
+        let response = UserList {
+            user_ids,
+            next_cursor: reservations.next_cursor.unwrap_or_default(),
+        };
 
         Ok(Response::new(response))
     }
@@ -10,7 +10,11 @@ pub async fn run_grpc_server(state: Arc<AppState>) -> Result<(), tonic::transpor
         .parse()
         .unwrap();
 
-    let service = ReservationGrpcService::new(Arc::clone(&state.reservation_service));
+    let service = ReservationGrpcService::new(
+        Arc::clone(&state.reservation_service),
+        Arc::clone(&state.idempotency_service),
+        Arc::clone(&state.grpc_clients),
+    );
 
     println!("gRPC Server running at {}", addr);
 
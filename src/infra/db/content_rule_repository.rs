@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::content_rule::ContentBookingRule;
+
+#[async_trait]
+pub trait ContentRuleRepository: Send + Sync {
+    async fn load_content_rule(&self, content_id: u64) -> Result<Option<ContentBookingRule>, String>;
+    async fn save_content_rule(&self, rule: ContentBookingRule) -> Result<(), String>;
+}
\ No newline at end of file
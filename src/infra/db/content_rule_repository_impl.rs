@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use sqlx::{query, MySqlPool, Row};
+use std::sync::Arc;
+use std::str::FromStr;
+use crate::{domain::{content_rule::{CategoryRule, ContentBookingRule}, ticket_category::TicketCategory}, infra::db::content_rule_repository::ContentRuleRepository};
+
+// Repository Implementation
+pub struct ContentRuleRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl ContentRuleRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ContentRuleRepository for ContentRuleRepositoryImpl {
+    async fn load_content_rule(&self, content_id: u64) -> Result<Option<ContentBookingRule>, String> {
+        let row = query(
+            "
+            SELECT 
+                content_id, children_only, max_party_size, max_adults, max_children,
+                booking_opens_days_before, booking_closes_minutes_before
+            FROM CONTENT_BOOKING_RULE
+            WHERE content_id = ?
+            "
+        )
+        .bind(content_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| err.to_string())?;
+
+        let category_rows = query(
+            "
+            SELECT category, max_per_reservation, max_per_schedule
+            FROM CONTENT_CATEGORY_RULE
+            WHERE content_id = ?
+            "
+        )
+        .bind(content_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| err.to_string())?;
+
+        let categories: Vec<CategoryRule> = category_rows
+            .iter()
+            .filter_map(|row| {
+                let category = TicketCategory::from_str(&row.get::<String, _>("category")).ok()?;
+                Some(CategoryRule {
+                    category,
+                    max_per_reservation: row.try_get("max_per_reservation").ok().flatten(),
+                    max_per_schedule: row.try_get("max_per_schedule").ok().flatten(),
+                })
+            })
+            .collect();
+
+        // 권종 규칙만 등록된 컨텐츠도 규칙이 있는 것으로 간주
+        if row.is_none() && categories.is_empty() {
+            return Ok(None);
+        }
+        let Some(row) = row else {
+            return Ok(Some(ContentBookingRule { content_id, categories, ..Default::default() }));
+        };
+
+        Ok(Some(ContentBookingRule {
+            content_id: row.get("content_id"),
+            children_only: row.get::<i8, _>("children_only") != 0, // `TINYINT(1)` → `bool` 변환
+            max_party_size: row.try_get("max_party_size").ok().flatten(),
+            max_adults: row.try_get("max_adults").ok().flatten(),
+            max_children: row.try_get("max_children").ok().flatten(),
+            booking_opens_days_before: row.try_get("booking_opens_days_before").ok().flatten(),
+            booking_closes_minutes_before: row.try_get("booking_closes_minutes_before").ok().flatten(),
+            categories,
+        }))
+    }
+
+    // 컨텐츠 규칙 저장 (없으면 생성, 있으면 덮어쓰기, 권종 규칙은 요청 내용으로 교체)
+    async fn save_content_rule(&self, rule: ContentBookingRule) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|err| err.to_string())?;
+
+        query(
+            "
+            INSERT INTO CONTENT_BOOKING_RULE 
+                (content_id, children_only, max_party_size, max_adults, max_children,
+                 booking_opens_days_before, booking_closes_minutes_before)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                children_only = VALUES(children_only),
+                max_party_size = VALUES(max_party_size),
+                max_adults = VALUES(max_adults),
+                max_children = VALUES(max_children),
+                booking_opens_days_before = VALUES(booking_opens_days_before),
+                booking_closes_minutes_before = VALUES(booking_closes_minutes_before)
+            "
+        )
+        .bind(rule.content_id)
+        .bind(rule.children_only as i8)
+        .bind(rule.max_party_size)
+        .bind(rule.max_adults)
+        .bind(rule.max_children)
+        .bind(rule.booking_opens_days_before)
+        .bind(rule.booking_closes_minutes_before)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| err.to_string())?;
+
+        query("DELETE FROM CONTENT_CATEGORY_RULE WHERE content_id = ?")
+            .bind(rule.content_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        for category_rule in &rule.categories {
+            query(
+                "INSERT INTO CONTENT_CATEGORY_RULE (content_id, category, max_per_reservation, max_per_schedule)
+                 VALUES (?, ?, ?, ?)"
+            )
+            .bind(rule.content_id)
+            .bind(category_rule.category.to_string())
+            .bind(category_rule.max_per_reservation)
+            .bind(category_rule.max_per_schedule)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| err.to_string())?;
+        }
+
+        tx.commit().await.map_err(|err| err.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,465 @@
+use async_trait::async_trait;
+use sqlx::{mysql::MySqlRow, query, MySql, MySqlPool, QueryBuilder, Row, Transaction};
+use std::{collections::HashMap, sync::Arc};
+use crate::{domain::{group_booking::{GroupQuota, GroupReservation, GroupReservationItem, GroupReservationStatus, Organisation, OrganisationType}, pricing::ReservationPrice, reservation::ReservationStatus, reservation_history::{AuditContext, HistoryAction}, ticket_category::PartyComposition}, infra::db::{group_booking_repository::GroupBookingRepository, reservation_repository_impl::ReservationRepositoryImpl}};
+
+const ORGANISATION_COLUMNS: &str = "o.id, o.name, o.org_type, o.max_party_size, o.active, o.created_at";
+
+const GROUP_RESERVATION_COLUMNS: &str = "
+    g.id, g.organisation_id, g.requested_by, g.status, g.note,
+    g.reviewed_by, g.review_note, g.reviewed_at, g.created_at
+";
+
+// Repository Implementation
+pub struct GroupBookingRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl GroupBookingRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+
+    fn map_organisation(row: &MySqlRow) -> Organisation {
+        Organisation {
+            id: row.get("id"),
+            name: row.get("name"),
+            org_type: row.get::<String, _>("org_type").parse().unwrap_or(OrganisationType::Other),
+            max_party_size: row.get("max_party_size"),
+            active: row.get("active"),
+            created_at: row.try_get("created_at").ok(),
+        }
+    }
+
+    fn map_group_quota(row: &MySqlRow) -> GroupQuota {
+        GroupQuota {
+            content_schedule_id: row.get("content_schedule_id"),
+            quota_seats: row.get("quota_seats"),
+            held_seats: row.get("held_seats"),
+        }
+    }
+
+    // 항목은 load_items 로 별도 조회
+    fn map_group_reservation(row: &MySqlRow) -> GroupReservation {
+        GroupReservation {
+            id: row.get("id"),
+            organisation_id: row.get("organisation_id"),
+            requested_by: row.get("requested_by"),
+            status: row.get::<String, _>("status").parse().unwrap_or(GroupReservationStatus::Requested),
+            note: row.try_get("note").ok().flatten(),
+            reviewed_by: row.try_get("reviewed_by").ok().flatten(),
+            review_note: row.try_get("review_note").ok().flatten(),
+            reviewed_at: row.try_get("reviewed_at").ok().flatten(),
+            created_at: row.try_get("created_at").ok(),
+            items: Vec::new(),
+        }
+    }
+
+    fn map_item(row: &MySqlRow) -> GroupReservationItem {
+        GroupReservationItem {
+            content_schedule_id: row.get("content_schedule_id"),
+            ad_cnt: row.get("ad_cnt"),
+            cd_cnt: row.get("cd_cnt"),
+            reservation_id: row.try_get("reservation_id").ok().flatten(),
+        }
+    }
+
+    // 단체 예약들의 회차별 항목을 한 번에 조회해 채움
+    async fn load_items(&self, groups: &mut [GroupReservation]) -> Result<(), String> {
+        if groups.is_empty() {
+            return Ok(());
+        }
+        let mut builder = QueryBuilder::<MySql>::new(
+            "SELECT group_reservation_id, content_schedule_id, ad_cnt, cd_cnt, reservation_id
+             FROM GROUP_RESERVATION_ITEM
+             WHERE group_reservation_id IN ("
+        );
+        let mut ids = builder.separated(", ");
+        for group in groups.iter() {
+            ids.push_bind(group.id);
+        }
+        ids.push_unseparated(") ORDER BY content_schedule_id");
+
+        let rows = builder.build().fetch_all(&*self.pool).await.map_err(|e| e.to_string())?;
+        let mut items: HashMap<i64, Vec<GroupReservationItem>> = HashMap::new();
+        for row in &rows {
+            items.entry(row.get("group_reservation_id")).or_default().push(Self::map_item(row));
+        }
+        for group in groups.iter_mut() {
+            group.items = items.remove(&group.id).unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    // 단체 예약 행 잠금 + 상태 / 항목 조회 (승인 / 반려 / 취소 공통)
+    async fn lock_group_reservation(tx: &mut Transaction<'_, MySql>, group_reservation_id: i64) -> Result<GroupReservation, String> {
+        let row = query(&format!("SELECT {} FROM GROUP_RESERVATION g WHERE g.id = ? FOR UPDATE", GROUP_RESERVATION_COLUMNS))
+            .bind(group_reservation_id)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or(format!("단체 예약을 찾을 수 없습니다! ID: {}", group_reservation_id))?;
+        let mut group = Self::map_group_reservation(&row);
+
+        let rows = query(
+            "SELECT content_schedule_id, ad_cnt, cd_cnt, reservation_id
+             FROM GROUP_RESERVATION_ITEM
+             WHERE group_reservation_id = ?
+             ORDER BY content_schedule_id"
+        )
+        .bind(group_reservation_id)
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+        group.items = rows.iter().map(Self::map_item).collect();
+        Ok(group)
+    }
+
+    // 단체 좌석 반환 (반려 / 취소)
+    async fn release_seats(tx: &mut Transaction<'_, MySql>, group: &GroupReservation) -> Result<(), String> {
+        for item in &group.items {
+            query(
+                "UPDATE SCHEDULE_GROUP_QUOTA
+                 SET held_seats = GREATEST(held_seats - ?, 0)
+                 WHERE content_schedule_id = ?"
+            )
+            .bind(item.party_size())
+            .bind(item.content_schedule_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    async fn update_group_status(
+        tx: &mut Transaction<'_, MySql>,
+        group_reservation_id: i64,
+        status: GroupReservationStatus,
+        reviewer_id: Option<&str>,
+        review_note: Option<&str>,
+    ) -> Result<(), String> {
+        let sql = if reviewer_id.is_some() {
+            "UPDATE GROUP_RESERVATION SET status = ?, reviewed_by = ?, review_note = ?, reviewed_at = NOW() WHERE id = ?"
+        } else {
+            "UPDATE GROUP_RESERVATION SET status = ? WHERE id = ?"
+        };
+        let mut update = query(sql).bind(status.to_string());
+        if let Some(reviewer_id) = reviewer_id {
+            update = update.bind(reviewer_id).bind(review_note);
+        }
+        update
+            .bind(group_reservation_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GroupBookingRepository for GroupBookingRepositoryImpl {
+    async fn save_organisation(&self, organisation: &Organisation) -> Result<i64, String> {
+        let inserted = query("INSERT INTO ORGANISATION (name, org_type, max_party_size, active) VALUES (?, ?, ?, ?)")
+            .bind(&organisation.name)
+            .bind(organisation.org_type.to_string())
+            .bind(organisation.max_party_size)
+            .bind(organisation.active)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(inserted.last_insert_id() as i64)
+    }
+
+    async fn load_organisation(&self, organisation_id: i64) -> Result<Option<Organisation>, String> {
+        let row = query(&format!("SELECT {} FROM ORGANISATION o WHERE o.id = ?", ORGANISATION_COLUMNS))
+            .bind(organisation_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_organisation))
+    }
+
+    // 사용자당 하나의 단체 (다른 단체에 속해 있으면 이동)
+    async fn save_organisation_member(&self, organisation_id: i64, user_id: &str) -> Result<(), String> {
+        query(
+            "INSERT INTO ORGANISATION_MEMBER (user_id, organisation_id) VALUES (?, ?)
+             ON DUPLICATE KEY UPDATE organisation_id = VALUES(organisation_id)"
+        )
+        .bind(user_id)
+        .bind(organisation_id)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn load_member_organisation(&self, user_id: &str) -> Result<Option<Organisation>, String> {
+        let row = query(&format!(
+            "SELECT {} FROM ORGANISATION o
+             JOIN ORGANISATION_MEMBER m ON m.organisation_id = o.id
+             WHERE m.user_id = ?",
+            ORGANISATION_COLUMNS
+        ))
+        .bind(user_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_organisation))
+    }
+
+    // 단체 좌석 설정 (회차 행 잠금 → 개인 예약과 동시에 좌석을 잡지 않도록)
+    async fn save_group_quota(&self, content_schedule_id: u64, quota_seats: i32) -> Result<GroupQuota, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let schedule = query(
+            "SELECT c.tot_seats AS total_seats, cs.adult_count, cs.child_count
+             FROM CONTENT_SCHEDULES cs
+             JOIN CONTENTS c ON c.id = cs.content_id
+             WHERE cs.id = ?
+             FOR UPDATE OF cs"
+        )
+        .bind(content_schedule_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or(format!("스케줄을 찾을 수 없습니다! ID: {}", content_schedule_id))?;
+
+        let total_seats: i32 = schedule.try_get::<Option<i32>, _>("total_seats").ok().flatten().unwrap_or(0);
+        let booked: i32 = schedule.get::<i32, _>("adult_count") + schedule.get::<i32, _>("child_count");
+        if booked + quota_seats > total_seats {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!(
+                "단체 좌석 설정 불가: 개인 예약 인원 포함 최대 좌석 수 초과 (최대 {}명, 개인 예약 {}명, 단체 좌석 {}석)",
+                total_seats, booked, quota_seats
+            ));
+        }
+
+        let held_seats: i32 = query("SELECT held_seats FROM SCHEDULE_GROUP_QUOTA WHERE content_schedule_id = ? FOR UPDATE")
+            .bind(content_schedule_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(|row| row.get("held_seats"))
+            .unwrap_or(0);
+        if quota_seats < held_seats {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("단체 좌석 설정 불가: 이미 단체 예약된 인원({}명)보다 적게 설정할 수 없습니다.", held_seats));
+        }
+
+        query(
+            "INSERT INTO SCHEDULE_GROUP_QUOTA (content_schedule_id, quota_seats) VALUES (?, ?)
+             ON DUPLICATE KEY UPDATE quota_seats = VALUES(quota_seats)"
+        )
+        .bind(content_schedule_id)
+        .bind(quota_seats)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(GroupQuota { content_schedule_id, quota_seats, held_seats })
+    }
+
+    async fn load_group_quota(&self, content_schedule_id: u64) -> Result<Option<GroupQuota>, String> {
+        let row = query("SELECT content_schedule_id, quota_seats, held_seats FROM SCHEDULE_GROUP_QUOTA WHERE content_schedule_id = ?")
+            .bind(content_schedule_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_group_quota))
+    }
+
+    // 단체 예약 신청 (회차별 단체 좌석을 승인 전까지 확보)
+    async fn save_group_reservation(&self, group: &GroupReservation) -> Result<i64, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // 회차 id 순서로 잠금 (동시 신청 시 교착 상태 방지)
+        let mut items: Vec<&GroupReservationItem> = group.items.iter().collect();
+        items.sort_by_key(|item| item.content_schedule_id);
+        for item in &items {
+            let quota = query("SELECT content_schedule_id, quota_seats, held_seats FROM SCHEDULE_GROUP_QUOTA WHERE content_schedule_id = ? FOR UPDATE")
+                .bind(item.content_schedule_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?
+                .map(|row| Self::map_group_quota(&row));
+            let Some(quota) = quota else {
+                tx.rollback().await.map_err(|e| e.to_string())?;
+                return Err(format!("단체 좌석이 없는 회차입니다! ID: {}", item.content_schedule_id));
+            };
+            if item.party_size() > quota.seats_remaining() {
+                tx.rollback().await.map_err(|e| e.to_string())?;
+                return Err(format!(
+                    "단체 좌석 부족 (회차 {}: 잔여 {}석, 요청 {}명)",
+                    item.content_schedule_id, quota.seats_remaining(), item.party_size()
+                ));
+            }
+
+            query("UPDATE SCHEDULE_GROUP_QUOTA SET held_seats = held_seats + ? WHERE content_schedule_id = ?")
+                .bind(item.party_size())
+                .bind(item.content_schedule_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let inserted = query("INSERT INTO GROUP_RESERVATION (organisation_id, requested_by, status, note) VALUES (?, ?, ?, ?)")
+            .bind(group.organisation_id)
+            .bind(&group.requested_by)
+            .bind(GroupReservationStatus::Requested.to_string())
+            .bind(group.note.as_deref())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        let group_reservation_id = inserted.last_insert_id() as i64;
+
+        for item in &items {
+            query("INSERT INTO GROUP_RESERVATION_ITEM (group_reservation_id, content_schedule_id, ad_cnt, cd_cnt) VALUES (?, ?, ?, ?)")
+                .bind(group_reservation_id)
+                .bind(item.content_schedule_id)
+                .bind(item.ad_cnt)
+                .bind(item.cd_cnt)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(group_reservation_id)
+    }
+
+    async fn load_group_reservation(&self, group_reservation_id: i64) -> Result<Option<GroupReservation>, String> {
+        let row = query(&format!("SELECT {} FROM GROUP_RESERVATION g WHERE g.id = ?", GROUP_RESERVATION_COLUMNS))
+            .bind(group_reservation_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut groups: Vec<GroupReservation> = row.as_ref().map(Self::map_group_reservation).into_iter().collect();
+        self.load_items(&mut groups).await?;
+        Ok(groups.pop())
+    }
+
+    async fn load_group_reservations(&self, status: Option<GroupReservationStatus>, organisation_id: Option<i64>, limit: i64) -> Result<Vec<GroupReservation>, String> {
+        let mut builder = QueryBuilder::<MySql>::new(format!("SELECT {} FROM GROUP_RESERVATION g WHERE 1 = 1", GROUP_RESERVATION_COLUMNS));
+        if let Some(status) = status {
+            builder.push(" AND g.status = ").push_bind(status.to_string());
+        }
+        if let Some(organisation_id) = organisation_id {
+            builder.push(" AND g.organisation_id = ").push_bind(organisation_id);
+        }
+        builder.push(" ORDER BY g.created_at, g.id LIMIT ").push_bind(limit);
+
+        let rows = builder.build().fetch_all(&*self.pool).await.map_err(|e| e.to_string())?;
+        let mut groups: Vec<GroupReservation> = rows.iter().map(Self::map_group_reservation).collect();
+        self.load_items(&mut groups).await?;
+        Ok(groups)
+    }
+
+    // 승인: 회차별 예약 생성 + 산정된 금액 저장 (단체 좌석은 신청 시 확보했으므로 회차 인원 카운터는 변경하지 않음)
+    async fn approve_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<&str>, prices: &HashMap<u64, ReservationPrice>, audit: &AuditContext) -> Result<Vec<i32>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let group = Self::lock_group_reservation(&mut tx, group_reservation_id).await?;
+        if group.status != GroupReservationStatus::Requested {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("승인 대기 중인 단체 예약만 승인할 수 있습니다. (현재 상태: {})", group.status));
+        }
+
+        let mut reservation_ids = Vec::with_capacity(group.items.len());
+        for item in &group.items {
+            let Some(price) = prices.get(&item.content_schedule_id) else {
+                tx.rollback().await.map_err(|e| e.to_string())?;
+                return Err(format!("금액이 산정되지 않은 회차입니다. (회차 ID: {})", item.content_schedule_id));
+            };
+            let inserted = query(
+                "INSERT INTO RESERVATION (user_id, content_schedule_id, reserved_at, ad_cnt, cd_cnt, status, use_at, group_reservation_id, list_price, total_price, discount_type, currency)
+                 VALUES (?, ?, NOW(), ?, ?, ?, 0, ?, ?, ?, ?, ?)"
+            )
+            .bind(&group.requested_by)
+            .bind(item.content_schedule_id)
+            .bind(item.ad_cnt)
+            .bind(item.cd_cnt)
+            .bind(ReservationStatus::Pending.to_string())
+            .bind(group_reservation_id)
+            .bind(price.list_price)
+            .bind(price.total_price)
+            .bind(price.discount_type.map(|discount_type| discount_type.to_string()))
+            .bind(&price.currency)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+            let reservation_id = inserted.last_insert_id() as i32;
+            ReservationRepositoryImpl::save_party(&mut tx, reservation_id, &PartyComposition::from_legacy(item.ad_cnt, item.cd_cnt)).await?;
+
+            query("UPDATE GROUP_RESERVATION_ITEM SET reservation_id = ? WHERE group_reservation_id = ? AND content_schedule_id = ?")
+                .bind(reservation_id)
+                .bind(group_reservation_id)
+                .bind(item.content_schedule_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let after = ReservationRepositoryImpl::load_snapshot(&mut tx, reservation_id).await?;
+            ReservationRepositoryImpl::record_change(&mut tx, reservation_id, HistoryAction::Create, audit, None, after.as_ref()).await?;
+            reservation_ids.push(reservation_id);
+        }
+
+        Self::update_group_status(&mut tx, group_reservation_id, GroupReservationStatus::Approved, Some(reviewer_id), review_note).await?;
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(reservation_ids)
+    }
+
+    async fn reject_group_reservation(&self, group_reservation_id: i64, reviewer_id: &str, review_note: Option<&str>) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let group = Self::lock_group_reservation(&mut tx, group_reservation_id).await?;
+        if group.status != GroupReservationStatus::Requested {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("승인 대기 중인 단체 예약만 반려할 수 있습니다. (현재 상태: {})", group.status));
+        }
+
+        Self::release_seats(&mut tx, &group).await?;
+        Self::update_group_status(&mut tx, group_reservation_id, GroupReservationStatus::Rejected, Some(reviewer_id), review_note).await?;
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 취소: 승인된 경우 생성된 예약도 함께 취소하고 단체 좌석 반환
+    async fn cancel_group_reservation(&self, group_reservation_id: i64, audit: &AuditContext) -> Result<Vec<i32>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let group = Self::lock_group_reservation(&mut tx, group_reservation_id).await?;
+        if !group.status.holds_seats() {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("이미 종료된 단체 예약입니다. (현재 상태: {})", group.status));
+        }
+
+        let mut cancelled_ids = Vec::new();
+        for reservation_id in group.items.iter().filter_map(|item| item.reservation_id) {
+            let Some(before) = ReservationRepositoryImpl::load_snapshot(&mut tx, reservation_id).await? else {
+                continue;
+            };
+            if matches!(before.status, Some(ReservationStatus::Cancelled)) {
+                continue;
+            }
+            if before.checked_in_ad_cnt > 0 || before.checked_in_cd_cnt > 0 || !matches!(before.status, Some(ReservationStatus::Pending) | None) {
+                tx.rollback().await.map_err(|e| e.to_string())?;
+                return Err(format!("입장이 시작되었거나 처리된 예약이 포함되어 있어 취소할 수 없습니다. (예약 ID: {})", reservation_id));
+            }
+
+            query("UPDATE RESERVATION SET status = ? WHERE id = ?")
+                .bind(ReservationStatus::Cancelled.to_string())
+                .bind(reservation_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let after = ReservationRepositoryImpl::load_snapshot(&mut tx, reservation_id).await?;
+            ReservationRepositoryImpl::record_change(&mut tx, reservation_id, HistoryAction::Cancel, audit, Some(&before), after.as_ref()).await?;
+            cancelled_ids.push(reservation_id);
+        }
+
+        Self::release_seats(&mut tx, &group).await?;
+        Self::update_group_status(&mut tx, group_reservation_id, GroupReservationStatus::Cancelled, None, None).await?;
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(cancelled_ids)
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use sqlx::{query, MySqlPool, Row};
+use std::sync::Arc;
+use crate::{domain::idempotency::{IdempotencyClaim, IdempotentResponse}, infra::db::idempotency_repository::IdempotencyRepository};
+
+// Repository Implementation
+pub struct IdempotencyRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl IdempotencyRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IdempotencyRepository for IdempotencyRepositoryImpl {
+    // 멱등성 키 선점 (UNIQUE 제약으로 동시 재시도 중 하나만 선점 성공)
+    async fn claim_key(&self, user_id: &str, key: &str, fingerprint: &str, ttl_secs: i64, lease_secs: i64) -> Result<IdempotencyClaim, String> {
+        // TTL이 지난 키와 점유 시간이 지난 처리 중 키(처리 도중 중단된 요청)는 삭제 후 새 요청으로 취급
+        query(
+            "DELETE FROM IDEMPOTENCY_KEY
+             WHERE user_id = ? AND idem_key = ?
+               AND (expires_at < NOW() OR (status = 'IN_PROGRESS' AND claimed_at < DATE_SUB(NOW(), INTERVAL ? SECOND)))"
+        )
+        .bind(user_id)
+        .bind(key)
+        .bind(lease_secs)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let inserted = query(
+            "INSERT INTO IDEMPOTENCY_KEY (user_id, idem_key, request_fingerprint, status, claimed_at, expires_at)
+             VALUES (?, ?, ?, 'IN_PROGRESS', NOW(), DATE_ADD(NOW(), INTERVAL ? SECOND))"
+        )
+        .bind(user_id)
+        .bind(key)
+        .bind(fingerprint)
+        .bind(ttl_secs)
+        .execute(&*self.pool)
+        .await;
+
+        match inserted {
+            Ok(_) => return Ok(IdempotencyClaim::Acquired),
+            Err(sqlx::Error::Database(err)) if err.is_unique_violation() => {
+                println!("멱등성 키 중복 요청 감지: user_id={}, key={}", user_id, key);
+            }
+            Err(err) => return Err(err.to_string()),
+        }
+
+        let row = query(
+            "SELECT request_fingerprint, status, response_code, response_body
+             FROM IDEMPOTENCY_KEY
+             WHERE user_id = ? AND idem_key = ?"
+        )
+        .bind(user_id)
+        .bind(key)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // 조회 직전에 키가 해제된 경우 → 처리 중으로 간주 (클라이언트 재시도)
+        let Some(row) = row else {
+            return Ok(IdempotencyClaim::InProgress);
+        };
+
+        let stored_fingerprint: String = row.get("request_fingerprint");
+        if stored_fingerprint != fingerprint {
+            return Ok(IdempotencyClaim::Mismatch);
+        }
+
+        let status: String = row.get("status");
+        if status != "COMPLETED" {
+            return Ok(IdempotencyClaim::InProgress);
+        }
+
+        Ok(IdempotencyClaim::Replay(IdempotentResponse {
+            status_code: row.try_get::<Option<u16>, _>("response_code").ok().flatten().unwrap_or(200),
+            message: row.try_get::<Option<String>, _>("response_body").ok().flatten().unwrap_or_default(),
+        }))
+    }
+
+    async fn complete_key(&self, user_id: &str, key: &str, response: &IdempotentResponse) -> Result<(), String> {
+        query(
+            "UPDATE IDEMPOTENCY_KEY
+             SET status = 'COMPLETED', response_code = ?, response_body = ?
+             WHERE user_id = ? AND idem_key = ?"
+        )
+        .bind(response.status_code)
+        .bind(&response.message)
+        .bind(user_id)
+        .bind(key)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 처리 실패 시 키 해제 → 클라이언트가 같은 키로 재시도 가능
+    async fn release_key(&self, user_id: &str, key: &str) -> Result<(), String> {
+        query("DELETE FROM IDEMPOTENCY_KEY WHERE user_id = ? AND idem_key = ? AND status = 'IN_PROGRESS'")
+            .bind(user_id)
+            .bind(key)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -1,5 +1,41 @@
 pub mod reservation_repository_impl; 
 pub mod reservation_repository;
+pub mod idempotency_repository_impl;
+pub mod idempotency_repository;
+pub mod content_rule_repository_impl;
+pub mod content_rule_repository;
+pub mod outbox_repository_impl;
+pub mod outbox_repository;
+pub mod webhook_repository_impl;
+pub mod webhook_repository;
+pub mod reminder_repository_impl;
+pub mod reminder_repository;
+pub mod group_booking_repository_impl;
+pub mod group_booking_repository;
+pub mod transfer_repository_impl;
+pub mod transfer_repository;
+pub mod pricing_repository_impl;
+pub mod pricing_repository;
+pub mod payment_repository_impl;
+pub mod payment_repository;
 
 pub use reservation_repository::ReservationRepository;
-pub use reservation_repository_impl::ReservationRepositoryImpl; 
\ No newline at end of file
+pub use reservation_repository_impl::ReservationRepositoryImpl; 
+pub use idempotency_repository::IdempotencyRepository;
+pub use idempotency_repository_impl::IdempotencyRepositoryImpl;
+pub use content_rule_repository::ContentRuleRepository;
+pub use content_rule_repository_impl::ContentRuleRepositoryImpl;
+pub use outbox_repository::OutboxRepository;
+pub use outbox_repository_impl::OutboxRepositoryImpl;
+pub use webhook_repository::WebhookRepository;
+pub use webhook_repository_impl::WebhookRepositoryImpl;
+pub use reminder_repository::ReminderRepository;
+pub use reminder_repository_impl::ReminderRepositoryImpl;
+pub use group_booking_repository::GroupBookingRepository;
+pub use group_booking_repository_impl::GroupBookingRepositoryImpl;
+pub use transfer_repository::TransferRepository;
+pub use transfer_repository_impl::TransferRepositoryImpl;
+pub use pricing_repository::PricingRepository;
+pub use pricing_repository_impl::PricingRepositoryImpl;
+pub use payment_repository::PaymentRepository;
+pub use payment_repository_impl::PaymentRepositoryImpl;
\ No newline at end of file
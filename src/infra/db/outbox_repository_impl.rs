@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{query, MySql, MySqlPool, QueryBuilder, Row};
+use std::sync::Arc;
+use crate::{domain::reservation_event::OutboxEvent, infra::db::outbox_repository::OutboxRepository};
+
+const MAX_ERROR_LEN: usize = 500;
+
+// Repository Implementation
+pub struct OutboxRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl OutboxRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxRepositoryImpl {
+    // 전달 대상 이벤트 선점 (다른 인스턴스가 잠근 행은 건너뛰고, lease_until 까지 재조회되지 않도록 미룸)
+    // 예약별 순서 보장: 같은 예약의 앞선 이벤트가 전달되기 전(재시도 대기 / 다른 인스턴스 처리 중)에는 뒤 이벤트를 가져가지 않음
+    async fn claim_pending(&self, now: DateTime<Utc>, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxEvent>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let rows = query(
+            "SELECT o.id, o.event_type, o.reservation_id, CAST(o.payload AS CHAR) AS payload, o.created_at, o.attempts
+             FROM RESERVATION_OUTBOX o
+             WHERE o.published_at IS NULL
+               AND o.dead_at IS NULL
+               AND o.next_attempt_at <= ?
+               AND NOT EXISTS (
+                   SELECT 1 FROM RESERVATION_OUTBOX prev
+                   WHERE prev.reservation_id = o.reservation_id
+                     AND prev.id < o.id
+                     AND prev.published_at IS NULL
+                     AND prev.dead_at IS NULL
+               )
+             ORDER BY o.id
+             LIMIT ?
+             FOR UPDATE SKIP LOCKED"
+        )
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if rows.is_empty() {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Ok(Vec::new());
+        }
+
+        let mut events = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let event_id: i64 = row.get("id");
+            let payload: String = row.get("payload");
+            // 읽을 수 없는 payload 는 재시도해도 같으므로 전달 포기 처리 후 나머지 이벤트는 계속 전달
+            let payload = match serde_json::from_str(&payload) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    eprintln!("이벤트 payload 파싱 실패 → 전달 포기 (event_id={}): {}", event_id, e);
+                    let error: String = format!("invalid payload: {}", e).chars().take(MAX_ERROR_LEN).collect();
+                    query("UPDATE RESERVATION_OUTBOX SET dead_at = ?, last_error = ? WHERE id = ?")
+                        .bind(now)
+                        .bind(error)
+                        .bind(event_id)
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    continue;
+                }
+            };
+            events.push(OutboxEvent {
+                event_id,
+                event_type: row.get("event_type"),
+                reservation_id: row.get("reservation_id"),
+                payload,
+                occurred_at: row.get("created_at"),
+                attempts: row.get("attempts"),
+            });
+        }
+
+        if events.is_empty() {
+            tx.commit().await.map_err(|e| e.to_string())?;
+            return Ok(events);
+        }
+
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new("UPDATE RESERVATION_OUTBOX SET next_attempt_at = ");
+        builder.push_bind(lease_until);
+        builder.push(" WHERE id IN (");
+        let mut ids = builder.separated(", ");
+        for event in &events {
+            ids.push_bind(event.event_id);
+        }
+        ids.push_unseparated(")");
+        builder.build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(events)
+    }
+
+    async fn mark_published(&self, event_id: i64) -> Result<(), String> {
+        query(
+            "UPDATE RESERVATION_OUTBOX
+             SET published_at = CURRENT_TIMESTAMP(6), attempts = attempts + 1, last_error = NULL
+             WHERE id = ?"
+        )
+        .bind(event_id)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 전달 실패 → 시도 횟수 증가 후 next_attempt_at 이후 재시도
+    async fn mark_failed(&self, event_id: i64, error: &str, next_attempt_at: DateTime<Utc>) -> Result<(), String> {
+        let error: String = error.chars().take(MAX_ERROR_LEN).collect();
+        query(
+            "UPDATE RESERVATION_OUTBOX
+             SET attempts = attempts + 1, last_error = ?, next_attempt_at = ?
+             WHERE id = ?"
+        )
+        .bind(error)
+        .bind(next_attempt_at)
+        .bind(event_id)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, query, MySqlPool, Row};
+use std::sync::Arc;
+use crate::{domain::{payment::{Payment, PaymentConfirmation, PaymentStatus}, reservation::ReservationStatus, reservation_history::{AuditContext, HistoryAction}}, infra::db::{payment_repository::PaymentRepository, reservation_repository_impl::ReservationRepositoryImpl}};
+
+const PAYMENT_COLUMNS: &str = "id, reservation_id, user_id, provider, provider_payment_id, checkout_url, amount, currency, status, refund_amount, failure_reason, created_at, paid_at, refunded_at";
+
+// Repository Implementation
+pub struct PaymentRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl PaymentRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+
+    fn map_payment(row: &MySqlRow) -> Payment {
+        Payment {
+            id: row.get("id"),
+            reservation_id: row.get("reservation_id"),
+            user_id: row.get("user_id"),
+            provider: row.get("provider"),
+            provider_payment_id: row.try_get("provider_payment_id").ok().flatten(),
+            checkout_url: row.try_get("checkout_url").ok().flatten(),
+            amount: row.get("amount"),
+            currency: row.get("currency"),
+            status: row.get::<String, _>("status").parse().unwrap_or(PaymentStatus::Pending),
+            refund_amount: row.try_get("refund_amount").ok().flatten(),
+            failure_reason: row.try_get("failure_reason").ok().flatten(),
+            created_at: row.try_get("created_at").ok(),
+            paid_at: row.try_get("paid_at").ok().flatten(),
+            refunded_at: row.try_get("refunded_at").ok().flatten(),
+        }
+    }
+}
+
+#[async_trait]
+impl PaymentRepository for PaymentRepositoryImpl {
+    // 결제 대기 예약에만, 진행 중 / 완료된 결제가 없을 때 생성 (예약 행 잠금으로 동시 요청 직렬화)
+    async fn save_payment(&self, payment: &Payment) -> Result<i64, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let before = ReservationRepositoryImpl::load_snapshot(&mut tx, payment.reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", payment.reservation_id))?;
+        if !matches!(before.status, Some(ReservationStatus::PendingPayment)) {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err("결제 대기 중인 예약이 아닙니다.".to_string());
+        }
+
+        let active = query("SELECT id FROM PAYMENT WHERE reservation_id = ? AND status IN ('PENDING', 'PAID')")
+            .bind(payment.reservation_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Some(active) = active {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("이미 진행 중인 결제가 있습니다. (결제 ID: {})", active.get::<i64, _>("id")));
+        }
+
+        let inserted = query(
+            "INSERT INTO PAYMENT (reservation_id, user_id, provider, amount, currency, status)
+             VALUES (?, ?, ?, ?, ?, 'PENDING')"
+        )
+        .bind(payment.reservation_id)
+        .bind(&payment.user_id)
+        .bind(&payment.provider)
+        .bind(payment.amount)
+        .bind(&payment.currency)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(inserted.last_insert_id() as i64)
+    }
+
+    async fn attach_checkout(&self, payment_id: i64, provider_payment_id: &str, checkout_url: &str) -> Result<(), String> {
+        query("UPDATE PAYMENT SET provider_payment_id = ?, checkout_url = ? WHERE id = ?")
+            .bind(provider_payment_id)
+            .bind(checkout_url)
+            .bind(payment_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn load_payment_by_provider_id(&self, provider: &str, provider_payment_id: &str) -> Result<Option<Payment>, String> {
+        let row = query(&format!("SELECT {} FROM PAYMENT WHERE provider = ? AND provider_payment_id = ?", PAYMENT_COLUMNS))
+            .bind(provider)
+            .bind(provider_payment_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_payment))
+    }
+
+    async fn load_latest_payment(&self, reservation_id: i32) -> Result<Option<Payment>, String> {
+        let row = query(&format!("SELECT {} FROM PAYMENT WHERE reservation_id = ? ORDER BY id DESC LIMIT 1", PAYMENT_COLUMNS))
+            .bind(reservation_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_payment))
+    }
+
+    // 결제 완료: 예약 / 결제 행을 잠그고 예약이 아직 결제 대기 중이면 예약 완료로 변경
+    // 결제 기한이 지나 예약이 이미 해제된 뒤 도착한 결제는 전액 환불 대상으로 기록
+    async fn confirm_payment(&self, payment_id: i64, now: DateTime<Utc>, audit: &AuditContext) -> Result<PaymentConfirmation, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // 결제 기한 만료 처리와 같은 잠금 순서 (예약 → 결제)
+        let reservation_id: i32 = query("SELECT reservation_id FROM PAYMENT WHERE id = ?")
+            .bind(payment_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(|row| row.get("reservation_id"))
+            .ok_or(format!("결제를 찾을 수 없습니다! ID: {}", payment_id))?;
+        let before = ReservationRepositoryImpl::load_snapshot(&mut tx, reservation_id).await?;
+
+        let payment = query(&format!("SELECT {} FROM PAYMENT WHERE id = ? FOR UPDATE", PAYMENT_COLUMNS))
+            .bind(payment_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(|row| Self::map_payment(&row))
+            .ok_or(format!("결제를 찾을 수 없습니다! ID: {}", payment_id))?;
+        if !matches!(payment.status, PaymentStatus::Pending | PaymentStatus::Failed | PaymentStatus::Expired) {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Ok(PaymentConfirmation::AlreadyProcessed);
+        }
+
+        let Some(before) = before.filter(|before| matches!(before.status, Some(ReservationStatus::PendingPayment))) else {
+            query("UPDATE PAYMENT SET status = 'REFUND_PENDING', refund_amount = amount, paid_at = ? WHERE id = ?")
+                .bind(now)
+                .bind(payment_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+            tx.commit().await.map_err(|e| e.to_string())?;
+            return Ok(PaymentConfirmation::Late);
+        };
+
+        query("UPDATE PAYMENT SET status = 'PAID', failure_reason = NULL, paid_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(payment_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        query("UPDATE RESERVATION SET status = ?, payment_due_at = NULL WHERE id = ?")
+            .bind(ReservationStatus::Pending.to_string())
+            .bind(payment.reservation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let after = ReservationRepositoryImpl::load_snapshot(&mut tx, payment.reservation_id).await?;
+        ReservationRepositoryImpl::record_change(&mut tx, payment.reservation_id, HistoryAction::Payment, audit, Some(&before), after.as_ref()).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(PaymentConfirmation::Confirmed)
+    }
+
+    // 결제 대기 예약 취소 + 회차 좌석 반환 + 진행 중인 결제 종료 (이미 결제 / 취소된 예약이면 false)
+    async fn release_unpaid_reservation(&self, reservation_id: i32, payment_status: PaymentStatus, failure_reason: Option<&str>, audit: &AuditContext) -> Result<bool, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let before = ReservationRepositoryImpl::load_snapshot(&mut tx, reservation_id).await?;
+        let Some(before) = before.filter(|before| matches!(before.status, Some(ReservationStatus::PendingPayment))) else {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Ok(false);
+        };
+
+        // 회차 행 잠금 후 좌석 반환 (인원 변경과 같은 잠금 순서: 예약 → 회차)
+        query("SELECT id FROM CONTENT_SCHEDULES WHERE id = ? FOR UPDATE")
+            .bind(before.content_schedule_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        query(
+            "UPDATE CONTENT_SCHEDULES
+             SET adult_count = adult_count - ?,
+                 child_count = child_count - ?
+             WHERE id = ?"
+        )
+        .bind(before.ad_cnt)
+        .bind(before.cd_cnt)
+        .bind(before.content_schedule_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        query("UPDATE RESERVATION SET status = ?, payment_due_at = NULL WHERE id = ?")
+            .bind(ReservationStatus::Cancelled.to_string())
+            .bind(reservation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        query("UPDATE PAYMENT SET status = ?, failure_reason = COALESCE(?, failure_reason) WHERE reservation_id = ? AND status = 'PENDING'")
+            .bind(payment_status.to_string())
+            .bind(failure_reason)
+            .bind(reservation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let after = ReservationRepositoryImpl::load_snapshot(&mut tx, reservation_id).await?;
+        ReservationRepositoryImpl::record_change(&mut tx, reservation_id, HistoryAction::Cancel, audit, Some(&before), after.as_ref()).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(true)
+    }
+
+    async fn load_overdue_reservation_ids(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, String> {
+        let rows = query(
+            "SELECT id FROM RESERVATION
+             WHERE status = 'PENDING_PAYMENT' AND payment_due_at <= ?
+             ORDER BY payment_due_at
+             LIMIT ?"
+        )
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
+    // 결제 완료 건만 환불 요청 상태로 변경 (중복 취소 요청은 false)
+    async fn request_refund(&self, payment_id: i64, amount: i64) -> Result<bool, String> {
+        let result = query("UPDATE PAYMENT SET status = 'REFUND_PENDING', refund_amount = ? WHERE id = ? AND status = 'PAID'")
+            .bind(amount)
+            .bind(payment_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn complete_refund(&self, payment_id: i64, now: DateTime<Utc>) -> Result<(), String> {
+        query("UPDATE PAYMENT SET status = 'REFUNDED', refunded_at = ? WHERE id = ? AND status = 'REFUND_PENDING'")
+            .bind(now)
+            .bind(payment_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn load_refund_pending(&self, limit: i64) -> Result<Vec<Payment>, String> {
+        let rows = query(&format!("SELECT {} FROM PAYMENT WHERE status = 'REFUND_PENDING' ORDER BY id LIMIT ?", PAYMENT_COLUMNS))
+            .bind(limit)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(Self::map_payment).collect())
+    }
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, query, MySqlPool, Row};
+use std::sync::Arc;
+use std::str::FromStr;
+use crate::{domain::{pricing::{ContentPrice, DiscountType, PricingDiscount}, ticket_category::TicketCategory}, infra::db::pricing_repository::PricingRepository};
+
+const DISCOUNT_COLUMNS: &str = "id, content_id, discount_type, rate_percent, min_party_size, start_hour, end_hour, active, created_at";
+
+// Repository Implementation
+pub struct PricingRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl PricingRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+
+    fn map_discount(row: &MySqlRow) -> PricingDiscount {
+        PricingDiscount {
+            id: row.get("id"),
+            content_id: row.try_get("content_id").ok().flatten(),
+            discount_type: row.get::<String, _>("discount_type").parse().unwrap_or(DiscountType::Member),
+            rate_percent: row.get("rate_percent"),
+            min_party_size: row.try_get("min_party_size").ok().flatten(),
+            start_hour: row.try_get::<Option<i32>, _>("start_hour").ok().flatten().map(|hour| hour as u32),
+            end_hour: row.try_get::<Option<i32>, _>("end_hour").ok().flatten().map(|hour| hour as u32),
+            active: row.get::<i8, _>("active") != 0, // `TINYINT(1)` → `bool` 변환
+            created_at: row.try_get("created_at").ok(),
+        }
+    }
+}
+
+#[async_trait]
+impl PricingRepository for PricingRepositoryImpl {
+    async fn load_content_prices(&self, content_id: u64) -> Result<Vec<ContentPrice>, String> {
+        let rows = query("SELECT category, unit_price FROM CONTENT_PRICE WHERE content_id = ?")
+            .bind(content_id)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                let category = TicketCategory::from_str(&row.get::<String, _>("category")).ok()?;
+                Some(ContentPrice { category, unit_price: row.get("unit_price") })
+            })
+            .collect())
+    }
+
+    // 컨텐츠 단가 저장 (요청 내용으로 교체)
+    async fn save_content_prices(&self, content_id: u64, prices: &[ContentPrice]) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        query("DELETE FROM CONTENT_PRICE WHERE content_id = ?")
+            .bind(content_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        for price in prices {
+            query("INSERT INTO CONTENT_PRICE (content_id, category, unit_price) VALUES (?, ?, ?)")
+                .bind(content_id)
+                .bind(price.category.to_string())
+                .bind(price.unit_price)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 컨텐츠 전용 + 전체 컨텐츠 대상 활성 할인
+    async fn load_applicable_discounts(&self, content_id: u64) -> Result<Vec<PricingDiscount>, String> {
+        let rows = query(&format!(
+            "SELECT {} FROM PRICING_DISCOUNT
+             WHERE active = 1 AND (content_id = ? OR content_id IS NULL)
+             ORDER BY id",
+            DISCOUNT_COLUMNS
+        ))
+        .bind(content_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(Self::map_discount).collect())
+    }
+
+    async fn load_discounts(&self) -> Result<Vec<PricingDiscount>, String> {
+        let rows = query(&format!("SELECT {} FROM PRICING_DISCOUNT ORDER BY id", DISCOUNT_COLUMNS))
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(Self::map_discount).collect())
+    }
+
+    async fn save_discount(&self, discount: &PricingDiscount) -> Result<i64, String> {
+        let inserted = query(
+            "INSERT INTO PRICING_DISCOUNT (content_id, discount_type, rate_percent, min_party_size, start_hour, end_hour, active)
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(discount.content_id)
+        .bind(discount.discount_type.to_string())
+        .bind(discount.rate_percent)
+        .bind(discount.min_party_size)
+        .bind(discount.start_hour.map(|hour| hour as i32))
+        .bind(discount.end_hour.map(|hour| hour as i32))
+        .bind(discount.active as i8)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(inserted.last_insert_id() as i64)
+    }
+
+    // 이미 저장된 예약 금액에 영향을 주지 않도록 삭제 대신 비활성화
+    async fn deactivate_discount(&self, discount_id: i64) -> Result<bool, String> {
+        let result = query("UPDATE PRICING_DISCOUNT SET active = 0 WHERE id = ? AND active = 1")
+            .bind(discount_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn is_member(&self, user_id: &str, now: DateTime<Utc>) -> Result<bool, String> {
+        let row = query(
+            "SELECT EXISTS(
+                SELECT 1 FROM MEMBERSHIP
+                WHERE user_id = ? AND (expires_at IS NULL OR expires_at > ?)
+             ) AS is_member"
+        )
+        .bind(user_id)
+        .bind(now)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(row.get::<i64, _>("is_member") != 0)
+    }
+
+    // 회원 등록 (이미 회원이면 만료일만 갱신)
+    async fn save_membership(&self, user_id: &str, expires_at: Option<DateTime<Utc>>) -> Result<(), String> {
+        query(
+            "INSERT INTO MEMBERSHIP (user_id, expires_at) VALUES (?, ?)
+             ON DUPLICATE KEY UPDATE expires_at = VALUES(expires_at)"
+        )
+        .bind(user_id)
+        .bind(expires_at)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_membership(&self, user_id: &str) -> Result<bool, String> {
+        let result = query("DELETE FROM MEMBERSHIP WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+}
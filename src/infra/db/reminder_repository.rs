@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::reminder::ReminderTarget;
+
+#[async_trait]
+pub trait ReminderRepository: Send + Sync {
+    async fn load_due_reminders(&self, now: DateTime<Utc>, minutes_before: i64, stale_before: DateTime<Utc>) -> Result<Vec<ReminderTarget>, String>;
+    async fn claim_reminders(&self, reservation_ids: &[i32], minutes_before: i64, stale_before: DateTime<Utc>) -> Result<Vec<i32>, String>;
+    async fn complete_reminders(&self, reservation_ids: &[i32], minutes_before: i64) -> Result<(), String>;
+    async fn release_reminders(&self, reservation_ids: &[i32], minutes_before: i64) -> Result<(), String>;
+}
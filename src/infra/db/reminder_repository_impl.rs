@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{query, MySql, MySqlPool, QueryBuilder, Row};
+use std::sync::Arc;
+use crate::{domain::reminder::{ReminderRecipient, ReminderTarget}, infra::db::reminder_repository::ReminderRepository};
+
+// Repository Implementation
+pub struct ReminderRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl ReminderRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+
+    fn push_reservation_ids(builder: &mut QueryBuilder<'_, MySql>, reservation_ids: &[i32]) {
+        builder.push(" AND reservation_id IN (");
+        let mut ids = builder.separated(", ");
+        for reservation_id in reservation_ids {
+            ids.push_bind(*reservation_id);
+        }
+        ids.push_unseparated(")");
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for ReminderRepositoryImpl {
+    // 시작 시간이 (now, now + N분] 인 회차의 유효한 예약 중 아직 알림을 받지 않은 예약 (회차 알림 이후 예약한 사용자 포함)
+    // 발송 중 중단된 예약(claimed_at 이 stale_before 이전)은 다시 대상에 포함
+    async fn load_due_reminders(&self, now: DateTime<Utc>, minutes_before: i64, stale_before: DateTime<Utc>) -> Result<Vec<ReminderTarget>, String> {
+        let rows = query(
+            "SELECT cs.id AS content_schedule_id, cs.content_id, c.title AS content_title, cs.start_time, r.id AS reservation_id, r.user_id
+             FROM CONTENT_SCHEDULES cs
+             JOIN CONTENTS c ON c.id = cs.content_id
+             JOIN RESERVATION r ON r.content_schedule_id = cs.id
+             LEFT JOIN RESERVATION_REMINDER rr ON rr.reservation_id = r.id AND rr.minutes_before = ?
+             WHERE cs.start_time > ?
+               AND cs.start_time <= ?
+               AND (rr.reservation_id IS NULL OR (rr.status = 'SENDING' AND rr.claimed_at < ?))
+               AND (r.status IS NULL OR r.status = 'PENDING')
+               AND r.use_at = 0
+             ORDER BY cs.id, r.id"
+        )
+        .bind(minutes_before)
+        .bind(now)
+        .bind(now + Duration::minutes(minutes_before))
+        .bind(stale_before)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // 회차별로 예약 묶기 (회차 id 순 정렬)
+        let mut targets: Vec<ReminderTarget> = Vec::new();
+        for row in &rows {
+            let content_schedule_id: u64 = row.get("content_schedule_id");
+            let recipient = ReminderRecipient { reservation_id: row.get("reservation_id"), user_id: row.get("user_id") };
+            match targets.last_mut() {
+                Some(target) if target.content_schedule_id == content_schedule_id => target.recipients.push(recipient),
+                _ => targets.push(ReminderTarget {
+                    content_schedule_id,
+                    content_id: row.get("content_id"),
+                    content_title: row.try_get("content_title").ok().flatten(),
+                    start_time: row.get("start_time"),
+                    recipients: vec![recipient],
+                }),
+            }
+        }
+        Ok(targets)
+    }
+
+    // 예약별 발송 선점 (여러 인스턴스 중 하나만 성공, 중단된 발송은 stale_before 이후 다시 선점 가능) → 선점한 예약 id 반환
+    async fn claim_reminders(&self, reservation_ids: &[i32], minutes_before: i64, stale_before: DateTime<Utc>) -> Result<Vec<i32>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let mut claimed = Vec::with_capacity(reservation_ids.len());
+        for reservation_id in reservation_ids {
+            query(
+                "DELETE FROM RESERVATION_REMINDER
+                 WHERE reservation_id = ? AND minutes_before = ? AND status = 'SENDING' AND claimed_at < ?"
+            )
+            .bind(reservation_id)
+            .bind(minutes_before)
+            .bind(stale_before)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+            let inserted = query("INSERT IGNORE INTO RESERVATION_REMINDER (reservation_id, minutes_before) VALUES (?, ?)")
+                .bind(reservation_id)
+                .bind(minutes_before)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+            if inserted.rows_affected() == 1 {
+                claimed.push(*reservation_id);
+            }
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(claimed)
+    }
+
+    async fn complete_reminders(&self, reservation_ids: &[i32], minutes_before: i64) -> Result<(), String> {
+        if reservation_ids.is_empty() {
+            return Ok(());
+        }
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new(
+            "UPDATE RESERVATION_REMINDER SET status = 'SENT', sent_at = CURRENT_TIMESTAMP(6) WHERE minutes_before = "
+        );
+        builder.push_bind(minutes_before);
+        Self::push_reservation_ids(&mut builder, reservation_ids);
+        builder.build()
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 발송 실패 → 선점 해제 (다음 주기에 재시도)
+    async fn release_reminders(&self, reservation_ids: &[i32], minutes_before: i64) -> Result<(), String> {
+        if reservation_ids.is_empty() {
+            return Ok(());
+        }
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new(
+            "DELETE FROM RESERVATION_REMINDER WHERE status = 'SENDING' AND minutes_before = "
+        );
+        builder.push_bind(minutes_before);
+        Self::push_reservation_ids(&mut builder, reservation_ids);
+        builder.build()
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -1,19 +1,27 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
-use crate::{domain::reservation::{Reservation, ReservationStatus}, dto::reservation_chk_dto::ReservationLimits};
+use crate::{common::page::{Page, PageRequest}, domain::{reservation::{Reservation, ReservationStatus}, reservation_history::{AuditContext, ReservationHistory}, schedule_availability::ScheduleAvailability, pricing::ReservationPrice, ticket_category::PartyComposition}, dto::{reservation_chk_dto::ReservationLimits, reservation_query_dto::ReservationFilter, schedule_filter_dto::ScheduleAvailabilityFilter}};
 
 #[async_trait]
 pub trait ReservationRepository: Send + Sync {
-    async fn load_reservation(&self, reservation_id: i32) -> Option<Reservation>;
-    async fn laod_reservations_by_date(&self ,start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Result<Vec<Reservation>,String>;
-    async fn load_reservations_by_user(&self, user_id: &str) -> Result<Vec<Reservation>, String>; 
-    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64) -> Result<Vec<Reservation>,String>;
-    async fn save_reservation(&self, reservation: Reservation) -> Result<(), String>;
-    async fn update_status(&self, reservation_id: i32, status: ReservationStatus) -> Result<(), String>;
-    async fn update_reservaiton_user_count(&self, reservation_id: i32, ad_cnt:i32, cd_cnt:i32) -> Result<(), String>;
-    async fn delete_reservation(&self, reservation_id: i32) -> Result<(), String>;
+    async fn load_reservation(&self, reservation_id: i32) -> Result<Option<Reservation>, String>;
+    async fn laod_reservations_by_date(&self ,start_time: DateTime<Utc>, end_time: DateTime<Utc>, page: PageRequest) -> Result<Page<Reservation>,String>;
+    async fn load_reservations_by_user(&self, user_id: &str, page: PageRequest) -> Result<Page<Reservation>, String>;
+    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64, page: PageRequest) -> Result<Page<Reservation>,String>;
+    async fn save_reservation(&self, reservation: Reservation, audit: &AuditContext) -> Result<i32, String>;
+    async fn update_status(&self, reservation_id: i32, status: ReservationStatus, audit: &AuditContext) -> Result<(), String>;
+    async fn update_reservaiton_user_count(&self, reservation_id: i32, party: &PartyComposition, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>;
+    async fn check_in_party(&self, reservation_id: i32, content_schedule_id: u64, ad_cnt: i32, cd_cnt: i32, arriving_ad_cnt: i32, arriving_cd_cnt: i32, audit: &AuditContext) -> Result<bool, String>;
+    async fn mark_no_shows(&self, cutoff: DateTime<Utc>, audit: &AuditContext) -> Result<u64, String>;
+    async fn count_user_no_shows(&self, user_id: &str, since: DateTime<Utc>) -> Result<i64, String>;
+    async fn move_reservation(&self, reservation_id: i32, new_schedule_id: u64, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>;
+    async fn delete_reservation(&self, reservation_id: i32, audit: &AuditContext) -> Result<(), String>;
+    async fn load_reservation_history(&self, reservation_id: i32) -> Result<Vec<ReservationHistory>, String>;
     async fn check_reservation_for_user_count(&self, user_id: &str, schedule_id: u64) -> Result<ReservationLimits, String>;
     async fn check_schedule_and_reservation(&self, user_id: &str, schedule_id: u64) -> Result<bool,String>;
     async fn check_user_reservation_for_content(&self, user_id: &str, schedule_id: u64) -> Result<bool, String>;
+    async fn load_schedule_availability(&self, schedule_id: u64) -> Result<Option<ScheduleAvailability>, String>;
+    async fn load_schedules_availability(&self, filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String>;
+    async fn search_reservations(&self, filter: ReservationFilter, page: PageRequest) -> Result<Page<Reservation>, String>;
 }
\ No newline at end of file
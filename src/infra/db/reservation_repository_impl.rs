@@ -1,9 +1,39 @@
 use chrono::{DateTime, Utc};
-use sqlx::{query, MySqlPool,Row};
+use sqlx::{mysql::MySqlRow, query, MySql, MySqlPool, QueryBuilder, Row, Transaction};
 use async_trait::async_trait;
 use std::sync::Arc;
 use std::str::FromStr; 
-use crate::{domain::reservation::{Reservation, ReservationStatus}, dto::reservation_chk_dto::ReservationLimits,infra::db::reservation_repository::ReservationRepository};
+use crate::{common::page::{Page, PageCursor, PageRequest, NULL_SORT_VALUE}, domain::{reservation::{Reservation, ReservationSchedule, ReservationStatus}, reservation_event::ReservationEventType, reservation_history::{AuditChannel, AuditContext, HistoryAction, ReservationHistory, ReservationSnapshot}, schedule_availability::ScheduleAvailability, pricing::{DiscountType, ReservationPrice}, ticket_category::{PartyComposition, TicketCategory}}, dto::{reservation_chk_dto::ReservationLimits, reservation_query_dto::{ReservationFilter, ReservationSort}, schedule_filter_dto::ScheduleAvailabilityFilter},infra::db::reservation_repository::ReservationRepository};
+
+// 예약 조회 공통 SELECT (회차 시작 시간 / 컨텐츠 정보 JOIN)
+const RESERVATION_SELECT: &str = "
+    SELECT 
+        r.id, r.user_id, r.content_schedule_id, r.reserved_at, r.status, r.ad_cnt, r.cd_cnt, r.use_at,
+        r.checked_in_ad_cnt, r.checked_in_cd_cnt, r.group_reservation_id,
+        r.list_price, r.total_price, r.discount_type, r.currency, r.payment_due_at,
+        (SELECT GROUP_CONCAT(CONCAT(p.category, ':', p.cnt)) FROM RESERVATION_PARTY p WHERE p.reservation_id = r.id) AS party,
+        (SELECT MAX(h.id) FROM RESERVATION_HISTORY h WHERE h.reservation_id = r.id) AS version,
+        cs.start_time, cs.content_id, c.title AS content_title
+    FROM RESERVATION r
+    LEFT JOIN CONTENT_SCHEDULES cs ON r.content_schedule_id = cs.id
+    LEFT JOIN CONTENTS c ON c.id = cs.content_id
+";
+
+// 아웃박스 이벤트 payload (변경 후 예약 상태, {status} 자리에 상태 컬럼 / 값)
+const OUTBOX_PAYLOAD: &str = "JSON_OBJECT(
+    'reservation_id', r.id,
+    'user_id', r.user_id,
+    'content_schedule_id', r.content_schedule_id,
+    'ad_cnt', r.ad_cnt,
+    'cd_cnt', r.cd_cnt,
+    'party', (SELECT JSON_OBJECTAGG(p.category, p.cnt) FROM RESERVATION_PARTY p WHERE p.reservation_id = r.id),
+    'status', {status},
+    'checked_in_ad_cnt', r.checked_in_ad_cnt,
+    'checked_in_cd_cnt', r.checked_in_cd_cnt,
+    'total_price', r.total_price,
+    'currency', r.currency,
+    'reserved_at', r.reserved_at
+)";
 
 // Repository Implementation
 pub struct ReservationRepositoryImpl {
@@ -14,165 +44,403 @@ impl ReservationRepositoryImpl {
     pub fn new(pool: Arc<MySqlPool>) -> Self {
         Self { pool }
     }
-}
 
-#[async_trait]
-impl ReservationRepository for ReservationRepositoryImpl {
-    async fn load_reservation(&self, reservation_id: i32) -> Option<Reservation> {
-        if let Some(row) = sqlx::query!(
-            "SELECT id, user_id, content_schedule_id, reserved_at, ad_cnt, cd_cnt, status, use_at FROM RESERVATION WHERE id = ?",
-            reservation_id
+    // RESERVATION (+ 회차 / 컨텐츠) 조회 결과 → Reservation 변환
+    fn map_reservation(row: &MySqlRow) -> Reservation {
+        let status: Option<String> = row.try_get("status").ok();
+        let status = status.and_then(|s| ReservationStatus::from_str(&s).ok());
+        let schedule = row.try_get::<u64, _>("content_id").ok().map(|content_id| ReservationSchedule {
+            start_time: row.try_get("start_time").ok(),
+            content_id,
+            content_title: row.try_get::<Option<String>, _>("content_title").ok().flatten(),
+        });
+
+        let ad_cnt = row.try_get::<Option<i32>, _>("ad_cnt").ok().flatten().unwrap_or(0);
+        let cd_cnt = row.try_get::<Option<i32>, _>("cd_cnt").ok().flatten().unwrap_or(0);
+        let party = row.try_get::<Option<String>, _>("party").ok().flatten();
+
+        Reservation {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            content_schedule_id: row.get("content_schedule_id"),
+            reserved_at: row.try_get("reserved_at").ok(),
+            status,
+            ad_cnt,
+            cd_cnt,
+            use_at: row.get::<i8, _>("use_at") != 0, // `TINYINT(1)` → `bool` 변환
+            checked_in_ad_cnt: row.try_get("checked_in_ad_cnt").unwrap_or(0),
+            checked_in_cd_cnt: row.try_get("checked_in_cd_cnt").unwrap_or(0),
+            group_reservation_id: row.try_get("group_reservation_id").ok().flatten(),
+            payment_due_at: row.try_get("payment_due_at").ok().flatten(),
+            party: Self::parse_party(party.as_deref(), ad_cnt, cd_cnt),
+            price: Self::map_price(row),
+            schedule,
+            flags: None,
+            ticket_token: None,
+            version: row.try_get("version").ok().flatten(),
+        }
+    }
+
+    // 예약 시점 금액 (가격 산정 이전 예약은 None)
+    fn map_price(row: &MySqlRow) -> Option<ReservationPrice> {
+        let list_price: Option<i64> = row.try_get("list_price").ok().flatten();
+        let total_price: Option<i64> = row.try_get("total_price").ok().flatten();
+        match (list_price, total_price) {
+            (Some(list_price), Some(total_price)) => Some(ReservationPrice {
+                list_price,
+                total_price,
+                discount_type: row.try_get::<Option<String>, _>("discount_type").ok().flatten().and_then(|s| DiscountType::from_str(&s).ok()),
+                currency: row.try_get::<Option<String>, _>("currency").ok().flatten().unwrap_or_default(),
+            }),
+            _ => None,
+        }
+    }
+
+    // "ADULT:2,INFANT:1" → 권종 구성 (권종 행이 없는 예약은 ad_cnt / cd_cnt 를 성인 / 어린이로 간주)
+    fn parse_party(party: Option<&str>, ad_cnt: i32, cd_cnt: i32) -> PartyComposition {
+        party
+            .and_then(|party| PartyComposition::from_str(party).ok())
+            .filter(|party| party.total() > 0)
+            .unwrap_or_else(|| PartyComposition::from_legacy(ad_cnt, cd_cnt))
+    }
+
+    // 예약의 권종별 인원 조회 (예약 행을 잠근 트랜잭션 안에서 호출)
+    async fn load_party(tx: &mut Transaction<'_, MySql>, reservation_id: i32, ad_cnt: i32, cd_cnt: i32) -> Result<PartyComposition, String> {
+        let row = query(
+            "SELECT GROUP_CONCAT(CONCAT(category, ':', cnt)) AS party
+             FROM RESERVATION_PARTY
+             WHERE reservation_id = ?"
         )
-        .fetch_optional(&*self.pool)
+        .bind(reservation_id)
+        .fetch_one(&mut **tx)
         .await
-        .ok()
-        .flatten() {
-            Some(Reservation {
-                id: row.id,
-                user_id: row.user_id,
-                content_schedule_id: row.content_schedule_id,
-                reserved_at: row.reserved_at,
-                ad_cnt: row.ad_cnt.unwrap_or(0),
-                cd_cnt: row.cd_cnt.unwrap_or(0), 
-                status: row.status.and_then(|s| ReservationStatus::from_str(&s).ok()),
-                use_at: row.use_at != 0,
-            })
-        } else {
-            None
+        .map_err(|e| e.to_string())?;
+        let party = row.try_get::<Option<String>, _>("party").ok().flatten();
+        Ok(Self::parse_party(party.as_deref(), ad_cnt, cd_cnt))
+    }
+
+    // 예약의 권종별 인원 저장 (기존 행은 교체)
+    pub(crate) async fn save_party(tx: &mut Transaction<'_, MySql>, reservation_id: i32, party: &PartyComposition) -> Result<(), String> {
+        query("DELETE FROM RESERVATION_PARTY WHERE reservation_id = ?")
+            .bind(reservation_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        for (category, count) in party.iter() {
+            query("INSERT INTO RESERVATION_PARTY (reservation_id, category, cnt) VALUES (?, ?, ?)")
+                .bind(reservation_id)
+                .bind(category.to_string())
+                .bind(count)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| e.to_string())?;
         }
+        Ok(())
     }
-    async fn laod_reservations_by_date(&self, start_time: DateTime<Utc>, end_time:DateTime<Utc>) -> Result<Vec<Reservation>,String>
-    {
+
+    // 회차별 권종 최대 인원 확인 (CONTENT_CATEGORY_RULE.max_per_schedule)
+    // 회차 행을 잠근 뒤 호출하므로 같은 회차의 동시 예약과 겹치지 않음, 수정 / 이동 중인 예약은 집계에서 제외
+    async fn check_category_capacity(tx: &mut Transaction<'_, MySql>, schedule_id: u64, party: &PartyComposition, exclude_reservation_id: Option<i32>) -> Result<(), String> {
         let rows = query(
-            "
-             SELECT 
-                id, user_id, content_schedule_id, reserved_at, status, ad_cnt, cd_cnt, use_at  
-             FROM RESERVATION
-             where reserved_at BETWEEN ? AND ?  
-            "
+            "SELECT ccr.category, ccr.max_per_schedule,
+                    COALESCE((
+                        SELECT CAST(SUM(p.cnt) AS SIGNED)
+                        FROM RESERVATION_PARTY p
+                        JOIN RESERVATION r ON r.id = p.reservation_id
+                        WHERE r.content_schedule_id = cs.id
+                          AND p.category = ccr.category
+                          AND r.id <> ?
+                          AND (r.status IS NULL OR r.status NOT IN ('CANCELLED', 'NO_SHOW'))
+                    ), 0) AS booked
+             FROM CONTENT_SCHEDULES cs
+             JOIN CONTENT_CATEGORY_RULE ccr ON ccr.content_id = cs.content_id
+             WHERE cs.id = ?
+               AND ccr.max_per_schedule IS NOT NULL"
         )
-        .bind(start_time)
-        .bind(end_time)
-        .fetch_all(&*self.pool)
+        .bind(exclude_reservation_id.unwrap_or(0))
+        .bind(schedule_id)
+        .fetch_all(&mut **tx)
         .await
-        .map_err(|err| err.to_string())?;
+        .map_err(|e| e.to_string())?;
+
+        let mut reasons = Vec::new();
+        for row in rows {
+            let Ok(category) = TicketCategory::from_str(&row.get::<String, _>("category")) else {
+                continue;
+            };
+            let requested = party.count(category);
+            let max_per_schedule: i32 = row.get("max_per_schedule");
+            let booked = row.get::<i64, _>("booked") as i32;
+            if requested > 0 && booked + requested > max_per_schedule {
+                reasons.push(format!(
+                    "{} 좌석 초과 (최대 {}명, 현재 예약 {}명, 요청한 예약 {}명)",
+                    category.label(), max_per_schedule, booked, requested
+                ));
+            }
+        }
 
-        let reservations = rows.into_iter().map(|row| {
-            let status: Option<String> = row.try_get("status").ok();
-            let status = status.and_then(|s| match s.as_str() {
-                "PENDING" => Some(ReservationStatus::Pending),
-                "CONFIRMED" => Some(ReservationStatus::Confirmed),
-                "CANCELLED" => Some(ReservationStatus::Cancelled),
-                _ => None,
-            });
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("예약 불가: {}", reasons.join(", ")))
+        }
+    }
 
-            Reservation {
-                id: row.get("id"),
-                user_id: row.get("user_id"),
-                content_schedule_id: row.get("content_schedule_id"),
-                reserved_at: row.try_get("reserved_at").ok(),
-                status,
-                ad_cnt: row.get("ad_cnt"),
-                cd_cnt: row.get("cd_cnt"),
-                use_at: row.get::<i8, _>("use_at") != 0, // `TINYINT(1)` → `bool` 변환
+    // 키셋 페이지네이션: 커서 이후 조건 + 안정 정렬(정렬 값, id) + LIMIT (다음 페이지 확인용 1건 추가)
+    fn push_page(builder: &mut QueryBuilder<'_, MySql>, sort: ReservationSort, descending: bool, page: &PageRequest) {
+        let (direction, op) = if descending { ("DESC", "<") } else { ("ASC", ">") };
+
+        if let Some(cursor) = &page.cursor {
+            match sort {
+                ReservationSort::Id => {
+                    builder.push(format!(" AND r.id {} ", op)).push_bind(cursor.id);
+                }
+                _ => {
+                    let sort_value = cursor.sort_value.unwrap_or(NULL_SORT_VALUE);
+                    builder.push(format!(" AND ({} {} ", sort.column(), op)).push_bind(sort_value)
+                        .push(format!(" OR ({} = ", sort.column())).push_bind(sort_value)
+                        .push(format!(" AND r.id {} ", op)).push_bind(cursor.id)
+                        .push("))");
+                }
             }
-        }).collect();
+        }
+
+        match sort {
+            ReservationSort::Id => builder.push(format!(" ORDER BY r.id {}", direction)),
+            _ => builder.push(format!(" ORDER BY {} {}, r.id {}", sort.column(), direction, direction)),
+        };
 
-        Ok(reservations)
+        if let Some(limit) = page.limit {
+            builder.push(" LIMIT ").push_bind(limit + 1);
+        }
     }
 
-    async fn load_reservations_by_user(&self, user_id: &str) -> Result<Vec<Reservation>, String> 
-    {
-        let rows = query(
-            "
-            SELECT 
-                id, user_id, content_schedule_id, reserved_at, status, ad_cnt, cd_cnt, use_at 
-            FROM RESERVATION
-            WHERE user_id = ?
-            "
+    // 조회 결과 → Page 변환 (limit 초과분이 있으면 마지막 항목 기준으로 next_cursor 생성)
+    fn into_page(rows: Vec<MySqlRow>, sort: ReservationSort, page: &PageRequest) -> Page<Reservation> {
+        let has_more = page.limit.is_some_and(|limit| rows.len() > limit as usize);
+        let rows = match page.limit {
+            Some(limit) if has_more => &rows[..limit as usize],
+            _ => &rows[..],
+        };
+
+        let next_cursor = if has_more {
+            rows.last().map(|row| PageCursor {
+                sort_value: match sort {
+                    ReservationSort::Id => None,
+                    ReservationSort::ReservedAt => Some(row.try_get("reserved_at").unwrap_or(NULL_SORT_VALUE)),
+                    ReservationSort::StartTime => Some(row.try_get("start_time").unwrap_or(NULL_SORT_VALUE)),
+                },
+                id: row.get("id"),
+            }.encode())
+        } else {
+            None
+        };
+
+        Page {
+            items: rows.iter().map(Self::map_reservation).collect(),
+            next_cursor,
+        }
+    }
+
+    // 예약 행 잠금 + 현재 상태 조회 (이력의 변경 전 / 후 값)
+    pub(crate) async fn load_snapshot(tx: &mut Transaction<'_, MySql>, reservation_id: i32) -> Result<Option<ReservationSnapshot>, String> {
+        let row = query(
+            "SELECT user_id, content_schedule_id, ad_cnt, cd_cnt, status, checked_in_ad_cnt, checked_in_cd_cnt
+             FROM RESERVATION
+             WHERE id = ?
+             FOR UPDATE"
         )
-        .bind(user_id)
-        .fetch_all(&*self.pool)
+        .bind(reservation_id)
+        .fetch_optional(&mut **tx)
         .await
-        .map_err(|err| err.to_string())?;
+        .map_err(|e| e.to_string())?;
 
-        let reservations = rows.into_iter().map(|row| {
-            let status: Option<String> = row.try_get("status").ok();
-            let status = status.and_then(|s| match s.as_str() {
-                "PENDING" => Some(ReservationStatus::Pending),
-                "CONFIRMED" => Some(ReservationStatus::Confirmed),
-                "CANCELLED" => Some(ReservationStatus::Cancelled),
-                _ => None,
-            });
+        Ok(row.map(|row| ReservationSnapshot {
+            user_id: row.try_get("user_id").ok(),
+            content_schedule_id: row.get("content_schedule_id"),
+            ad_cnt: row.try_get::<Option<i32>, _>("ad_cnt").ok().flatten().unwrap_or(0),
+            cd_cnt: row.try_get::<Option<i32>, _>("cd_cnt").ok().flatten().unwrap_or(0),
+            status: row.try_get::<Option<String>, _>("status").ok().flatten().and_then(|s| ReservationStatus::from_str(&s).ok()),
+            checked_in_ad_cnt: row.try_get("checked_in_ad_cnt").unwrap_or(0),
+            checked_in_cd_cnt: row.try_get("checked_in_cd_cnt").unwrap_or(0),
+        }))
+    }
 
-            Reservation {
-                id: row.get("id"),
-                user_id: row.get("user_id"),
-                content_schedule_id: row.get("content_schedule_id"),
-                reserved_at: row.try_get("reserved_at").ok(),
-                status,
-                ad_cnt: row.get("ad_cnt"),
-                cd_cnt: row.get("cd_cnt"),
-                use_at: row.get::<i8, _>("use_at") != 0, // `TINYINT(1)` → `bool` 변환
-            }
-        }).collect();
+    // 예약 변경 이력 기록 (변경과 같은 트랜잭션에서 호출)
+    async fn insert_history(
+        tx: &mut Transaction<'_, MySql>,
+        reservation_id: i32,
+        action: HistoryAction,
+        audit: &AuditContext,
+        before: Option<&ReservationSnapshot>,
+        after: Option<&ReservationSnapshot>,
+    ) -> Result<(), String> {
+        query(
+            "INSERT INTO RESERVATION_HISTORY (
+                reservation_id, action, actor_id, channel,
+                before_user_id, before_schedule_id, before_ad_cnt, before_cd_cnt, before_status, before_checked_in_ad_cnt, before_checked_in_cd_cnt,
+                after_user_id, after_schedule_id, after_ad_cnt, after_cd_cnt, after_status, after_checked_in_ad_cnt, after_checked_in_cd_cnt
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(reservation_id)
+        .bind(action.to_string())
+        .bind(audit.actor_id.as_deref())
+        .bind(audit.channel.to_string())
+        .bind(before.and_then(|s| s.user_id.as_deref()))
+        .bind(before.map(|s| s.content_schedule_id))
+        .bind(before.map(|s| s.ad_cnt))
+        .bind(before.map(|s| s.cd_cnt))
+        .bind(before.and_then(|s| s.status.as_ref()).map(|status| status.to_string()))
+        .bind(before.map(|s| s.checked_in_ad_cnt))
+        .bind(before.map(|s| s.checked_in_cd_cnt))
+        .bind(after.and_then(|s| s.user_id.as_deref()))
+        .bind(after.map(|s| s.content_schedule_id))
+        .bind(after.map(|s| s.ad_cnt))
+        .bind(after.map(|s| s.cd_cnt))
+        .bind(after.and_then(|s| s.status.as_ref()).map(|status| status.to_string()))
+        .bind(after.map(|s| s.checked_in_ad_cnt))
+        .bind(after.map(|s| s.checked_in_cd_cnt))
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
 
-        Ok(reservations)
+    // 예약 이벤트를 아웃박스에 기록 (변경과 같은 트랜잭션에서 호출, 삭제 시에는 삭제 전에 호출)
+    async fn insert_outbox(tx: &mut Transaction<'_, MySql>, reservation_id: i32, event_type: ReservationEventType) -> Result<(), String> {
+        let sql = format!(
+            "INSERT INTO RESERVATION_OUTBOX (event_type, reservation_id, payload)
+             SELECT ?, r.id, {}
+             FROM RESERVATION r
+             WHERE r.id = ?",
+            OUTBOX_PAYLOAD.replace("{status}", "r.status")
+        );
+        query(&sql)
+            .bind(event_type.to_string())
+            .bind(reservation_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
     }
-    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64)-> Result<Vec<Reservation>, String> {
-        let rows = query(
-            "
-            SELECT 
-                id, user_id, content_schedule_id, reserved_at, status, ad_cnt, cd_cnt, use_at 
-            FROM RESERVATION
-            WHERE content_schedule_id = ?
-            "
-        ).bind(content_schedule_id)
-        .fetch_all(&*self.pool)
-        .await
-        .map_err(|err| err.to_string())?;
 
-        let reservations = rows.into_iter().map(|row| {
-            let status: Option<String> = row.try_get("status").ok();
-            let status = status.and_then(|s| match s.as_str() {
-                "PENDING" => Some(ReservationStatus::Pending),
-                "CONFIRMED" => Some(ReservationStatus::Confirmed),
-                "CANCELLED" => Some(ReservationStatus::Cancelled),
-                _ => None,
-            });
+    // 이력 + 아웃박스 이벤트 기록
+    pub(crate) async fn record_change(
+        tx: &mut Transaction<'_, MySql>,
+        reservation_id: i32,
+        action: HistoryAction,
+        audit: &AuditContext,
+        before: Option<&ReservationSnapshot>,
+        after: Option<&ReservationSnapshot>,
+    ) -> Result<(), String> {
+        Self::insert_history(tx, reservation_id, action, audit, before, after).await?;
+        Self::insert_outbox(tx, reservation_id, ReservationEventType::from_change(action, after)).await
+    }
 
-            Reservation {
-                id: row.get("id"),
-                user_id: row.get("user_id"),
-                content_schedule_id: row.get("content_schedule_id"),
-                reserved_at: row.try_get("reserved_at").ok(),
-                status,
-                ad_cnt: row.get("ad_cnt"),
-                cd_cnt: row.get("cd_cnt"),
-                use_at: row.get::<i8, _>("use_at") != 0, // `TINYINT(1)` → `bool` 변환
-            }
-        }).collect();
+    // 이력 테이블의 변경 전 / 후 컬럼 → ReservationSnapshot 변환 (값이 없으면 None)
+    fn map_snapshot(row: &MySqlRow, prefix: &str) -> Option<ReservationSnapshot> {
+        let content_schedule_id: Option<u64> = row.try_get(format!("{}_schedule_id", prefix).as_str()).ok().flatten();
+        content_schedule_id.map(|content_schedule_id| ReservationSnapshot {
+            user_id: row.try_get::<Option<String>, _>(format!("{}_user_id", prefix).as_str()).ok().flatten(),
+            content_schedule_id,
+            ad_cnt: row.try_get::<Option<i32>, _>(format!("{}_ad_cnt", prefix).as_str()).ok().flatten().unwrap_or(0),
+            cd_cnt: row.try_get::<Option<i32>, _>(format!("{}_cd_cnt", prefix).as_str()).ok().flatten().unwrap_or(0),
+            status: row.try_get::<Option<String>, _>(format!("{}_status", prefix).as_str()).ok().flatten().and_then(|s| ReservationStatus::from_str(&s).ok()),
+            checked_in_ad_cnt: row.try_get::<Option<i32>, _>(format!("{}_checked_in_ad_cnt", prefix).as_str()).ok().flatten().unwrap_or(0),
+            checked_in_cd_cnt: row.try_get::<Option<i32>, _>(format!("{}_checked_in_cd_cnt", prefix).as_str()).ok().flatten().unwrap_or(0),
+        })
+    }
 
-        Ok(reservations)
+    // CONTENT_SCHEDULES + CONTENTS 조회 결과 → ScheduleAvailability 변환 (단체 전용 좌석 제외)
+    fn map_schedule_availability(row: &MySqlRow) -> ScheduleAvailability {
+        let group_seats: i32 = row.try_get("group_seats").unwrap_or(0);
+        ScheduleAvailability::new(
+            row.get("content_schedule_id"),
+            row.get("content_id"),
+            row.try_get("start_time").ok(),
+            row.try_get::<Option<i32>, _>("total_seats").ok().flatten().unwrap_or(0) - group_seats,
+            row.get("adult_count"),
+            row.get("child_count"),
+        )
     }
+}
 
-    async fn save_reservation(&self, reservation: Reservation) -> Result<(), String> {
+#[async_trait]
+impl ReservationRepository for ReservationRepositoryImpl {
+    async fn load_reservation(&self, reservation_id: i32) -> Result<Option<Reservation>, String> {
+        let row = query(&format!("{} WHERE r.id = ?", RESERVATION_SELECT))
+            .bind(reservation_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(row.map(|row| Self::map_reservation(&row)))
+    }
+    async fn laod_reservations_by_date(&self, start_time: DateTime<Utc>, end_time:DateTime<Utc>, page: PageRequest) -> Result<Page<Reservation>,String>
+    {
+        let mut builder = QueryBuilder::<MySql>::new(RESERVATION_SELECT);
+        builder.push(" WHERE r.reserved_at >= ").push_bind(start_time).push(" AND r.reserved_at < ").push_bind(end_time);
+        Self::push_page(&mut builder, ReservationSort::Id, false, &page);
+
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(Self::into_page(rows, ReservationSort::Id, &page))
+    }
+
+    async fn load_reservations_by_user(&self, user_id: &str, page: PageRequest) -> Result<Page<Reservation>, String> 
+    {
+        let mut builder = QueryBuilder::<MySql>::new(RESERVATION_SELECT);
+        builder.push(" WHERE r.user_id = ").push_bind(user_id);
+        Self::push_page(&mut builder, ReservationSort::Id, false, &page);
+
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(Self::into_page(rows, ReservationSort::Id, &page))
+    }
+    async fn load_reservations_by_content_schedule(&self, content_schedule_id:u64, page: PageRequest)-> Result<Page<Reservation>, String> {
+        let mut builder = QueryBuilder::<MySql>::new(RESERVATION_SELECT);
+        builder.push(" WHERE r.content_schedule_id = ").push_bind(content_schedule_id);
+        Self::push_page(&mut builder, ReservationSort::Id, false, &page);
+
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(Self::into_page(rows, ReservationSort::Id, &page))
+    }
+
+    async fn save_reservation(&self, reservation: Reservation, audit: &AuditContext) -> Result<i32, String> {
         let status_str = reservation.status.map(|s| s.to_string());
         let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
     
-        // 현재 예약 인원 조회
+        // 현재 예약 인원 조회 (스케줄 행 잠금 → 동시 예약 시 초과 예약 방지)
         let schedule_data = sqlx::query!(
-            "SELECT c.tot_seats AS total_seats, cs.adult_count, cs.child_count
+            r#"SELECT c.tot_seats AS total_seats, cs.adult_count, cs.child_count,
+                    COALESCE(q.quota_seats, 0) AS "group_seats!: i32"
              FROM CONTENT_SCHEDULES cs
              JOIN CONTENTS c ON c.id = cs.content_id
-             WHERE cs.id = ?",
+             LEFT JOIN SCHEDULE_GROUP_QUOTA q ON q.content_schedule_id = cs.id
+             WHERE cs.id = ?
+             FOR UPDATE OF cs"#,
             reservation.content_schedule_id
         )
         .fetch_one(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
     
-        let total_seats: i32 = schedule_data.total_seats.unwrap_or(0);
+        // 단체 전용 좌석을 제외한 좌석만 개인 예약 가능
+        let total_seats: i32 = schedule_data.total_seats.unwrap_or(0) - schedule_data.group_seats;
         let current_adults: i32 = schedule_data.adult_count;
         let current_children: i32 = schedule_data.child_count;
     
@@ -192,17 +460,29 @@ impl ReservationRepository for ReservationRepositoryImpl {
                 total_seats, current_adults + current_children, new_total
             ));
         }
+
+        // 권종별 회차 최대 인원 확인
+        if let Err(e) = Self::check_category_capacity(&mut tx, reservation.content_schedule_id, &reservation.party, None).await {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(e);
+        }
     
-        // `INSERT` 실행 (최대 좌석을 초과하지 않을 경우)
-        sqlx::query!(
-            "INSERT INTO RESERVATION (user_id, content_schedule_id, reserved_at, ad_cnt, cd_cnt, status, use_at) 
-             VALUES (?, ?, NOW(), ?, ?, ?, ?)",
+        // `INSERT` 실행 (최대 좌석을 초과하지 않을 경우, 예약 시점 금액 함께 저장)
+        let price = reservation.price.as_ref();
+        let inserted = sqlx::query!(
+            "INSERT INTO RESERVATION (user_id, content_schedule_id, reserved_at, ad_cnt, cd_cnt, status, use_at, list_price, total_price, discount_type, currency, payment_due_at) 
+             VALUES (?, ?, NOW(), ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             reservation.user_id,
             reservation.content_schedule_id,
             reservation.ad_cnt,
             reservation.cd_cnt,
             status_str.as_deref(),
-            reservation.use_at as i8
+            reservation.use_at as i8,
+            price.map(|price| price.list_price),
+            price.map(|price| price.total_price),
+            price.and_then(|price| price.discount_type).map(|discount_type| discount_type.to_string()),
+            price.map(|price| price.currency.as_str()),
+            reservation.payment_due_at
         )
         .execute(&mut *tx)
         .await
@@ -220,54 +500,196 @@ impl ReservationRepository for ReservationRepositoryImpl {
         .execute(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
+
+        let reservation_id = inserted.last_insert_id() as i32;
+        Self::save_party(&mut tx, reservation_id, &reservation.party).await?;
+        let after = Self::load_snapshot(&mut tx, reservation_id).await?;
+        Self::record_change(&mut tx, reservation_id, HistoryAction::Create, audit, None, after.as_ref()).await?;
     
         tx.commit().await.map_err(|e| e.to_string())?;
-        Ok(())
+        Ok(reservation_id)
     }
 
-    async fn update_status(&self, reservation_id: i32, status: ReservationStatus) -> Result<(), String> {
+    async fn update_status(&self, reservation_id: i32, status: ReservationStatus, audit: &AuditContext) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let before = Self::load_snapshot(&mut tx, reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id))?;
+
         let status_str = status.to_string(); 
         sqlx::query!(
             "UPDATE RESERVATION SET status = ? WHERE id = ?",
             status_str,
             reservation_id
         )
-        .execute(&*self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
+
+        let after = Self::load_snapshot(&mut tx, reservation_id).await?;
+        Self::record_change(&mut tx, reservation_id, HistoryAction::from_status(&status), audit, Some(&before), after.as_ref()).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
         Ok(())
     }
-    // 인원 수 수정
-    async fn update_reservaiton_user_count(&self, reservation_id: i32, ad_cnt:i32, cd_cnt:i32) -> Result<(), String>{
-        
+
+    // 입장 인원 추가 (결제 완료 / 미사용 / 미취소 / 티켓 발급 당시 회차·인원과 동일 / 남은 인원 이내인 경우에만 성공)
+    // MySQL UPDATE 는 SET 을 왼쪽부터 적용하므로 use_at, status 는 증가된 입장 인원 기준으로 계산됨
+    async fn check_in_party(&self, reservation_id: i32, content_schedule_id: u64, ad_cnt: i32, cd_cnt: i32, arriving_ad_cnt: i32, arriving_cd_cnt: i32, audit: &AuditContext) -> Result<bool, String> {
         let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
-        let reservation_data = sqlx::query!(
-            "SELECT content_schedule_id, ad_cnt, cd_cnt 
-             FROM RESERVATION 
-             WHERE id = ?",
-            reservation_id
+        let Some(before) = Self::load_snapshot(&mut tx, reservation_id).await? else {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Ok(false);
+        };
+
+        let result = sqlx::query!(
+            "UPDATE RESERVATION
+             SET checked_in_ad_cnt = checked_in_ad_cnt + ?,
+                 checked_in_cd_cnt = checked_in_cd_cnt + ?,
+                 use_at = (checked_in_ad_cnt >= ad_cnt AND checked_in_cd_cnt >= cd_cnt),
+                 status = IF(use_at = 1, 'CONFIRMED', status)
+             WHERE id = ?
+               AND content_schedule_id = ?
+               AND ad_cnt = ?
+               AND cd_cnt = ?
+               AND checked_in_ad_cnt + ? <= ad_cnt
+               AND checked_in_cd_cnt + ? <= cd_cnt
+               AND use_at = 0
+               AND (status IS NULL OR status NOT IN ('PENDING_PAYMENT', 'CANCELLED', 'CONFIRMED', 'NO_SHOW'))",
+            arriving_ad_cnt,
+            arriving_cd_cnt,
+            reservation_id,
+            content_schedule_id,
+            ad_cnt,
+            cd_cnt,
+            arriving_ad_cnt,
+            arriving_cd_cnt
         )
-        .fetch_one(&mut *tx)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() != 1 {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Ok(false);
+        }
+
+        let after = Self::load_snapshot(&mut tx, reservation_id).await?;
+        Self::record_change(&mut tx, reservation_id, HistoryAction::CheckIn, audit, Some(&before), after.as_ref()).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(true)
+    }
+    // 시작 시간이 cutoff 이전인 회차에서 한 명도 입장하지 않은 예약 → NO_SHOW 기록 후 상태 변경
+    async fn mark_no_shows(&self, cutoff: DateTime<Utc>, audit: &AuditContext) -> Result<u64, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // 기록은 예약당 1건만 (이미 기록된 예약은 무시), INSERT ... SELECT 가 대상 예약 행을 잠가 동시 입장 처리와 겹치지 않음
+        sqlx::query!(
+            "INSERT IGNORE INTO NO_SHOW_RECORD (reservation_id, user_id, content_schedule_id, ad_cnt, cd_cnt, schedule_start_time)
+             SELECT r.id, r.user_id, r.content_schedule_id, COALESCE(r.ad_cnt, 0), COALESCE(r.cd_cnt, 0), cs.start_time
+             FROM RESERVATION r
+             JOIN CONTENT_SCHEDULES cs ON r.content_schedule_id = cs.id
+             WHERE cs.start_time < ?
+               AND r.use_at = 0
+               AND r.checked_in_ad_cnt = 0
+               AND r.checked_in_cd_cnt = 0
+               AND (r.status IS NULL OR r.status = 'PENDING')",
+            cutoff
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // 상태 변경 대상과 같은 조건으로 이력 기록
+        sqlx::query!(
+            "INSERT INTO RESERVATION_HISTORY (
+                reservation_id, action, actor_id, channel,
+                before_user_id, before_schedule_id, before_ad_cnt, before_cd_cnt, before_status, before_checked_in_ad_cnt, before_checked_in_cd_cnt,
+                after_user_id, after_schedule_id, after_ad_cnt, after_cd_cnt, after_status, after_checked_in_ad_cnt, after_checked_in_cd_cnt
+             )
+             SELECT r.id, 'NO_SHOW', ?, ?,
+                    r.user_id, r.content_schedule_id, r.ad_cnt, r.cd_cnt, r.status, r.checked_in_ad_cnt, r.checked_in_cd_cnt,
+                    r.user_id, r.content_schedule_id, r.ad_cnt, r.cd_cnt, 'NO_SHOW', r.checked_in_ad_cnt, r.checked_in_cd_cnt
+             FROM RESERVATION r
+             JOIN NO_SHOW_RECORD n ON n.reservation_id = r.id
+             WHERE (r.status IS NULL OR r.status = 'PENDING')
+               AND r.use_at = 0
+               AND r.checked_in_ad_cnt = 0
+               AND r.checked_in_cd_cnt = 0",
+            audit.actor_id.as_deref(),
+            audit.channel.to_string()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // 같은 조건으로 아웃박스 이벤트 기록
+        let outbox_sql = format!(
+            "INSERT INTO RESERVATION_OUTBOX (event_type, reservation_id, payload)
+             SELECT ?, r.id, {}
+             FROM RESERVATION r
+             JOIN NO_SHOW_RECORD n ON n.reservation_id = r.id
+             WHERE (r.status IS NULL OR r.status = 'PENDING')
+               AND r.use_at = 0
+               AND r.checked_in_ad_cnt = 0
+               AND r.checked_in_cd_cnt = 0",
+            OUTBOX_PAYLOAD.replace("{status}", "'NO_SHOW'")
+        );
+        query(&outbox_sql)
+            .bind(ReservationEventType::from_change(HistoryAction::NoShow, None).to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let result = sqlx::query!(
+            "UPDATE RESERVATION r
+             JOIN NO_SHOW_RECORD n ON n.reservation_id = r.id
+             SET r.status = 'NO_SHOW'
+             WHERE (r.status IS NULL OR r.status = 'PENDING')
+               AND r.use_at = 0
+               AND r.checked_in_ad_cnt = 0
+               AND r.checked_in_cd_cnt = 0"
+        )
+        .execute(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(result.rows_affected())
+    }
+
+    // 인원 수 수정
+    async fn update_reservaiton_user_count(&self, reservation_id: i32, party: &PartyComposition, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String>{
+        let ad_cnt = party.adults();
+        let cd_cnt = party.children();
         
-        let schedule_id: u64 = reservation_data.content_schedule_id;
-        let current_reservation_adults: i32 = reservation_data.ad_cnt.unwrap_or(0); //  NULL 값 처리
-        let current_reservation_children: i32 = reservation_data.cd_cnt.unwrap_or(0);
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        // 예약 행 잠금 (동일 예약에 대한 동시 수정 방지)
+        let before = Self::load_snapshot(&mut tx, reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id))?;
+        
+        let schedule_id: u64 = before.content_schedule_id;
+        let current_reservation_adults: i32 = before.ad_cnt;
+        let current_reservation_children: i32 = before.cd_cnt;
 
-        // 현재 예약 인원 조회
+        // 현재 예약 인원 조회 (스케줄 행 잠금 → 동시 예약 시 초과 예약 방지)
         let schedule_data = sqlx::query!(
-            "SELECT c.tot_seats AS total_seats, cs.adult_count, cs.child_count
+            r#"SELECT c.tot_seats AS total_seats, cs.adult_count, cs.child_count,
+                    COALESCE(q.quota_seats, 0) AS "group_seats!: i32"
              FROM CONTENT_SCHEDULES cs
              JOIN CONTENTS c ON c.id = cs.content_id
-             WHERE cs.id = ?",
+             LEFT JOIN SCHEDULE_GROUP_QUOTA q ON q.content_schedule_id = cs.id
+             WHERE cs.id = ?
+             FOR UPDATE OF cs"#,
              schedule_id
         )
         .fetch_one(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
     
-        let total_seats: i32 = schedule_data.total_seats.map(|v| v as i32).unwrap_or(0);
+        let total_seats: i32 = schedule_data.total_seats.map(|v| v as i32).unwrap_or(0) - schedule_data.group_seats;
         let current_adults: i32 = schedule_data.adult_count;
         let current_children: i32 = schedule_data.child_count;
         
@@ -282,21 +704,37 @@ impl ReservationRepository for ReservationRepositoryImpl {
                 total_seats, current_adults + current_children, new_total
             ));
         }
+
+        // 권종별 회차 최대 인원 확인 (기존 예약 인원은 새 인원으로 대체)
+        if let Err(e) = Self::check_category_capacity(&mut tx, schedule_id, party, Some(reservation_id)).await {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(e);
+        }
         
          // 예약 정보 업데이트 (RESERVATION 테이블)
     sqlx::query!(
         "UPDATE RESERVATION
          SET ad_cnt = ?, 
-             cd_cnt = ?
+             cd_cnt = ?,
+             list_price = ?,
+             total_price = ?,
+             discount_type = ?,
+             currency = ?
          WHERE id = ?",
         ad_cnt,
         cd_cnt,
+        price.list_price,
+        price.total_price,
+        price.discount_type.map(|discount_type| discount_type.to_string()),
+        price.currency,
         reservation_id
     )
     .execute(&mut *tx)
     .await
     .map_err(|e| e.to_string())?;
 
+        Self::save_party(&mut tx, reservation_id, party).await?;
+
         // 스케줄 정보 업데이트 
         sqlx::query!(
             "UPDATE CONTENT_SCHEDULES
@@ -311,20 +749,180 @@ impl ReservationRepository for ReservationRepositoryImpl {
         .await
         .map_err(|e| e.to_string())?;
 
+        let after = Self::load_snapshot(&mut tx, reservation_id).await?;
+        Self::record_change(&mut tx, reservation_id, HistoryAction::ChangeCount, audit, Some(&before), after.as_ref()).await?;
+
         tx.commit().await.map_err(|e| e.to_string())?; 
         Ok(())
     }
 
-    async fn delete_reservation(&self, reservation_id: i32) -> Result<(), String> {
+    // 다른 회차로 예약 이동 (기존 회차 좌석 반환 + 새 회차 좌석 확보를 한 트랜잭션으로 처리, 예약 ID 유지)
+    async fn move_reservation(&self, reservation_id: i32, new_schedule_id: u64, price: &ReservationPrice, audit: &AuditContext) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // 예약 행 잠금 (동일 예약에 대한 동시 수정 방지)
+        let before = Self::load_snapshot(&mut tx, reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", reservation_id))?;
+
+        let old_schedule_id: u64 = before.content_schedule_id;
+        let ad_cnt: i32 = before.ad_cnt;
+        let cd_cnt: i32 = before.cd_cnt;
+        let is_pending = matches!(before.status, None | Some(ReservationStatus::Pending));
+        let is_untouched = before.checked_in_ad_cnt == 0 && before.checked_in_cd_cnt == 0; // 전원 입장 시 CONFIRMED
+        if !is_pending || !is_untouched {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err("취소 / 사용 / 입장이 시작된 예약은 이동할 수 없습니다.".to_string());
+        }
+        if old_schedule_id == new_schedule_id {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err("현재와 같은 회차로는 이동할 수 없습니다.".to_string());
+        }
+
+        // 두 회차 행을 id 순서로 잠금 (동시 이동 시 교착 상태 방지)
+        let schedules = sqlx::query!(
+            r#"SELECT cs.id, c.tot_seats AS total_seats, cs.adult_count, cs.child_count,
+                    COALESCE(q.quota_seats, 0) AS "group_seats!: i32"
+             FROM CONTENT_SCHEDULES cs
+             JOIN CONTENTS c ON c.id = cs.content_id
+             LEFT JOIN SCHEDULE_GROUP_QUOTA q ON q.content_schedule_id = cs.id
+             WHERE cs.id IN (?, ?)
+             ORDER BY cs.id
+             FOR UPDATE OF cs"#,
+            old_schedule_id,
+            new_schedule_id
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let Some(new_schedule) = schedules.iter().find(|schedule| schedule.id == new_schedule_id) else {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("스케줄을 찾을 수 없습니다! ID: {}", new_schedule_id));
+        };
+
+        let total_seats: i32 = new_schedule.total_seats.unwrap_or(0) - new_schedule.group_seats;
+        let current_total = new_schedule.adult_count + new_schedule.child_count;
+        let final_total = current_total + ad_cnt + cd_cnt;
+
+        // total_seats 초과 확인
+        if final_total > total_seats {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!(
+                "예약 불가: 최대 좌석 수 초과 (최대 {:?}명, 현재 예약 {:?}명, 요청한 예약 {:?}명)",
+                total_seats, current_total, ad_cnt + cd_cnt
+            ));
+        }
+
+        // 새 회차의 권종별 최대 인원 확인
+        let party = Self::load_party(&mut tx, reservation_id, ad_cnt, cd_cnt).await?;
+        if let Err(e) = Self::check_category_capacity(&mut tx, new_schedule_id, &party, Some(reservation_id)).await {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(e);
+        }
+
+        // 기존 회차 좌석 반환
+        sqlx::query!(
+            "UPDATE CONTENT_SCHEDULES
+             SET adult_count = adult_count - ?,
+                 child_count = child_count - ?
+             WHERE id = ?",
+            ad_cnt,
+            cd_cnt,
+            old_schedule_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // 새 회차 좌석 확보
+        sqlx::query!(
+            "UPDATE CONTENT_SCHEDULES
+             SET adult_count = adult_count + ?,
+                 child_count = child_count + ?
+             WHERE id = ?",
+            ad_cnt,
+            cd_cnt,
+            new_schedule_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        sqlx::query!(
+            "UPDATE RESERVATION
+             SET content_schedule_id = ?,
+                 list_price = ?,
+                 total_price = ?,
+                 discount_type = ?,
+                 currency = ?
+             WHERE id = ?",
+            new_schedule_id,
+            price.list_price,
+            price.total_price,
+            price.discount_type.map(|discount_type| discount_type.to_string()),
+            price.currency,
+            reservation_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let after = Self::load_snapshot(&mut tx, reservation_id).await?;
+        Self::record_change(&mut tx, reservation_id, HistoryAction::Move, audit, Some(&before), after.as_ref()).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn delete_reservation(&self, reservation_id: i32, audit: &AuditContext) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let before = Self::load_snapshot(&mut tx, reservation_id).await?;
+        // 삭제 후에는 payload 를 만들 수 없으므로 먼저 기록
+        Self::insert_outbox(&mut tx, reservation_id, ReservationEventType::from_change(HistoryAction::Delete, None)).await?;
+
         sqlx::query!(
             "DELETE FROM RESERVATION WHERE id = ?",
             reservation_id
         )
-        .execute(&*self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
+
+        Self::insert_history(&mut tx, reservation_id, HistoryAction::Delete, audit, before.as_ref(), None).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
         Ok(())
     }
+
+    // 예약 변경 이력 조회 (오래된 순)
+    async fn load_reservation_history(&self, reservation_id: i32) -> Result<Vec<ReservationHistory>, String> {
+        let rows = query(
+            "SELECT 
+                id, reservation_id, action, actor_id, channel,
+                before_user_id, before_schedule_id, before_ad_cnt, before_cd_cnt, before_status, before_checked_in_ad_cnt, before_checked_in_cd_cnt,
+                after_user_id, after_schedule_id, after_ad_cnt, after_cd_cnt, after_status, after_checked_in_ad_cnt, after_checked_in_cd_cnt,
+                created_at
+             FROM RESERVATION_HISTORY
+             WHERE reservation_id = ?
+             ORDER BY id"
+        )
+        .bind(reservation_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows.iter().map(|row| ReservationHistory {
+            id: row.get("id"),
+            reservation_id: row.get("reservation_id"),
+            action: row.get::<String, _>("action").parse().unwrap_or(HistoryAction::StatusChange),
+            actor_id: row.try_get("actor_id").ok().flatten(),
+            channel: row.get::<String, _>("channel").parse().unwrap_or(AuditChannel::System),
+            before: Self::map_snapshot(row, "before"),
+            after: Self::map_snapshot(row, "after"),
+            created_at: row.try_get("created_at").ok(),
+        }).collect())
+    }
     async fn check_reservation_for_user_count(&self, user_id: &str, schedule_id: u64) -> Result<ReservationLimits, String> {
         let mut tx: sqlx::Transaction<'_, sqlx::MySql> = self.pool.begin().await.map_err(|e| e.to_string())?;
         
@@ -341,7 +939,8 @@ impl ReservationRepository for ReservationRepositoryImpl {
             JOIN CONTENT_SCHEDULES cs ON re.content_schedule_id = cs.id
             JOIN content_info ci ON cs.content_id = ci.content_id
             WHERE re.user_id = ?
-            AND re.status != 'CANCELED'"
+            AND re.group_reservation_id IS NULL -- 단체 예약은 개인 인원 제한에서 제외
+            AND (re.status IS NULL OR re.status != 'CANCELLED')"
         )
         .bind(schedule_id)
         .bind(user_id)
@@ -377,7 +976,8 @@ impl ReservationRepository for ReservationRepositoryImpl {
                     SELECT start_time FROM CONTENT_SCHEDULES WHERE id = ?
                 )
                 AND r.user_id = ?
-                AND (r.status IS NULL OR r.status != 'CANCELED')
+                AND r.group_reservation_id IS NULL
+                AND (r.status IS NULL OR r.status != 'CANCELLED')
             ) AS has_reservation;
             "#,
             schedule_id,
@@ -391,6 +991,22 @@ impl ReservationRepository for ReservationRepositoryImpl {
         Ok(has_reservation)
     }
 
+    // 기간 내 사용자 노쇼 횟수 (회차 시작 시간 기준)
+    async fn count_user_no_shows(&self, user_id: &str, since: DateTime<Utc>) -> Result<i64, String> {
+        let row = sqlx::query!(
+            "SELECT COUNT(*) AS no_show_count
+             FROM NO_SHOW_RECORD
+             WHERE user_id = ? AND schedule_start_time >= ?",
+            user_id,
+            since
+        )
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(row.no_show_count)
+    }
+
     // 동일 컨텐츠에 대한 예약 건이 있는지 확인
     async fn check_user_reservation_for_content(&self, user_id: &str, schedule_id: u64) -> Result<bool, String> {
         let has_reservation: bool = sqlx::query!(
@@ -401,7 +1017,8 @@ impl ReservationRepository for ReservationRepositoryImpl {
                 JOIN CONTENT_SCHEDULES cs ON r.content_schedule_id = cs.id
                 WHERE cs.content_id = (SELECT content_id FROM CONTENT_SCHEDULES WHERE id = ?)
                 AND r.user_id = ?
-                AND r.status != 'CANCELED' -- 취소된 예약 제외
+                AND r.group_reservation_id IS NULL
+                AND (r.status IS NULL OR r.status != 'CANCELLED') -- 취소된 예약 제외
             ) AS has_reservation;
             "#,
             schedule_id,
@@ -414,4 +1031,103 @@ impl ReservationRepository for ReservationRepositoryImpl {
     
         Ok(has_reservation)
     }
+
+    // 스케줄별 총 좌석 / 예약 인원 / 잔여 좌석 조회
+    async fn load_schedule_availability(&self, schedule_id: u64) -> Result<Option<ScheduleAvailability>, String> {
+        let row = query(
+            "
+            SELECT 
+                cs.id AS content_schedule_id, cs.content_id, cs.start_time,
+                c.tot_seats AS total_seats, cs.adult_count, cs.child_count,
+                COALESCE(q.quota_seats, 0) AS group_seats
+            FROM CONTENT_SCHEDULES cs
+            JOIN CONTENTS c ON c.id = cs.content_id
+            LEFT JOIN SCHEDULE_GROUP_QUOTA q ON q.content_schedule_id = cs.id
+            WHERE cs.id = ?
+            "
+        )
+        .bind(schedule_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| err.to_string())?;
+
+        Ok(row.as_ref().map(Self::map_schedule_availability))
+    }
+
+    // 조건에 맞는 스케줄들의 잔여 좌석을 단일 쿼리로 일괄 조회
+    async fn load_schedules_availability(&self, filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String> {
+        let mut builder = QueryBuilder::<MySql>::new(
+            "
+            SELECT 
+                cs.id AS content_schedule_id, cs.content_id, cs.start_time,
+                c.tot_seats AS total_seats, cs.adult_count, cs.child_count,
+                COALESCE(q.quota_seats, 0) AS group_seats
+            FROM CONTENT_SCHEDULES cs
+            JOIN CONTENTS c ON c.id = cs.content_id
+            LEFT JOIN SCHEDULE_GROUP_QUOTA q ON q.content_schedule_id = cs.id
+            WHERE 1 = 1
+            "
+        );
+
+        if let (Some(start_time), Some(end_time)) = (filter.start_time, filter.end_time) {
+            builder.push(" AND cs.start_time >= ").push_bind(start_time)
+                .push(" AND cs.start_time < ").push_bind(end_time);
+        }
+        if !filter.schedule_ids.is_empty() {
+            builder.push(" AND cs.id IN (");
+            let mut ids = builder.separated(", ");
+            for schedule_id in filter.schedule_ids {
+                ids.push_bind(schedule_id);
+            }
+            ids.push_unseparated(")");
+        }
+        if let Some(content_id) = filter.content_id {
+            builder.push(" AND cs.content_id = ").push_bind(content_id);
+        }
+        builder.push(" ORDER BY cs.start_time, cs.id");
+
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(rows.iter().map(Self::map_schedule_availability).collect())
+    }
+
+    // 조건 조합 예약 검색 (기간 / 스케줄 / 컨텐츠 / 상태 / 사용자 + 정렬 + 커서 페이지네이션)
+    async fn search_reservations(&self, filter: ReservationFilter, page: PageRequest) -> Result<Page<Reservation>, String> {
+        let mut builder = QueryBuilder::<MySql>::new(RESERVATION_SELECT);
+        builder.push(" WHERE 1 = 1");
+
+        if let Some(from) = filter.from {
+            builder.push(" AND r.reserved_at >= ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            builder.push(" AND r.reserved_at < ").push_bind(to);
+        }
+        if let Some(schedule_id) = filter.schedule_id {
+            builder.push(" AND r.content_schedule_id = ").push_bind(schedule_id);
+        }
+        if let Some(content_id) = filter.content_id {
+            builder.push(" AND cs.content_id = ").push_bind(content_id);
+        }
+        if let Some(status) = filter.status {
+            builder.push(" AND r.status = ").push_bind(status.to_string());
+        }
+        if let Some(user_id) = filter.user_id {
+            builder.push(" AND r.user_id = ").push_bind(user_id);
+        }
+
+        // 정렬 컬럼은 허용 목록(ReservationSort)에서만 선택, 동일 값은 id로 안정 정렬
+        Self::push_page(&mut builder, filter.sort, filter.descending, &page);
+
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(Self::into_page(rows, filter.sort, &page))
+    }
 }
@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, query, MySqlPool, Row};
+use std::sync::Arc;
+use crate::{domain::{reservation::ReservationStatus, reservation_history::{AuditContext, HistoryAction}, reservation_transfer::{ReservationTransfer, TransferStatus}}, infra::db::{reservation_repository_impl::ReservationRepositoryImpl, transfer_repository::TransferRepository}};
+
+const TRANSFER_COLUMNS: &str = "id, reservation_id, from_user_id, to_user_id, status, created_at, expires_at, responded_at";
+
+// Repository Implementation
+pub struct TransferRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl TransferRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+
+    fn map_transfer(row: &MySqlRow) -> ReservationTransfer {
+        ReservationTransfer {
+            id: row.get("id"),
+            reservation_id: row.get("reservation_id"),
+            from_user_id: row.get("from_user_id"),
+            to_user_id: row.get("to_user_id"),
+            status: row.get::<String, _>("status").parse().unwrap_or(TransferStatus::Pending),
+            created_at: row.try_get("created_at").ok(),
+            expires_at: row.get("expires_at"),
+            responded_at: row.try_get("responded_at").ok().flatten(),
+        }
+    }
+}
+
+#[async_trait]
+impl TransferRepository for TransferRepositoryImpl {
+    // 예약당 대기 중인 양도 요청은 1건만 (예약 행 잠금으로 동시 요청 직렬화)
+    async fn save_transfer(&self, transfer: &ReservationTransfer, now: DateTime<Utc>) -> Result<i64, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let owner: Option<String> = query("SELECT user_id FROM RESERVATION WHERE id = ? FOR UPDATE")
+            .bind(transfer.reservation_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(|row| row.get("user_id"));
+        if owner.as_deref() != Some(transfer.from_user_id.as_str()) {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("예약을 찾을 수 없습니다! ID: {}", transfer.reservation_id));
+        }
+
+        // 기한이 지난 요청 정리
+        query("UPDATE RESERVATION_TRANSFER SET status = 'EXPIRED' WHERE reservation_id = ? AND status = 'PENDING' AND expires_at <= ?")
+            .bind(transfer.reservation_id)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let pending = query("SELECT id FROM RESERVATION_TRANSFER WHERE reservation_id = ? AND status = 'PENDING'")
+            .bind(transfer.reservation_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Some(pending) = pending {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("이미 대기 중인 양도 요청이 있습니다. (양도 ID: {})", pending.get::<i64, _>("id")));
+        }
+
+        let inserted = query(
+            "INSERT INTO RESERVATION_TRANSFER (reservation_id, from_user_id, to_user_id, status, expires_at)
+             VALUES (?, ?, ?, 'PENDING', ?)"
+        )
+        .bind(transfer.reservation_id)
+        .bind(&transfer.from_user_id)
+        .bind(&transfer.to_user_id)
+        .bind(transfer.expires_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(inserted.last_insert_id() as i64)
+    }
+
+    async fn load_transfer(&self, transfer_id: i64) -> Result<Option<ReservationTransfer>, String> {
+        let row = query(&format!("SELECT {} FROM RESERVATION_TRANSFER WHERE id = ?", TRANSFER_COLUMNS))
+            .bind(transfer_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_transfer))
+    }
+
+    // 보낸 / 받은 양도 요청 (최신순)
+    async fn load_user_transfers(&self, user_id: &str, limit: i64) -> Result<Vec<ReservationTransfer>, String> {
+        let rows = query(&format!(
+            "SELECT {} FROM RESERVATION_TRANSFER
+             WHERE from_user_id = ? OR to_user_id = ?
+             ORDER BY id DESC
+             LIMIT ?",
+            TRANSFER_COLUMNS
+        ))
+        .bind(user_id)
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(Self::map_transfer).collect())
+    }
+
+    // 수락: 요청 / 예약 행을 잠그고 상태와 받는 사용자의 한도를 다시 확인한 뒤 소유자 변경 + 이력 기록
+    async fn accept_transfer(&self, transfer_id: i64, max_adult: i32, max_child: i32, now: DateTime<Utc>, audit: &AuditContext) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let transfer = query(&format!("SELECT {} FROM RESERVATION_TRANSFER WHERE id = ? FOR UPDATE", TRANSFER_COLUMNS))
+            .bind(transfer_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(|row| Self::map_transfer(&row))
+            .ok_or(format!("양도 요청을 찾을 수 없습니다! ID: {}", transfer_id))?;
+
+        match transfer.effective_status(now) {
+            TransferStatus::Pending => {},
+            TransferStatus::Expired => {
+                query("UPDATE RESERVATION_TRANSFER SET status = 'EXPIRED' WHERE id = ?")
+                    .bind(transfer_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                tx.commit().await.map_err(|e| e.to_string())?;
+                return Err("응답 기한이 지난 양도 요청입니다.".to_string());
+            },
+            status => {
+                tx.rollback().await.map_err(|e| e.to_string())?;
+                return Err(format!("이미 처리된 양도 요청입니다. (현재 상태: {})", status));
+            },
+        }
+
+        let before = ReservationRepositoryImpl::load_snapshot(&mut tx, transfer.reservation_id)
+            .await?
+            .ok_or(format!("예약을 찾을 수 없습니다! ID: {}", transfer.reservation_id))?;
+        let untouched = matches!(before.status, Some(ReservationStatus::Pending) | None)
+            && before.checked_in_ad_cnt == 0
+            && before.checked_in_cd_cnt == 0;
+        if before.user_id.as_deref() != Some(transfer.from_user_id.as_str()) || !untouched {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err("요청 이후 예약이 변경되어 양도할 수 없습니다.".to_string());
+        }
+
+        // 같은 컨텐츠에 대한 수락을 직렬화하기 위해 컨텐츠 행을 잠근 뒤 받는 사용자의 기존 예약을 다시 집계
+        query("SELECT id FROM CONTENTS WHERE id = (SELECT content_id FROM CONTENT_SCHEDULES WHERE id = ?) FOR UPDATE")
+            .bind(before.content_schedule_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let booked = query(
+            "SELECT COUNT(*) AS reservation_count,
+                    COALESCE(CAST(SUM(r.ad_cnt) AS SIGNED), 0) AS total_adults,
+                    COALESCE(CAST(SUM(r.cd_cnt) AS SIGNED), 0) AS total_children
+             FROM RESERVATION r
+             JOIN CONTENT_SCHEDULES cs ON r.content_schedule_id = cs.id
+             WHERE cs.content_id = (SELECT content_id FROM CONTENT_SCHEDULES WHERE id = ?)
+               AND r.user_id = ?
+               AND r.id != ?
+               AND r.group_reservation_id IS NULL
+               AND (r.status IS NULL OR r.status != 'CANCELLED')
+             FOR SHARE"
+        )
+        .bind(before.content_schedule_id)
+        .bind(&transfer.to_user_id)
+        .bind(transfer.reservation_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let mut reasons = Vec::new();
+        if booked.get::<i64, _>("reservation_count") > 0 {
+            reasons.push("이미 동일 컨텐츠에 대한 예약이 있습니다.".to_string());
+        }
+        let total_adults = booked.get::<i64, _>("total_adults") + i64::from(before.ad_cnt);
+        let total_children = booked.get::<i64, _>("total_children") + i64::from(before.cd_cnt);
+        if total_adults > i64::from(max_adult) {
+            reasons.push(format!("성인 수 초과 ({}명 > {}명)", total_adults, max_adult));
+        }
+        if total_children > i64::from(max_child) {
+            reasons.push(format!("어린이 수 초과 ({}명 > {}명)", total_children, max_child));
+        }
+        if !reasons.is_empty() {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Err(format!("양도 불가: {}", reasons.join(", ")));
+        }
+
+        query("UPDATE RESERVATION SET user_id = ? WHERE id = ?")
+            .bind(&transfer.to_user_id)
+            .bind(transfer.reservation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        query("UPDATE RESERVATION_TRANSFER SET status = 'ACCEPTED', responded_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(transfer_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let after = ReservationRepositoryImpl::load_snapshot(&mut tx, transfer.reservation_id).await?;
+        ReservationRepositoryImpl::record_change(&mut tx, transfer.reservation_id, HistoryAction::Transfer, audit, Some(&before), after.as_ref()).await?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 거절 / 철회 (대기 중인 요청만)
+    async fn close_transfer(&self, transfer_id: i64, status: TransferStatus, now: DateTime<Utc>) -> Result<bool, String> {
+        let result = query("UPDATE RESERVATION_TRANSFER SET status = ?, responded_at = ? WHERE id = ? AND status = 'PENDING'")
+            .bind(status.to_string())
+            .bind(now)
+            .bind(transfer_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+}
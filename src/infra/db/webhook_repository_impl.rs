@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, query, MySql, MySqlPool, QueryBuilder, Row};
+use std::sync::Arc;
+use crate::{domain::webhook::{DeliveryOutcome, DeliveryStatus, PendingWebhookDelivery, WebhookDelivery, WebhookSubscription}, infra::db::webhook_repository::WebhookRepository};
+
+const MAX_ERROR_LEN: usize = 500;
+
+const DELIVERY_COLUMNS: &str = "
+    d.id, d.subscription_id, d.event_id, d.event_type, d.body, d.status, d.attempts,
+    d.last_status_code, d.last_error, d.created_at, d.delivered_at
+";
+
+// Repository Implementation
+pub struct WebhookRepositoryImpl {
+    pool: Arc<MySqlPool>,
+}
+
+impl WebhookRepositoryImpl {
+    pub fn new(pool: Arc<MySqlPool>) -> Self {
+        Self { pool }
+    }
+
+    fn map_subscription(row: &MySqlRow) -> WebhookSubscription {
+        let event_types: Option<String> = row.try_get("event_types").ok().flatten();
+        WebhookSubscription {
+            id: row.get("id"),
+            url: row.get("url"),
+            secret: row.get("secret"),
+            event_types: WebhookSubscription::parse_event_types_column(event_types.as_deref()),
+            active: row.get("active"),
+            created_at: row.try_get("created_at").ok(),
+        }
+    }
+
+    fn map_delivery(row: &MySqlRow) -> WebhookDelivery {
+        WebhookDelivery {
+            id: row.get("id"),
+            subscription_id: row.get("subscription_id"),
+            event_id: row.get("event_id"),
+            event_type: row.get("event_type"),
+            body: row.get("body"),
+            status: row.get::<String, _>("status").parse().unwrap_or(DeliveryStatus::Pending),
+            attempts: row.get("attempts"),
+            last_status_code: row.try_get("last_status_code").ok().flatten(),
+            last_error: row.try_get("last_error").ok().flatten(),
+            created_at: row.try_get("created_at").ok(),
+            delivered_at: row.try_get("delivered_at").ok().flatten(),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryImpl {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<i64, String> {
+        let inserted = query("INSERT INTO WEBHOOK_SUBSCRIPTION (url, secret, event_types, active) VALUES (?, ?, ?, ?)")
+            .bind(&subscription.url)
+            .bind(&subscription.secret)
+            .bind(subscription.event_types_column())
+            .bind(subscription.active)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(inserted.last_insert_id() as i64)
+    }
+
+    async fn update_subscription(&self, subscription: &WebhookSubscription) -> Result<bool, String> {
+        let result = query("UPDATE WEBHOOK_SUBSCRIPTION SET url = ?, secret = ?, event_types = ?, active = ? WHERE id = ?")
+            .bind(&subscription.url)
+            .bind(&subscription.secret)
+            .bind(subscription.event_types_column())
+            .bind(subscription.active)
+            .bind(subscription.id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    // 구독 삭제 시 전달 기록도 함께 삭제 (FK CASCADE)
+    async fn delete_subscription(&self, subscription_id: i64) -> Result<bool, String> {
+        let result = query("DELETE FROM WEBHOOK_SUBSCRIPTION WHERE id = ?")
+            .bind(subscription_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn load_subscription(&self, subscription_id: i64) -> Result<Option<WebhookSubscription>, String> {
+        let row = query("SELECT id, url, secret, event_types, active, created_at FROM WEBHOOK_SUBSCRIPTION WHERE id = ?")
+            .bind(subscription_id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(row.as_ref().map(Self::map_subscription))
+    }
+
+    async fn load_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String> {
+        let rows = query("SELECT id, url, secret, event_types, active, created_at FROM WEBHOOK_SUBSCRIPTION ORDER BY id")
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(Self::map_subscription).collect())
+    }
+
+    // 이벤트 종류가 일치하는 활성 구독마다 전달 생성 (같은 이벤트가 다시 들어와도 구독당 1건)
+    async fn enqueue_deliveries(&self, event_id: i64, event_type: &str, body: &str) -> Result<u64, String> {
+        let result = query(
+            "INSERT IGNORE INTO WEBHOOK_DELIVERY (subscription_id, event_id, event_type, body)
+             SELECT s.id, ?, ?, ?
+             FROM WEBHOOK_SUBSCRIPTION s
+             WHERE s.active = TRUE
+               AND (s.event_types IS NULL OR FIND_IN_SET(?, s.event_types) > 0)"
+        )
+        .bind(event_id)
+        .bind(event_type)
+        .bind(body)
+        .bind(event_type)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected())
+    }
+
+    // 전달 대상 선점 (비활성 구독의 전달은 재활성화될 때까지 대기)
+    async fn claim_pending_deliveries(&self, now: DateTime<Utc>, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<PendingWebhookDelivery>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let sql = format!(
+            "SELECT {}, s.url, s.secret
+             FROM WEBHOOK_DELIVERY d
+             JOIN WEBHOOK_SUBSCRIPTION s ON s.id = d.subscription_id
+             WHERE d.status = 'PENDING'
+               AND d.next_attempt_at <= ?
+               AND s.active = TRUE
+             ORDER BY d.id
+             LIMIT ?
+             FOR UPDATE OF d SKIP LOCKED",
+            DELIVERY_COLUMNS
+        );
+        let rows = query(&sql)
+            .bind(now)
+            .bind(limit)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if rows.is_empty() {
+            tx.rollback().await.map_err(|e| e.to_string())?;
+            return Ok(Vec::new());
+        }
+
+        let deliveries: Vec<PendingWebhookDelivery> = rows.iter().map(|row| PendingWebhookDelivery {
+            delivery: Self::map_delivery(row),
+            url: row.get("url"),
+            secret: row.get("secret"),
+        }).collect();
+
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new("UPDATE WEBHOOK_DELIVERY SET next_attempt_at = ");
+        builder.push_bind(lease_until);
+        builder.push(" WHERE id IN (");
+        let mut ids = builder.separated(", ");
+        for pending in &deliveries {
+            ids.push_bind(pending.delivery.id);
+        }
+        ids.push_unseparated(")");
+        builder.build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        Ok(deliveries)
+    }
+
+    async fn record_delivery_outcome(&self, delivery_id: i64, outcome: &DeliveryOutcome) -> Result<(), String> {
+        let truncate = |error: &str| -> String { error.chars().take(MAX_ERROR_LEN).collect() };
+        let result = match outcome {
+            DeliveryOutcome::Delivered { status_code } => query(
+                "UPDATE WEBHOOK_DELIVERY
+                 SET status = 'DELIVERED', attempts = attempts + 1, last_status_code = ?, last_error = NULL, delivered_at = CURRENT_TIMESTAMP(6)
+                 WHERE id = ?"
+            )
+            .bind(status_code)
+            .bind(delivery_id)
+            .execute(&*self.pool)
+            .await,
+            DeliveryOutcome::Retry { status_code, error, next_attempt_at } => query(
+                "UPDATE WEBHOOK_DELIVERY
+                 SET attempts = attempts + 1, last_status_code = ?, last_error = ?, next_attempt_at = ?
+                 WHERE id = ?"
+            )
+            .bind(status_code)
+            .bind(truncate(error))
+            .bind(next_attempt_at)
+            .bind(delivery_id)
+            .execute(&*self.pool)
+            .await,
+            DeliveryOutcome::Dead { status_code, error } => query(
+                "UPDATE WEBHOOK_DELIVERY
+                 SET status = 'DEAD', attempts = attempts + 1, last_status_code = ?, last_error = ?
+                 WHERE id = ?"
+            )
+            .bind(status_code)
+            .bind(truncate(error))
+            .bind(delivery_id)
+            .execute(&*self.pool)
+            .await,
+        };
+        result.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 최근 실패 순
+    async fn load_dead_deliveries(&self, subscription_id: Option<i64>, limit: i64) -> Result<Vec<WebhookDelivery>, String> {
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new(format!("SELECT {} FROM WEBHOOK_DELIVERY d WHERE d.status = 'DEAD'", DELIVERY_COLUMNS));
+        if let Some(subscription_id) = subscription_id {
+            builder.push(" AND d.subscription_id = ").push_bind(subscription_id);
+        }
+        builder.push(" ORDER BY d.id DESC LIMIT ").push_bind(limit);
+
+        let rows = builder.build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(Self::map_delivery).collect())
+    }
+
+    // 실패 / 완료된 전달을 처음부터 다시 시도 (전달 중인 건은 제외)
+    async fn replay_delivery(&self, delivery_id: i64) -> Result<bool, String> {
+        let result = query(
+            "UPDATE WEBHOOK_DELIVERY
+             SET status = 'PENDING', attempts = 0, last_error = NULL, next_attempt_at = CURRENT_TIMESTAMP(6)
+             WHERE id = ?
+               AND status IN ('DEAD', 'DELIVERED')"
+        )
+        .bind(delivery_id)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected() == 1)
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::{application::port::out::event_publish_port::EventPublishPort, domain::reservation_event::OutboxEvent};
+
+// 여러 전달 대상에 순서대로 전달 (하나라도 실패하면 이벤트 전체를 재시도하므로 각 대상은 중복 수신을 허용해야 함)
+pub struct CompositeEventSink {
+    sinks: Vec<Arc<dyn EventPublishPort + Send + Sync>>,
+}
+
+impl CompositeEventSink {
+    pub fn new(sinks: Vec<Arc<dyn EventPublishPort + Send + Sync>>) -> Self {
+        Self { sinks }
+    }
+}
+
+#[async_trait]
+impl EventPublishPort for CompositeEventSink {
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String> {
+        for sink in &self.sinks {
+            sink.publish(event).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use std::time::Duration;
+use tonic::{transport::{Channel, Endpoint}, Request};
+use crate::{application::port::out::event_publish_port::EventPublishPort, domain::reservation_event::OutboxEvent, reservationevent_proto::{reservation_event_sink_client::ReservationEventSinkClient, ReservationEvent}};
+
+// gRPC ReservationEventSink 서비스로 이벤트 전달
+pub struct GrpcEventSink {
+    client: ReservationEventSinkClient<Channel>,
+}
+
+impl GrpcEventSink {
+    // 연결은 첫 전달 시점에 맺음 (수신 서비스가 늦게 떠도 기동 가능)
+    pub fn new(addr: &str, timeout_secs: u64) -> Result<Self, String> {
+        let channel = Endpoint::from_shared(addr.to_string())
+            .map_err(|e| e.to_string())?
+            .timeout(Duration::from_secs(timeout_secs.max(1)))
+            .connect_lazy();
+        Ok(Self { client: ReservationEventSinkClient::new(channel) })
+    }
+}
+
+#[async_trait]
+impl EventPublishPort for GrpcEventSink {
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String> {
+        let request = Request::new(ReservationEvent {
+            event_id: event.event_id,
+            event_type: event.event_type.clone(),
+            reservation_id: event.reservation_id,
+            payload: event.payload.to_string(),
+            occurred_at: event.occurred_at.to_rfc3339(),
+        });
+
+        // 클라이언트는 Channel 을 공유하므로 복제해서 사용
+        let ack = self.client.clone()
+            .publish(request)
+            .await
+            .map_err(|status| status.to_string())?
+            .into_inner();
+
+        if ack.accepted {
+            Ok(())
+        } else {
+            Err(format!("수신 거부: {}", ack.message))
+        }
+    }
+}
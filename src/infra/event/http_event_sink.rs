@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use std::time::Duration;
+use crate::{application::port::out::event_publish_port::EventPublishPort, domain::reservation_event::OutboxEvent};
+
+// HTTP 웹훅으로 이벤트 전달 (2xx 응답만 성공으로 처리)
+pub struct HttpEventSink {
+    client: Client,
+    url: String,
+}
+
+impl HttpEventSink {
+    pub fn new(url: &str, timeout_secs: u64) -> Result<Self, String> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(timeout_secs.max(1)))
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self { client, url: url.to_string() })
+    }
+}
+
+#[async_trait]
+impl EventPublishPort for HttpEventSink {
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String> {
+        let response = self.client
+            .post(&self.url)
+            .header("X-Event-Id", event.event_id.to_string())
+            .header("X-Event-Type", &event.event_type)
+            .json(event)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("HTTP {}", response.status()))
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::Mutex};
+use crate::{application::port::out::event_publish_port::EventPublishPort, domain::reservation_event::OutboxEvent};
+
+// 로컬 개발 / 테스트용: 이벤트를 JSON 한 줄씩 파일에 추가하거나 (경로 미지정 시) 표준 출력으로 기록
+pub struct LogEventSink {
+    path: Option<PathBuf>,
+    write_lock: Mutex<()>, // 여러 줄이 섞이지 않도록 파일 쓰기 직렬화
+}
+
+impl LogEventSink {
+    pub fn new(path: Option<&str>) -> Self {
+        Self { path: path.map(PathBuf::from), write_lock: Mutex::new(()) }
+    }
+}
+
+#[async_trait]
+impl EventPublishPort for LogEventSink {
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String> {
+        let line = serde_json::to_string(event).map_err(|e| e.to_string())?;
+
+        let Some(path) = &self.path else {
+            println!("📨 예약 이벤트: {}", line);
+            return Ok(());
+        };
+
+        let _guard = self.write_lock.lock().await;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .map_err(|e| e.to_string())?;
+        file.write_all(format!("{}\n", line).as_bytes())
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
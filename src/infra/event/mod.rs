@@ -0,0 +1,13 @@
+pub mod http_event_sink;
+pub mod grpc_event_sink;
+pub mod log_event_sink;
+pub mod composite_event_sink;
+pub mod webhook_fanout_sink;
+pub mod webhook_http_sender;
+
+pub use http_event_sink::HttpEventSink;
+pub use grpc_event_sink::GrpcEventSink;
+pub use log_event_sink::LogEventSink;
+pub use composite_event_sink::CompositeEventSink;
+pub use webhook_fanout_sink::WebhookFanoutSink;
+pub use webhook_http_sender::WebhookHttpSender;
\ No newline at end of file
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::{application::port::out::{event_publish_port::EventPublishPort, webhook_port::WebhookPort}, domain::reservation_event::OutboxEvent};
+
+// 아웃박스 이벤트 → 구독별 웹훅 전달 생성 (실제 전송은 웹훅 전달 작업이 수행)
+pub struct WebhookFanoutSink {
+    webhook_port: Arc<dyn WebhookPort + Send + Sync>,
+}
+
+impl WebhookFanoutSink {
+    pub fn new(webhook_port: Arc<dyn WebhookPort + Send + Sync>) -> Self {
+        Self { webhook_port }
+    }
+}
+
+#[async_trait]
+impl EventPublishPort for WebhookFanoutSink {
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String> {
+        let body = serde_json::to_string(event).map_err(|e| e.to_string())?;
+        self.webhook_port.enqueue_deliveries(event.event_id, &event.event_type, &body).await?;
+        Ok(())
+    }
+}
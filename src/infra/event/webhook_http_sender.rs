@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::Client;
+use std::error::Error;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::application::port::out::webhook_send_port::WebhookSendPort;
+use crate::common::network::{ensure_public_host, is_public_ip, url_host};
+
+// 공인 주소만 돌려주는 DNS 조회 (등록 후 도메인이 내부 주소로 바뀌는 경우 차단)
+struct PublicOnlyResolver;
+
+impl PublicOnlyResolver {
+    async fn lookup(host: String) -> Result<Addrs, Box<dyn Error + Send + Sync>> {
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+            .await?
+            .filter(|addr| is_public_ip(addr.ip()))
+            .collect();
+        if addrs.is_empty() {
+            return Err(format!("내부 주소로는 보낼 수 없습니다: {}", host).into());
+        }
+        Ok(Box::new(addrs.into_iter()))
+    }
+}
+
+impl Resolve for PublicOnlyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(Self::lookup(name.as_str().to_string()))
+    }
+}
+
+// 웹훅 HTTP POST 전송 (리다이렉트 / 프록시는 따르지 않고 공인 주소로만 전송)
+pub struct WebhookHttpSender {
+    client: Client,
+    allow_private_targets: bool, // 개발용: 루프백 / 내부망 주소 허용
+}
+
+impl WebhookHttpSender {
+    pub fn new(timeout_secs: u64, allow_private_targets: bool) -> Result<Self, String> {
+        let mut builder = Client::builder()
+            .timeout(Duration::from_secs(timeout_secs.max(1)))
+            .redirect(reqwest::redirect::Policy::none())
+            .no_proxy();
+        if !allow_private_targets {
+            builder = builder.dns_resolver(Arc::new(PublicOnlyResolver));
+        }
+        let client = builder.build().map_err(|e| e.to_string())?;
+        Ok(Self { client, allow_private_targets })
+    }
+}
+
+#[async_trait]
+impl WebhookSendPort for WebhookHttpSender {
+    async fn send(&self, url: &str, headers: &[(&str, String)], body: &str) -> Result<u16, String> {
+        // IP 주소로 등록된 URL 은 DNS 조회를 거치지 않으므로 여기서 확인
+        if !self.allow_private_targets {
+            ensure_public_host(url_host(url).ok_or("url에 호스트가 없습니다.".to_string())?)?;
+        }
+
+        let mut request = self.client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        Ok(response.status().as_u16())
+    }
+}
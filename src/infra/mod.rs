@@ -1,2 +1,5 @@
 pub mod web;
-pub mod db;
\ No newline at end of file
+pub mod db;
+pub mod event;
+pub mod notification;
+pub mod payment;
\ No newline at end of file
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use std::{collections::HashMap, time::Duration};
+use tonic::{transport::{Channel, Endpoint}, Request};
+use crate::{application::port::out::{notification_port::NotificationPort, push_notification_port::PushNotificationPort}, domain::{notification::NotificationMessage, reminder::PushNotification}, notification_proto::{notification_service_client::NotificationServiceClient, NotificationRequest}};
+
+// 알림 gRPC 서비스 (NotificationService.SendNotification) 호출
+pub struct GrpcPushNotifier {
+    client: NotificationServiceClient<Channel>,
+}
+
+impl GrpcPushNotifier {
+    // 연결은 첫 발송 시점에 맺음 (알림 서비스가 늦게 떠도 기동 가능)
+    pub fn new(addr: &str, timeout_secs: u64) -> Result<Self, String> {
+        let channel = Endpoint::from_shared(addr.to_string())
+            .map_err(|e| e.to_string())?
+            .timeout(Duration::from_secs(timeout_secs.max(1)))
+            .connect_lazy();
+        Ok(Self { client: NotificationServiceClient::new(channel) })
+    }
+}
+
+#[async_trait]
+impl PushNotificationPort for GrpcPushNotifier {
+    async fn send_push(&self, notification: &PushNotification) -> Result<(), String> {
+        let request = Request::new(NotificationRequest {
+            user_ids: notification.user_ids.clone(),
+            title: notification.title.clone(),
+            body: notification.body.clone(),
+            data: notification.data.iter().cloned().collect::<HashMap<String, String>>(),
+            dedup_key: notification.dedup_key.clone(),
+        });
+
+        // 클라이언트는 Channel 을 공유하므로 복제해서 사용
+        let response = self.client.clone()
+            .send_notification(request)
+            .await
+            .map_err(|status| status.to_string())?
+            .into_inner();
+
+        if response.success {
+            println!("알림 발송 완료 ({}): {}명", notification.dedup_key, response.sent_count);
+            Ok(())
+        } else {
+            Err(format!("알림 서비스 오류: {}", response.message))
+        }
+    }
+}
+
+// 예약 알림도 같은 알림 서비스로 발송 (사용자 1명)
+#[async_trait]
+impl NotificationPort for GrpcPushNotifier {
+    async fn notify(&self, message: &NotificationMessage) -> Result<(), String> {
+        self.send_push(&PushNotification {
+            user_ids: vec![message.user_id.clone()],
+            title: message.title.clone(),
+            body: message.body.clone(),
+            data: message.data.clone(),
+            dedup_key: message.dedup_key.clone(),
+        })
+        .await
+    }
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::{application::port::out::user_locale_port::UserLocalePort, domain::notification::Locale, grpc_client::GrpcClients};
+
+// User-Service 사용자 정보의 locale 로 알림 언어 결정
+pub struct GrpcUserLocale {
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+}
+
+impl GrpcUserLocale {
+    pub fn new(grpc_clients: Arc<Mutex<GrpcClients>>) -> Self {
+        Self { grpc_clients }
+    }
+}
+
+#[async_trait]
+impl UserLocalePort for GrpcUserLocale {
+    async fn load_locale(&self, user_id: &str) -> Result<Option<Locale>, String> {
+        let user_info = {
+            let mut grpc_clients = self.grpc_clients.lock().await;
+            grpc_clients.get_user_info(user_id.to_string()).await.map_err(|e| e.to_string())?
+        };
+        if user_info.locale.trim().is_empty() {
+            return Ok(None);
+        }
+        // 지원하지 않는 언어는 기본 언어로 발송
+        Ok(user_info.locale.parse().ok())
+    }
+}
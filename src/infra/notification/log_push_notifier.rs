@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use crate::{application::port::out::{notification_port::NotificationPort, push_notification_port::PushNotificationPort}, domain::{notification::NotificationMessage, reminder::PushNotification}};
+
+// 알림 서비스가 설정되지 않은 경우 (로컬 개발): 발송하지 않고 내용만 표준 출력으로 기록
+pub struct LogPushNotifier;
+
+#[async_trait]
+impl PushNotificationPort for LogPushNotifier {
+    async fn send_push(&self, notification: &PushNotification) -> Result<(), String> {
+        println!(
+            "🔔 [{}] {} / {} → {:?} {:?}",
+            notification.dedup_key, notification.title, notification.body, notification.user_ids, notification.data
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NotificationPort for LogPushNotifier {
+    async fn notify(&self, message: &NotificationMessage) -> Result<(), String> {
+        println!("🔔 [{}] {} → {}: {}", message.dedup_key, message.user_id, message.title, message.body);
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod grpc_push_notifier;
+pub mod grpc_user_locale;
+pub mod log_push_notifier;
+pub mod smtp_email_notifier;
+
+pub use grpc_push_notifier::GrpcPushNotifier;
+pub use grpc_user_locale::GrpcUserLocale;
+pub use log_push_notifier::LogPushNotifier;
+pub use smtp_email_notifier::{SmtpConfig, SmtpEmailNotifier};
\ No newline at end of file
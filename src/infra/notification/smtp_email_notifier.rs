@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use lettre::{message::header::ContentType, transport::smtp::authentication::Credentials, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use crate::{application::port::out::notification_port::NotificationPort, domain::notification::NotificationMessage};
+
+// SMTP 설정
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub starttls: bool,               // false 면 평문 연결 (로컬 메일 서버용)
+    pub from: String,
+    pub recipient_template: String,   // 사용자 ID → 수신 주소 ("{user_id}@example.com")
+}
+
+// SMTP 이메일 발송
+// User 서비스가 이메일 주소를 제공하지 않으므로 수신 주소는 recipient_template 으로 만듦
+pub struct SmtpEmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: String,
+    recipient_template: String,
+}
+
+impl SmtpEmailNotifier {
+    pub fn new(config: SmtpConfig) -> Result<Self, String> {
+        if !config.recipient_template.contains("{user_id}") {
+            return Err("recipient_template 에 {user_id} 가 포함되어야 합니다.".to_string());
+        }
+        config.from.parse::<lettre::message::Mailbox>().map_err(|e| format!("잘못된 발신 주소입니다: {}", e))?;
+
+        let mut builder = if config.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host).map_err(|e| e.to_string())?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+        };
+        builder = builder.port(config.port);
+        if let (Some(username), Some(password)) = (config.username, config.password) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self { transport: builder.build(), from: config.from, recipient_template: config.recipient_template })
+    }
+}
+
+#[async_trait]
+impl NotificationPort for SmtpEmailNotifier {
+    async fn notify(&self, message: &NotificationMessage) -> Result<(), String> {
+        let recipient = self.recipient_template.replace("{user_id}", &message.user_id);
+        let email = Message::builder()
+            .from(self.from.parse().map_err(|e| format!("잘못된 발신 주소입니다: {}", e))?)
+            .to(recipient.parse().map_err(|e| format!("잘못된 수신 주소입니다: {} ({})", recipient, e))?)
+            .subject(&message.title)
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body.clone())
+            .map_err(|e| e.to_string())?;
+
+        self.transport.send(email).await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+use crate::{application::port::out::payment_port::PaymentPort, common::{clock::Clock, webhook_signature}, domain::payment::{PaymentCallback, PaymentCheckout, PaymentRequest, PaymentResult}};
+
+const SIGNATURE_HEADER: &str = "X-Mock-Payment-Signature";
+const SIGNATURE_TOLERANCE_SECS: i64 = 5 * 60;
+
+// 콜백 payload (예: {"payment_id": "mock_12", "status": "PAID", "amount": 30000} / {"payment_id": "mock_12", "status": "FAILED", "reason": "한도 초과"})
+#[derive(Debug, Deserialize)]
+struct MockCallbackPayload {
+    payment_id: String,
+    status: String,
+    amount: Option<i64>,
+    reason: Option<String>,
+}
+
+// 게이트웨이 측 결제 기록 (프로세스 메모리, 재시작하면 초기화)
+struct MockCharge {
+    amount: i64,
+    refunded: i64,
+}
+
+// 로컬 개발 / 테스트용 결제 게이트웨이: 실제 결제 없이 결제창 ID 만 발급하고, 결제 결과는 콜백 API 로 직접 전달
+// 콜백은 웹훅과 같은 형식의 서명("t=..,v1=..")이 있어야 받음
+pub struct MockPaymentGateway {
+    callback_secret: String,
+    clock: Arc<dyn Clock>,
+    charges: Mutex<HashMap<String, MockCharge>>,
+}
+
+impl MockPaymentGateway {
+    pub fn new(callback_secret: &str, clock: Arc<dyn Clock>) -> Result<Self, String> {
+        if callback_secret.trim().len() < 32 {
+            return Err("결제 콜백 서명 키는 32자 이상이어야 합니다.".to_string());
+        }
+        Ok(Self { callback_secret: callback_secret.to_string(), clock, charges: Mutex::new(HashMap::new()) })
+    }
+}
+
+#[async_trait]
+impl PaymentPort for MockPaymentGateway {
+    fn provider(&self) -> &'static str {
+        "mock"
+    }
+
+    fn signature_header(&self) -> &'static str {
+        SIGNATURE_HEADER
+    }
+
+    // 같은 결제 ID 로 다시 요청하면 같은 결제창 반환
+    async fn create_checkout(&self, request: &PaymentRequest) -> Result<PaymentCheckout, String> {
+        let provider_payment_id = format!("mock_{}", request.payment_id);
+        self.charges
+            .lock()
+            .map_err(|e| e.to_string())?
+            .entry(provider_payment_id.clone())
+            .or_insert(MockCharge { amount: request.amount, refunded: 0 });
+        println!(
+            "💳 [mock] 결제창 생성: {} (예약 ID: {}, 사용자: {}, {} {})",
+            provider_payment_id, request.reservation_id, request.user_id, request.amount, request.currency
+        );
+        Ok(PaymentCheckout {
+            checkout_url: format!("mock://checkout/{}", provider_payment_id),
+            provider_payment_id,
+        })
+    }
+
+    // 결제 금액을 넘는 환불은 거부, 이미 환불된 결제는 다시 환불하지 않음
+    async fn refund(&self, provider_payment_id: &str, amount: i64) -> Result<(), String> {
+        let mut charges = self.charges.lock().map_err(|e| e.to_string())?;
+        match charges.get_mut(provider_payment_id) {
+            Some(charge) if charge.refunded > 0 => {
+                println!("💳 [mock] 이미 환불된 결제: {} ({})", provider_payment_id, charge.refunded);
+            },
+            Some(charge) if amount > charge.amount => {
+                return Err(format!("환불 금액이 결제 금액을 초과합니다. (결제 {}, 환불 요청 {})", charge.amount, amount));
+            },
+            Some(charge) => {
+                charge.refunded = amount;
+                println!("💳 [mock] 환불 완료: {} ({})", provider_payment_id, amount);
+            },
+            // 재시작 전에 생성된 결제는 기록이 없으므로 환불된 것으로 처리
+            None => println!("💳 [mock] 기록 없는 결제 환불: {} ({})", provider_payment_id, amount),
+        }
+        Ok(())
+    }
+
+    fn parse_callback(&self, body: &str, signature: Option<&str>) -> Result<PaymentCallback, String> {
+        let signature = signature.ok_or(format!("{} 헤더가 없습니다.", SIGNATURE_HEADER))?;
+        if !webhook_signature::verify(&self.callback_secret, signature, body, self.clock.now(), SIGNATURE_TOLERANCE_SECS) {
+            return Err("결제 콜백 서명이 올바르지 않습니다.".to_string());
+        }
+
+        let payload: MockCallbackPayload = serde_json::from_str(body).map_err(|e| format!("결제 콜백 형식이 올바르지 않습니다: {}", e))?;
+        let result = match payload.status.as_str() {
+            "PAID" if payload.amount.is_none() => return Err("결제 완료 콜백에 amount 가 없습니다.".to_string()),
+            "PAID" => PaymentResult::Paid,
+            "FAILED" => PaymentResult::Failed(payload.reason.unwrap_or_else(|| "결제 실패".to_string())),
+            other => return Err(format!("알 수 없는 결제 상태입니다: {}", other)),
+        };
+        Ok(PaymentCallback { provider_payment_id: payload.payment_id, amount: payload.amount, result })
+    }
+}
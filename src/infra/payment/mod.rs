@@ -0,0 +1,3 @@
+pub mod mock_payment_gateway;
+
+pub use mock_payment_gateway::MockPaymentGateway;
\ No newline at end of file
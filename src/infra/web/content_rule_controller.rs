@@ -0,0 +1,77 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::application::port::r#in::content_rule_usecase::ContentRuleUseCase;
+use crate::dto::content_rule_dto::{ContentRuleDTO, UpdateContentRuleRequest};
+use crate::grpc_client::GrpcClients;
+use crate::common::valid::{validate_staff_token, StaffRoles};
+
+#[derive(Clone)]
+pub struct ContentRuleController {
+    use_case: Arc<dyn ContentRuleUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+    roles: Arc<StaffRoles>,
+}
+
+impl ContentRuleController {
+    pub fn new(
+        use_case: Arc<dyn ContentRuleUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>,
+        roles: Arc<StaffRoles>,
+    ) -> Self {
+        Self { use_case, grpc_clients, roles }
+    }
+
+    // /reservation/admin/contents/{content_id}/rules - 컨텐츠 예약 규칙 조회
+    pub async fn show_content_rule(
+        controller: web::Data<Arc<ContentRuleController>>,
+        content_id: web::Path<u64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_content_rule(content_id.into_inner()).await {
+            Ok(rule) => HttpResponse::Ok().json(ContentRuleDTO::from(rule)),
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/contents/{content_id}/rules - 컨텐츠 예약 규칙 수정
+    pub async fn update_content_rule(
+        controller: web::Data<Arc<ContentRuleController>>,
+        content_id: web::Path<u64>,
+        req: web::Json<UpdateContentRuleRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let user_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        let content_id = content_id.into_inner();
+        println!("컨텐츠 예약 규칙 수정 요청: content_id={}, user_id={}", content_id, user_id);
+
+        let rule = match req.into_inner().into_rule(content_id) {
+            Ok(rule) => rule,
+            Err(e) => return HttpResponse::BadRequest().json(format!("규칙 수정 실패: {}", e)),
+        };
+        match controller.use_case.update_content_rule(rule).await {
+            Ok(_) => HttpResponse::Ok().json("컨텐츠 예약 규칙이 수정되었습니다."),
+            Err(e) => HttpResponse::BadRequest().json(format!("규칙 수정 실패: {}", e)),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::application::port::r#in::group_booking_usecase::GroupBookingUseCase;
+use crate::dto::group_booking_dto::{GroupQuotaDTO, GroupQuotaRequest, GroupReservationDTO, GroupReservationQuery, GroupReservationRequest, GroupReviewRequest, OrganisationDTO, OrganisationMemberRequest, OrganisationRequest};
+use crate::grpc_client::GrpcClients;
+use crate::common::valid::{validate_staff_token, validate_user_token, StaffRoles};
+
+#[derive(Clone)]
+pub struct GroupBookingController {
+    use_case: Arc<dyn GroupBookingUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+    roles: Arc<StaffRoles>,
+}
+
+impl GroupBookingController {
+    pub fn new(
+        use_case: Arc<dyn GroupBookingUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>,
+        roles: Arc<StaffRoles>,
+    ) -> Self {
+        Self { use_case, grpc_clients, roles }
+    }
+
+    // /reservation/admin/organisations - 단체 계정 등록
+    pub async fn create_organisation(
+        controller: web::Data<Arc<GroupBookingController>>,
+        req: web::Json<OrganisationRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        let org_type = match req.parse_org_type() {
+            Ok(org_type) => org_type,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+
+        match controller.use_case.create_organisation(req.name.clone(), org_type, req.max_party_size).await {
+            Ok(organisation) => HttpResponse::Created().json(OrganisationDTO::from(organisation)),
+            Err(e) => HttpResponse::BadRequest().json(format!("단체 등록 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/organisations/{id}/members - 단체 소속 사용자 등록
+    pub async fn add_organisation_member(
+        controller: web::Data<Arc<GroupBookingController>>,
+        organisation_id: web::Path<i64>,
+        req: web::Json<OrganisationMemberRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.add_organisation_member(organisation_id.into_inner(), &req.user_id).await {
+            Ok(_) => HttpResponse::Ok().json("단체 소속 사용자가 등록되었습니다."),
+            Err(e) => HttpResponse::BadRequest().json(format!("단체 소속 등록 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/schedules/{id}/group-quota - 회차별 단체 좌석 조회
+    pub async fn show_group_quota(
+        controller: web::Data<Arc<GroupBookingController>>,
+        schedule_id: web::Path<u64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_group_quota(schedule_id.into_inner()).await {
+            Ok(quota) => HttpResponse::Ok().json(GroupQuotaDTO::from(quota)),
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/schedules/{id}/group-quota - 회차별 단체 좌석 설정
+    pub async fn update_group_quota(
+        controller: web::Data<Arc<GroupBookingController>>,
+        schedule_id: web::Path<u64>,
+        req: web::Json<GroupQuotaRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.update_group_quota(schedule_id.into_inner(), req.quota_seats).await {
+            Ok(quota) => HttpResponse::Ok().json(GroupQuotaDTO::from(quota)),
+            Err(e) => HttpResponse::BadRequest().json(format!("단체 좌석 설정 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/groups - 단체 예약 목록 (status=REQUESTED 로 승인 대기 조회)
+    pub async fn show_group_reservations(
+        controller: web::Data<Arc<GroupBookingController>>,
+        query: web::Query<GroupReservationQuery>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        let status = match query.parse_status() {
+            Ok(status) => status,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+
+        match controller.use_case.show_group_reservations(status, query.limit).await {
+            Ok(groups) => {
+                let group_dtos: Vec<GroupReservationDTO> = groups.into_iter().map(GroupReservationDTO::from).collect();
+                HttpResponse::Ok().json(group_dtos)
+            },
+            Err(e) => HttpResponse::BadRequest().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/groups/{id}/approve - 단체 예약 승인 (회차별 예약 생성)
+    pub async fn approve_group_reservation(
+        controller: web::Data<Arc<GroupBookingController>>,
+        group_reservation_id: web::Path<i64>,
+        req: Option<web::Json<GroupReviewRequest>>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let user_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        let note = req.and_then(|req| req.into_inner().note);
+        match controller.use_case.approve_group_reservation(group_reservation_id.into_inner(), &user_id, note).await {
+            Ok(group) => HttpResponse::Ok().json(GroupReservationDTO::from(group)),
+            Err(e) => HttpResponse::Conflict().json(format!("단체 예약 승인 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/groups/{id}/reject - 단체 예약 반려 (단체 좌석 반환)
+    pub async fn reject_group_reservation(
+        controller: web::Data<Arc<GroupBookingController>>,
+        group_reservation_id: web::Path<i64>,
+        req: Option<web::Json<GroupReviewRequest>>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let user_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        let note = req.and_then(|req| req.into_inner().note);
+        match controller.use_case.reject_group_reservation(group_reservation_id.into_inner(), &user_id, note).await {
+            Ok(group) => HttpResponse::Ok().json(GroupReservationDTO::from(group)),
+            Err(e) => HttpResponse::Conflict().json(format!("단체 예약 반려 실패: {}", e)),
+        }
+    }
+
+    // /reservation/groups - 단체 예약 신청 (직원 승인 후 확정)
+    pub async fn request_group_reservation(
+        controller: web::Data<Arc<GroupBookingController>>,
+        req: web::Json<GroupReservationRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        println!("단체 예약 신청 요청: user_id={}, 회차 {}개", user_id, req.items.len());
+        match controller.use_case.request_group_reservation(&user_id, req.to_items(), req.note.clone()).await {
+            Ok(group) => HttpResponse::Created().json(GroupReservationDTO::from(group)),
+            Err(e) => HttpResponse::BadRequest().json(format!("단체 예약 신청 실패: {}", e)),
+        }
+    }
+
+    // /reservation/groups - 소속 단체의 단체 예약 목록
+    pub async fn show_organisation_group_reservations(
+        controller: web::Data<Arc<GroupBookingController>>,
+        query: web::Query<GroupReservationQuery>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.show_organisation_group_reservations(&user_id, query.limit).await {
+            Ok(groups) => {
+                let group_dtos: Vec<GroupReservationDTO> = groups.into_iter().map(GroupReservationDTO::from).collect();
+                HttpResponse::Ok().json(group_dtos)
+            },
+            Err(e) => HttpResponse::BadRequest().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/groups/{id} - 단체 예약 조회
+    pub async fn show_group_reservation(
+        controller: web::Data<Arc<GroupBookingController>>,
+        group_reservation_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.show_group_reservation(&user_id, group_reservation_id.into_inner()).await {
+            Ok(group) => HttpResponse::Ok().json(GroupReservationDTO::from(group)),
+            Err(e) => HttpResponse::NotFound().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/groups/{id}/cancellation - 단체 예약 취소 (생성된 예약도 함께 취소)
+    pub async fn cancel_group_reservation(
+        controller: web::Data<Arc<GroupBookingController>>,
+        group_reservation_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.cancel_group_reservation(&user_id, group_reservation_id.into_inner()).await {
+            Ok(group) => HttpResponse::Ok().json(GroupReservationDTO::from(group)),
+            Err(e) => HttpResponse::BadRequest().json(format!("단체 예약 취소 실패: {}", e)),
+        }
+    }
+}
\ No newline at end of file
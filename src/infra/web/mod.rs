@@ -1,4 +1,16 @@
 pub mod reservation_controller;
+pub mod content_rule_controller;
+pub mod webhook_controller;
+pub mod group_booking_controller;
+pub mod transfer_controller;
+pub mod pricing_controller;
+pub mod payment_controller;
 pub mod routes;
 
-pub use reservation_controller::ReservationController;
\ No newline at end of file
+pub use reservation_controller::ReservationController;
+pub use content_rule_controller::ContentRuleController;
+pub use webhook_controller::WebhookController;
+pub use group_booking_controller::GroupBookingController;
+pub use transfer_controller::TransferController;
+pub use pricing_controller::PricingController;
+pub use payment_controller::PaymentController;
\ No newline at end of file
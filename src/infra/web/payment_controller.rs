@@ -0,0 +1,90 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::application::port::r#in::payment_usecase::PaymentUseCase;
+use crate::dto::payment_dto::{PaymentDTO, StartPaymentRequest};
+use crate::grpc_client::GrpcClients;
+use crate::common::valid::validate_user_token;
+
+#[derive(Clone)]
+pub struct PaymentController {
+    use_case: Arc<dyn PaymentUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+}
+
+impl PaymentController {
+    pub fn new(
+        use_case: Arc<dyn PaymentUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>
+    ) -> Self {
+        Self { use_case, grpc_clients }
+    }
+
+    // /reservation/payments - 결제 대기 예약의 결제창 요청
+    pub async fn start_payment(
+        controller: web::Data<Arc<PaymentController>>,
+        req: web::Json<StartPaymentRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        println!("결제 요청: reservation_id={}, user_id={}", req.reservation_id, user_id);
+
+        match controller.use_case.start_payment(&user_id, req.reservation_id).await {
+            Ok(payment) => HttpResponse::Ok().json(PaymentDTO::from(payment)),
+            Err(e) => HttpResponse::BadRequest().json(format!("결제 요청 실패: {}", e)),
+        }
+    }
+
+    // /reservation/{id}/payment - 예약의 최근 결제 조회
+    pub async fn show_payment(
+        controller: web::Data<Arc<PaymentController>>,
+        reservation_id: web::Path<i32>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.show_payment(&user_id, reservation_id.into_inner()).await {
+            Ok(payment) => HttpResponse::Ok().json(PaymentDTO::from(payment)),
+            Err(e) => HttpResponse::NotFound().json(format!("결제 조회 실패: {}", e)),
+        }
+    }
+
+    // /reservation/payments/callback - 결제 게이트웨이 결과 통보 (토큰 대신 게이트웨이 서명으로 검증)
+    pub async fn payment_callback(
+        controller: web::Data<Arc<PaymentController>>,
+        body: String,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let signature = http_req
+            .headers()
+            .get(controller.use_case.callback_signature_header())
+            .and_then(|value| value.to_str().ok());
+
+        match controller.use_case.handle_callback(&body, signature).await {
+            Ok(_) => HttpResponse::Ok().json("결제 결과가 반영되었습니다."),
+            Err(e) => {
+                eprintln!("결제 콜백 처리 실패: {}", e);
+                HttpResponse::BadRequest().json(format!("결제 콜백 처리 실패: {}", e))
+            }
+        }
+    }
+}
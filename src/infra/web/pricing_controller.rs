@@ -0,0 +1,229 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::application::port::r#in::pricing_usecase::PricingUseCase;
+use crate::dto::pricing_dto::{ContentPriceDTO, DiscountDTO, DiscountRequest, MembershipRequest, PriceQuoteDTO, QuoteRequest, UpdateContentPricesRequest};
+use crate::grpc_client::GrpcClients;
+use crate::common::valid::{validate_staff_token, validate_user_token, StaffRoles};
+
+#[derive(Clone)]
+pub struct PricingController {
+    use_case: Arc<dyn PricingUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+    roles: Arc<StaffRoles>,
+}
+
+impl PricingController {
+    pub fn new(
+        use_case: Arc<dyn PricingUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>,
+        roles: Arc<StaffRoles>,
+    ) -> Self {
+        Self { use_case, grpc_clients, roles }
+    }
+
+    // /reservation/quote - 예약 전 금액 견적
+    pub async fn quote(
+        controller: web::Data<Arc<PricingController>>,
+        req: web::Json<QuoteRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        let party = match req.party() {
+            Ok(party) => party,
+            Err(e) => return HttpResponse::BadRequest().json(format!("견적 실패: {}", e)),
+        };
+
+        match controller.use_case.quote(&user_id, req.content_schedule_id, &party).await {
+            Ok(quote) => HttpResponse::Ok().json(PriceQuoteDTO::from(quote)),
+            Err(e) => HttpResponse::BadRequest().json(format!("견적 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/contents/{content_id}/prices - 컨텐츠 권종별 단가 조회
+    pub async fn show_content_prices(
+        controller: web::Data<Arc<PricingController>>,
+        content_id: web::Path<u64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_content_prices(content_id.into_inner()).await {
+            Ok(prices) => {
+                let dtos: Vec<ContentPriceDTO> = prices.into_iter().map(ContentPriceDTO::from).collect();
+                HttpResponse::Ok().json(dtos)
+            }
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/contents/{content_id}/prices - 컨텐츠 권종별 단가 수정 (전체 교체)
+    pub async fn update_content_prices(
+        controller: web::Data<Arc<PricingController>>,
+        content_id: web::Path<u64>,
+        req: web::Json<UpdateContentPricesRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let user_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        let content_id = content_id.into_inner();
+        println!("컨텐츠 단가 수정 요청: content_id={}, user_id={}", content_id, user_id);
+
+        let prices = match req.into_inner().into_prices() {
+            Ok(prices) => prices,
+            Err(e) => return HttpResponse::BadRequest().json(format!("단가 수정 실패: {}", e)),
+        };
+        match controller.use_case.update_content_prices(content_id, prices).await {
+            Ok(_) => HttpResponse::Ok().json("컨텐츠 단가가 수정되었습니다."),
+            Err(e) => HttpResponse::BadRequest().json(format!("단가 수정 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/discounts - 할인 규칙 목록
+    pub async fn show_discounts(
+        controller: web::Data<Arc<PricingController>>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_discounts().await {
+            Ok(discounts) => {
+                let dtos: Vec<DiscountDTO> = discounts.into_iter().map(DiscountDTO::from).collect();
+                HttpResponse::Ok().json(dtos)
+            }
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/discounts - 할인 규칙 등록
+    pub async fn create_discount(
+        controller: web::Data<Arc<PricingController>>,
+        req: web::Json<DiscountRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        let discount = match req.into_inner().into_discount() {
+            Ok(discount) => discount,
+            Err(e) => return HttpResponse::BadRequest().json(format!("할인 등록 실패: {}", e)),
+        };
+        match controller.use_case.create_discount(discount).await {
+            Ok(discount) => HttpResponse::Created().json(DiscountDTO::from(discount)),
+            Err(e) => HttpResponse::BadRequest().json(format!("할인 등록 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/discounts/{id}/deactivate - 할인 규칙 비활성화
+    pub async fn deactivate_discount(
+        controller: web::Data<Arc<PricingController>>,
+        discount_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.deactivate_discount(discount_id.into_inner()).await {
+            Ok(_) => HttpResponse::Ok().json("할인 규칙이 비활성화되었습니다."),
+            Err(e) => HttpResponse::NotFound().json(format!("할인 비활성화 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/members/{user_id} - 회원 할인 대상 등록 / 만료일 변경
+    pub async fn register_member(
+        controller: web::Data<Arc<PricingController>>,
+        member_user_id: web::Path<String>,
+        req: web::Json<MembershipRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        let expires_at = match req.expires_at() {
+            Ok(expires_at) => expires_at,
+            Err(e) => return HttpResponse::BadRequest().json(format!("회원 등록 실패: {}", e)),
+        };
+        match controller.use_case.register_member(&member_user_id, expires_at).await {
+            Ok(_) => HttpResponse::Ok().json("회원 할인 대상으로 등록되었습니다."),
+            Err(e) => HttpResponse::BadRequest().json(format!("회원 등록 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/members/{user_id} - 회원 할인 대상 해제
+    pub async fn remove_member(
+        controller: web::Data<Arc<PricingController>>,
+        member_user_id: web::Path<String>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.remove_member(&member_user_id).await {
+            Ok(_) => HttpResponse::Ok().json("회원 할인 대상에서 해제되었습니다."),
+            Err(e) => HttpResponse::NotFound().json(format!("회원 해제 실패: {}", e)),
+        }
+    }
+}
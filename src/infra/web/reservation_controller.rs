@@ -1,15 +1,27 @@
-use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use actix_web::{http::StatusCode, web, HttpRequest, HttpResponse, Responder};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use chrono::{DateTime, Utc, ParseError};
-use crate::application::port::r#in::reservation_usecase::ReservationUseCase;
+use crate::application::port::r#in::{idempotency_usecase::IdempotencyUseCase, reservation_usecase::ReservationUseCase};
+use crate::common::page::PageRequest;
+use crate::domain::check_in::CheckInOutcome;
+use crate::domain::idempotency::{IdempotencyClaim, IdempotentResponse};
+use crate::domain::policy_violation::BookingOutcome;
 use crate::domain::reservation::{Reservation, ReservationStatus};
+use crate::domain::reservation_history::{AuditChannel, AuditContext};
+use crate::dto::check_in_dto::CheckInRequest;
 use crate::dto::create_reservation_dto::CreateReservationRequest;
 use crate::dto::update_reservation_dto::UpdateReservationRequest;
+use crate::dto::move_reservation_dto::MoveReservationRequest;
+use crate::dto::page_dto::{PageDTO, PageQuery};
 use crate::dto::reservation_response_dto::ReservationDTO;
+use crate::dto::reservation_history_dto::ReservationHistoryDTO;
+use crate::dto::reservation_query_dto::{ReservationFilter, ReservationQuery};
 use crate::dto::update_status_dto::UpdateStatusRequest;
+use crate::dto::schedule_availability_dto::ScheduleAvailabilityDTO;
+use crate::dto::schedule_filter_dto::{ScheduleAvailabilityFilter, ScheduleAvailabilityQuery};
 use crate::grpc_client::GrpcClients;
-use crate::common::valid::validate_user_token;
+use crate::common::valid::{validate_scanner_token, validate_staff_token, validate_user_token, StaffRoles};
 use crate::r#struct::user_param::UserParams;
 
 // `String` → `DateTime<Utc>` 변환 함수
@@ -20,15 +32,79 @@ fn parse_datetime_from_string(datetime_str: &str) -> Result<DateTime<Utc>, Parse
 #[derive(Clone)]
 pub struct ReservationController {
     use_case: Arc<dyn ReservationUseCase + Send + Sync>,
+    idempotency: Arc<dyn IdempotencyUseCase + Send + Sync>,
     grpc_clients: Arc<Mutex<GrpcClients>>, 
+    roles: Arc<StaffRoles>,
 }
 
 impl ReservationController {
     pub fn new(
         use_case: Arc<dyn ReservationUseCase + Send + Sync>, 
-        grpc_clients: Arc<Mutex<GrpcClients>> 
+        idempotency: Arc<dyn IdempotencyUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>,
+        roles: Arc<StaffRoles>,
     ) -> Self {
-        Self { use_case, grpc_clients }
+        Self { use_case, idempotency, grpc_clients, roles }
+    }
+
+    // 예약 가능 여부 확인 후 예약 생성 (일반 / 수동 예약 공통)
+    async fn reserve(
+        controller: &ReservationController,
+        user_id: String,
+        req: &CreateReservationRequest,
+        audit: AuditContext,
+    ) -> (StatusCode, String) {
+        let party = match req.party() {
+            Ok(party) => party,
+            Err(e) => return (StatusCode::BAD_REQUEST, format!("예약 불가: {}", e)),
+        };
+
+        /* userId로 User-service로 통신해서 User 정보 가져오기*/
+        let user_info = {
+            let mut grpc_clients = controller.grpc_clients.lock().await;
+            match grpc_clients.get_user_info(user_id.clone()).await {
+                Ok(user) => {
+                    println!("User-Service returned user info: {:?}", user);
+                    user
+                },
+                Err(err) => {
+                    println!("gRPC call to User-Service failed: {}", err);
+                    return (StatusCode::INTERNAL_SERVER_ERROR, "User Service Error".to_string());
+                }
+            }
+        };
+
+        // 예약 객체 생성
+        let reservation = Reservation {
+            id: 0,
+            user_id, 
+            content_schedule_id: req.content_schedule_id,
+            reserved_at: None,
+            ad_cnt: party.adults(),
+            cd_cnt: party.children(),
+            status: Some(ReservationStatus::Pending),
+            use_at: false,
+            checked_in_ad_cnt: 0,
+            checked_in_cd_cnt: 0,
+            group_reservation_id: None,
+            payment_due_at: None,
+            party,
+            price: None, // 예약 생성 시 서비스에서 산정
+            schedule: None,
+            flags: None,
+            ticket_token: None,
+            version: None,
+        };
+
+        // 예약 생성 처리 (예약 정책 검사 포함)
+        match controller.use_case.create_reservation(reservation, user_info.ad_cnt, user_info.cd_cnt, &audit).await {
+            Ok(BookingOutcome::Created) => (StatusCode::CREATED, "예약이 성공적으로 생성되었습니다.".to_string()),
+            Ok(BookingOutcome::Rejected(violations)) => {
+                let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+                (StatusCode::BAD_REQUEST, format!("예약 불가: {}", reasons.join(", ")))
+            },
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("예약 생성 실패: {}", e)),
+        }
     }
 
     // 예약 생성
@@ -44,61 +120,56 @@ impl ReservationController {
         };
     
         // gRPC를 사용하여 AuthService에 토큰 검증 요청
-        let mut grpc_clients = controller.grpc_clients.lock().await;
-        let user_id = match grpc_clients.validate_token(token).await {
-            Ok(Some(user_id)) => {
-                println!("Received user_id from gRPC: {}", user_id);
-                user_id
-            },
-            Ok(None) => {
-                println!("gRPC returned None for user_id!");
-                return HttpResponse::Unauthorized().json("Invalid Token");
-            },
-            Err(err) => {
-                println!("gRPC call failed: {}", err);
-                return HttpResponse::InternalServerError().json("Auth Service Error");
-            }
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
         };
         println!("Received user_id from Auth Service: {}", user_id);
-        /* userId로 User-service로 통신해서 User 정보 가져오기*/
-        let user_info = match grpc_clients.get_user_info(user_id.clone()).await {
-            Ok(user) => {
-                println!("User-Service returned user info: {:?}", user);
-                user
-            },
-            Err(err) => {
-                println!("gRPC call to User-Service failed: {}", err);
-                return HttpResponse::InternalServerError().json("User Service Error");
-            }
+
+        // Idempotency-Key 헤더가 없으면 바로 예약 처리
+        let idempotency_key = http_req.headers()
+            .get("Idempotency-Key")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let Some(key) = idempotency_key else {
+            let audit = AuditContext::new(Some(user_id.clone()), AuditChannel::Rest);
+            let (status, message) = Self::reserve(&controller, user_id, &req, audit).await;
+            return HttpResponse::build(status).json(message);
         };
-        // 예약 가능 여부 확인
-        let is_available = match controller.use_case.check_reservation(user_id.clone(), req.content_schedule_id, req.ad_cnt,req.cd_cnt, user_info.ad_cnt,user_info.cd_cnt).await {
-            Ok(true) => true,
-            Ok(false) => return HttpResponse::BadRequest().json("🚫 예약 불가: 인원 초과 또는 중복 예약 불가"),
-            Err(e) => return HttpResponse::InternalServerError().json(format!("예약 검증 실패: {}", e)),
+
+        // 동일 키 재시도 → 최초 요청의 응답 반환 (인원은 권종 구성 기준, 잘못된 요청은 키를 잡지 않고 바로 응답)
+        let party = match req.party() {
+            Ok(party) => party,
+            Err(e) => return HttpResponse::BadRequest().json(format!("예약 불가: {}", e)),
         };
-    
-        // 예약 객체 생성
-        if is_available {
-            let reservation = Reservation {
-                id: 0,
-                user_id, 
-                content_schedule_id: req.content_schedule_id,
-                reserved_at: None,
-                ad_cnt: req.ad_cnt,
-                cd_cnt: req.cd_cnt,
-                status: Some(ReservationStatus::Pending),
-                use_at: false,
-            };
-    
-            // 예약 생성 처리
-            match controller.use_case.create_reservation(reservation).await {
-                Ok(_) => HttpResponse::Created().json("예약이 성공적으로 생성되었습니다."),
-                Err(e) => HttpResponse::InternalServerError().json(format!("예약 생성 실패: {}", e)),
-            }
+        let fingerprint = format!("{}:{}", req.content_schedule_id, party);
+        match controller.idempotency.begin(&user_id, &key, &fingerprint).await {
+            Ok(IdempotencyClaim::Acquired) => {},
+            Ok(IdempotencyClaim::Replay(response)) => {
+                println!("멱등성 키 재사용 → 저장된 응답 반환: {}", key);
+                let status = StatusCode::from_u16(response.status_code).unwrap_or(StatusCode::OK);
+                return HttpResponse::build(status).json(response.message);
+            },
+            Ok(IdempotencyClaim::InProgress) => return HttpResponse::Conflict().json("동일한 예약 요청이 처리 중입니다."),
+            Ok(IdempotencyClaim::Mismatch) => return HttpResponse::UnprocessableEntity().json("Idempotency-Key가 다른 요청에 이미 사용되었습니다."),
+            Err(e) => return HttpResponse::BadRequest().json(format!("Idempotency-Key 처리 실패: {}", e)),
+        }
+
+        let audit = AuditContext::new(Some(user_id.clone()), AuditChannel::Rest);
+        let (status, message) = Self::reserve(&controller, user_id.clone(), &req, audit).await;
+
+        // 성공한 응답만 저장하고, 실패 시 키를 해제하여 같은 키로 재시도 가능하게 함
+        let saved = if status.is_success() {
+            controller.idempotency.complete(&user_id, &key, IdempotentResponse { status_code: status.as_u16(), message: message.clone() }).await
         } else {
-            HttpResponse::BadRequest().json("예약 불가: 인원 초과 또는 중복 예약 불가")
+            controller.idempotency.release(&user_id, &key).await
+        };
+        if let Err(e) = saved {
+            println!("멱등성 키 상태 저장 실패: {}", e);
         }
+
+        HttpResponse::build(status).json(message)
     }
 
     //수동 예약
@@ -106,57 +177,32 @@ impl ReservationController {
         controller: web::Data<Arc<ReservationController>>,
         req: web::Json<CreateReservationRequest>,
         path: web::Path<UserParams>,
-        // http_req: HttpRequest, 
+        http_req: HttpRequest, 
     ) -> impl Responder {
+        // 헤더에서 JWT 가져오기
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let staff_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(staff_id) => staff_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
 
         let user_id = path.user_id.clone();
 
-        // gRPC를 사용하여 AuthService에 토큰 검증 요청
-        let mut grpc_clients = controller.grpc_clients.lock().await;
-    
-        /* userId로 User-service로 통신해서 User 정보 가져오기*/
-        let user_info = match grpc_clients.get_user_info(user_id.clone()).await {
-            Ok(user) => {
-                println!("User-Service returned user info: {:?}", user);
-                user
-            },
-            Err(err) => {
-                println!("gRPC call to User-Service failed: {}", err);
-                return HttpResponse::InternalServerError().json("User Service Error");
-            }
-        };
-        // 예약 가능 여부 확인
-        let is_available = match controller.use_case.check_reservation(user_id.clone(), req.content_schedule_id, req.ad_cnt,req.cd_cnt, user_info.ad_cnt,user_info.cd_cnt).await {
-            Ok(true) => true,
-            Ok(false) => return HttpResponse::BadRequest().json("예약 불가: 인원 초과 또는 중복 예약 불가"),
-            Err(e) => return HttpResponse::InternalServerError().json(format!("예약 검증 실패: {}", e)),
-        };
-    
-        // 예약 객체 생성
-        if is_available {
-            let reservation = Reservation {
-                id: 0,
-                user_id, 
-                content_schedule_id: req.content_schedule_id,
-                reserved_at: None,
-                ad_cnt: req.ad_cnt,
-                cd_cnt: req.cd_cnt,
-                status: Some(ReservationStatus::Pending),
-                use_at: false,
-            };
-    
-            // 예약 생성 처리
-            match controller.use_case.create_reservation(reservation).await {
-                Ok(_) => HttpResponse::Created().json("예약이 성공적으로 생성되었습니다."),
-                Err(e) => HttpResponse::InternalServerError().json(format!("예약 생성 실패: {}", e)),
-            }
-        } else {
-            HttpResponse::BadRequest().json("예약 불가: 인원 초과 또는 중복 예약 불가")
-        }
+        // 등록한 직원을 요청자로 기록
+        let audit = AuditContext::new(Some(staff_id), AuditChannel::Manual);
+        let (status, message) = Self::reserve(&controller, user_id, &req, audit).await;
+        HttpResponse::build(status).json(message)
     }
 
+    // /reservation/user?limit=..&cursor=.. (limit / cursor 가 없으면 전체 목록 배열)
     pub async fn show_user_reservations(
         controller: web::Data<Arc<ReservationController>>,
+        page_query: web::Query<PageQuery>,
         http_req: HttpRequest, 
     )-> impl Responder {
         // 헤더에서 JWT 가져오기
@@ -172,19 +218,26 @@ impl ReservationController {
         };
 
         println!("Received user_id from Auth Service: {}", user_id);
+
+        let page = match page_query.page_request() {
+            Ok(page) => page,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+        let paged = page.is_some();
        
-        match controller.use_case.show_user_reservations(&user_id).await {
-            Ok(reservations) => {
-                let reservation_dtos: Vec<ReservationDTO> = reservations.into_iter().map(ReservationDTO::from).collect(); 
-                HttpResponse::Ok().json(reservation_dtos) // JSON 변환 가능
-            },
+        match controller.use_case.show_user_reservations(&user_id, page.unwrap_or_else(PageRequest::unbounded)).await {
+            Ok(reservations) if paged => HttpResponse::Ok().json(PageDTO::<ReservationDTO>::from(reservations)),
+            Ok(reservations) => HttpResponse::Ok().json(reservations.items.into_iter().map(ReservationDTO::from).collect::<Vec<_>>()),
             Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
         }
     }
 
-    // 당일 전체 예약확인
-    pub async fn show_today_reservations(
+    // /reservation?from=..&to=..&schedule_id=..&content_id=..&status=..&user_id=..&sort=..&order=..&limit=..&cursor=..
+    // 조건이 없으면 당일 전체 예약확인, limit / cursor 가 없으면 전체 목록 배열
+    pub async fn search_reservations(
         controller: web::Data<Arc<ReservationController>>,
+        query: web::Query<ReservationQuery>,
+        page_query: web::Query<PageQuery>,
         http_req: HttpRequest, 
     )-> impl Responder {
         let token = match http_req.headers().get("Authorization") {
@@ -192,28 +245,127 @@ impl ReservationController {
             None => return HttpResponse::Unauthorized().json("No Authorization Header"),
         };
     
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        let filter = match ReservationFilter::try_from(query.into_inner()) {
+            Ok(filter) => filter,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+        let page = match page_query.page_request() {
+            Ok(page) => page,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+        let paged = page.is_some();
+
+        match controller.use_case.search_reservations(filter, page.unwrap_or_else(PageRequest::unbounded)).await {
+            Ok(reservations) if paged => HttpResponse::Ok().json(PageDTO::<ReservationDTO>::from(reservations)),
+            Ok(reservations) => HttpResponse::Ok().json(reservations.items.into_iter().map(ReservationDTO::from).collect::<Vec<_>>()),
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/{id} 엔드포인트 - 본인 예약 조회 (DTO 반환, 입장 티켓 포함)
+    pub async fn show_reservation(
+        controller: web::Data<Arc<ReservationController>>,
+        reservation_id: web::Path<i32>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
         // gRPC를 사용하여 AuthService에 토큰 검증 요청
         let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
             Ok(user_id) => user_id,
             Err(response) => return response, // 오류 발생 시 바로 응답 반환
         };
-        match controller.use_case.show_today_reservations().await {
-            Ok(reservations) => {
-                let reservation_dtos: Vec<ReservationDTO> = reservations.into_iter().map(ReservationDTO::from).collect(); 
-                HttpResponse::Ok().json(reservation_dtos) // JSON 변환 가능
-            },
+
+        match controller.use_case.show_reservation(&user_id, reservation_id.into_inner()).await {
+            Ok(Some(reservation)) => HttpResponse::Ok().json(ReservationDTO::from(reservation)), 
+            Ok(None) => HttpResponse::NotFound().json("Reservation not found"),
             Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
         }
     }
 
-    // /reservation/{id} 엔드포인트 - 예약 조회 (DTO 반환)
-    pub async fn show_reservation(
+    // /reservation/{id}/history - 예약 변경 이력 조회 (직원용)
+    pub async fn show_reservation_history(
         controller: web::Data<Arc<ReservationController>>,
         reservation_id: web::Path<i32>,
+        http_req: HttpRequest,
     ) -> impl Responder {
-        match controller.use_case.show_reservation(reservation_id.into_inner()).await {
-            Ok(reservation) => HttpResponse::Ok().json(ReservationDTO::from(reservation)), 
-            Err(e) => HttpResponse::NotFound().json(format!("Error: {}", e)),
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용, 이력에 다른 사용자 ID 포함)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_reservation_history(reservation_id.into_inner()).await {
+            Ok(histories) => {
+                let history_dtos: Vec<ReservationHistoryDTO> = histories.into_iter().map(ReservationHistoryDTO::from).collect();
+                HttpResponse::Ok().json(history_dtos)
+            },
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/schedules/{id}/availability - 스케줄 잔여 좌석 조회
+    pub async fn show_schedule_availability(
+        controller: web::Data<Arc<ReservationController>>,
+        schedule_id: web::Path<u64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        // 유저 정보 가져오기 (사용자별 예약 한도)
+        let mut grpc_clients = controller.grpc_clients.lock().await;
+        let user_info = match grpc_clients.get_user_info(user_id.clone()).await {
+            Ok(info) => info,
+            Err(e) => {
+                println!("Failed to fetch user info: {}", e);
+                return HttpResponse::InternalServerError().json(format!("Failed to get user info: {}", e));
+            }
+        };
+
+        match controller.use_case.show_schedule_availability(user_id, schedule_id.into_inner(), user_info.ad_cnt, user_info.cd_cnt).await {
+            Ok(Some(availability)) => HttpResponse::Ok().json(ScheduleAvailabilityDTO::from(availability)),
+            Ok(None) => HttpResponse::NotFound().json("Schedule not found"),
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/schedules/availability - 스케줄 잔여 좌석 일괄 조회 (키오스크 홈 화면)
+    pub async fn show_schedules_availability(
+        controller: web::Data<Arc<ReservationController>>,
+        query: web::Query<ScheduleAvailabilityQuery>,
+    ) -> impl Responder {
+        let filter = match ScheduleAvailabilityFilter::try_from(query.into_inner()) {
+            Ok(filter) => filter,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+
+        match controller.use_case.show_schedules_availability(filter).await {
+            Ok(availabilities) => {
+                let availability_dtos: Vec<ScheduleAvailabilityDTO> = availabilities.into_iter().map(ScheduleAvailabilityDTO::from).collect();
+                HttpResponse::Ok().json(availability_dtos)
+            },
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
         }
     }
 
@@ -249,19 +401,22 @@ impl ReservationController {
     
         // DTO에서 필요한 정보 추출
         let reservation_id = req.reservation_id;
-        let ad_cnt = req.ad_cnt;
-        let cd_cnt = req.cd_cnt;
+        let party = match req.party() {
+            Ok(party) => party,
+            Err(e) => return HttpResponse::BadRequest().json(format!("예약 수정 실패: {}", e)),
+        };
+        let audit = AuditContext::new(Some(user_id.clone()), AuditChannel::Rest);
     
-        match controller.use_case.update_reservation(reservation_id, ad_cnt, cd_cnt,user_info.ad_cnt,user_info.cd_cnt).await {
+        match controller.use_case.update_reservation(&user_id, reservation_id, party, user_info.ad_cnt,user_info.cd_cnt, &audit).await {
             Ok(_) => HttpResponse::Ok().json("예약이 성공적으로 수정되었습니다."),
             Err(e) => HttpResponse::InternalServerError().json(format!("예약 수정 실패: {}", e)),
         }
     }
 
-    // /use - 예약 사용하기
-    pub async fn use_reservation (
+    // /move - 같은 컨텐츠의 다른 회차로 예약 이동
+    pub async fn move_reservation (
         controller: web::Data<Arc<ReservationController>>,
-        req: web::Json<UpdateStatusRequest>, 
+        req: web::Json<MoveReservationRequest>, 
         http_req: HttpRequest,
     ) -> impl Responder {
         let token = match http_req.headers().get("Authorization") {
@@ -275,12 +430,52 @@ impl ReservationController {
             Err(response) => return response, // 오류 발생 시 바로 응답 반환
         };
     
-        // DTO에서 필요한 정보 추출
-        let reservation_id = req.reservation_id;
-     
-        match controller.use_case.use_reservation(reservation_id).await {
-            Ok(_) => HttpResponse::Ok().json("티켓이 성공적으로 사용되었습니다."),
-            Err(e) => HttpResponse::InternalServerError().json(format!("예약 수정 실패: {}", e)),
+        // 유저 정보 가져오기 (사용자별 예약 한도, 본인 예약만 이동하므로 예약자 한도와 같음)
+        let user_info = {
+            let mut grpc_clients = controller.grpc_clients.lock().await;
+            match grpc_clients.get_user_info(user_id.clone()).await {
+                Ok(info) => info,
+                Err(e) => {
+                    println!("Failed to fetch user info: {}", e);
+                    return HttpResponse::InternalServerError().json(format!("Failed to get user info: {}", e));
+                }
+            }
+        };
+    
+        let audit = AuditContext::new(Some(user_id.clone()), AuditChannel::Rest);
+        match controller.use_case.move_reservation(&user_id, req.reservation_id, req.content_schedule_id, user_info.ad_cnt, user_info.cd_cnt, &audit).await {
+            Ok(_) => HttpResponse::Ok().json("예약 회차가 성공적으로 변경되었습니다."),
+            Err(e) => HttpResponse::BadRequest().json(format!("예약 이동 실패: {}", e)),
+        }
+    }
+
+    // /check-in - 서명된 티켓 스캔으로 입장 처리
+    pub async fn check_in (
+        controller: web::Data<Arc<ReservationController>>,
+        req: web::Json<CheckInRequest>, 
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+    
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (스캐너 / 직원 계정만 허용)
+        let user_id = match validate_scanner_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+        let audit = AuditContext::new(Some(user_id), AuditChannel::Rest);
+
+        match controller.use_case.check_in(&req.ticket_token, req.content_schedule_id, req.ad_cnt, req.cd_cnt, &audit).await {
+            Ok(CheckInOutcome::CheckedIn(reservation)) => HttpResponse::Ok().json(ReservationDTO::from(reservation)),
+            Ok(CheckInOutcome::InvalidTicket(e)) => HttpResponse::BadRequest().json(e),
+            Ok(CheckInOutcome::InvalidPartySize(e)) => HttpResponse::BadRequest().json(e),
+            Ok(CheckInOutcome::WrongSchedule) => HttpResponse::Conflict().json("다른 회차의 티켓입니다."),
+            Ok(CheckInOutcome::OutsideWindow(e)) => HttpResponse::Conflict().json(e),
+            Ok(CheckInOutcome::AlreadyUsed) => HttpResponse::Conflict().json("이미 사용된 티켓입니다."),
+            Ok(CheckInOutcome::Cancelled) => HttpResponse::Conflict().json("취소된 예약입니다."),
+            Err(e) => HttpResponse::InternalServerError().json(format!("입장 처리 실패: {}", e)),
         }
     }
 
@@ -303,8 +498,33 @@ impl ReservationController {
     
         // DTO에서 필요한 정보 추출
         let reservation_id = req.reservation_id;
+        let audit = AuditContext::new(Some(user_id.clone()), AuditChannel::Rest);
      
-        match controller.use_case.cancel_reservation(reservation_id).await {
+        match controller.use_case.cancel_reservation(&user_id, reservation_id, &audit).await {
+            Ok(_) => HttpResponse::Ok().json("티켓이 성공적으로 취소되었습니다."),
+            Err(e) => HttpResponse::InternalServerError().json(format!("예약 수정 실패: {}", e)),
+        }
+    }
+
+    // /admin/reservations/{id}/cancellation - 직원 예약 취소하기
+    pub async fn cancel_reservation_by_staff (
+        controller: web::Data<Arc<ReservationController>>,
+        reservation_id: web::Path<i32>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let staff_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(staff_id) => staff_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+        let audit = AuditContext::new(Some(staff_id), AuditChannel::Rest);
+
+        match controller.use_case.cancel_reservation_by_staff(reservation_id.into_inner(), &audit).await {
             Ok(_) => HttpResponse::Ok().json("티켓이 성공적으로 취소되었습니다."),
             Err(e) => HttpResponse::InternalServerError().json(format!("예약 수정 실패: {}", e)),
         }
@@ -2,20 +2,78 @@ use actix_web::web;
 use std::sync::Arc;
 use crate::state::AppState;
 use crate::infra::web::reservation_controller::ReservationController;
+use crate::infra::web::content_rule_controller::ContentRuleController;
+use crate::infra::web::webhook_controller::WebhookController;
+use crate::infra::web::group_booking_controller::GroupBookingController;
+use crate::infra::web::transfer_controller::TransferController;
+use crate::infra::web::pricing_controller::PricingController;
+use crate::infra::web::payment_controller::PaymentController;
 
 pub fn configure(cfg: &mut web::ServiceConfig, state: Arc<AppState>) {
     let controller = state.reservation_controller.clone(); //  AppState에서 컨트롤러 가져오기
+    let content_rule_controller = state.content_rule_controller.clone();
+    let webhook_controller = state.webhook_controller.clone();
+    let group_booking_controller = state.group_booking_controller.clone();
+    let transfer_controller = state.transfer_controller.clone();
+    let pricing_controller = state.pricing_controller.clone();
+    let payment_controller = state.payment_controller.clone();
 
     cfg.service(
         web::scope("/reservation")
-            .route("",web::get().to(ReservationController::show_today_reservations))
+            .route("",web::get().to(ReservationController::search_reservations))
             .route("/create", web::post().to(ReservationController::create_reservation))
             .route("/create/manual/{user_id}", web::post().to(ReservationController::create_manual_reservation))
             .route("/user",web::get().to(ReservationController::show_user_reservations))
+            .route("/schedules/availability", web::get().to(ReservationController::show_schedules_availability))
+            .route("/schedules/{id}/availability", web::get().to(ReservationController::show_schedule_availability))
+            .route("/groups", web::post().to(GroupBookingController::request_group_reservation))
+            .route("/groups", web::get().to(GroupBookingController::show_organisation_group_reservations))
+            .route("/groups/{id}", web::get().to(GroupBookingController::show_group_reservation))
+            .route("/groups/{id}/cancellation", web::post().to(GroupBookingController::cancel_group_reservation))
+            .route("/transfers", web::post().to(TransferController::request_transfer))
+            .route("/transfers", web::get().to(TransferController::show_transfers))
+            .route("/transfers/{id}/accept", web::post().to(TransferController::accept_transfer))
+            .route("/transfers/{id}/decline", web::post().to(TransferController::decline_transfer))
+            .route("/transfers/{id}/cancellation", web::post().to(TransferController::cancel_transfer))
+            .route("/quote", web::post().to(PricingController::quote))
+            .route("/payments", web::post().to(PaymentController::start_payment))
+            .route("/payments/callback", web::post().to(PaymentController::payment_callback))
             .route("/{id}", web::get().to(ReservationController::show_reservation))
+            .route("/{id}/history", web::get().to(ReservationController::show_reservation_history))
+            .route("/{id}/payment", web::get().to(PaymentController::show_payment))
             .route("/count",web::post().to(ReservationController::update_reservation))
-            .route("/use", web::post().to(ReservationController::use_reservation))
+            .route("/move", web::post().to(ReservationController::move_reservation))
+            .route("/check-in", web::post().to(ReservationController::check_in))
             .route("/cancellation", web::post().to(ReservationController::cancel_reservation))
-            .app_data(web::Data::new(controller.clone())),
+            .route("/admin/reservations/{id}/cancellation", web::post().to(ReservationController::cancel_reservation_by_staff))
+            .route("/admin/contents/{content_id}/rules", web::get().to(ContentRuleController::show_content_rule))
+            .route("/admin/contents/{content_id}/rules", web::put().to(ContentRuleController::update_content_rule))
+            .route("/admin/webhooks", web::post().to(WebhookController::create_webhook))
+            .route("/admin/webhooks", web::get().to(WebhookController::show_webhooks))
+            .route("/admin/webhooks/dead-letters", web::get().to(WebhookController::show_dead_letters))
+            .route("/admin/webhooks/deliveries/{id}/replay", web::post().to(WebhookController::replay_delivery))
+            .route("/admin/webhooks/{id}", web::put().to(WebhookController::update_webhook))
+            .route("/admin/webhooks/{id}", web::delete().to(WebhookController::delete_webhook))
+            .route("/admin/organisations", web::post().to(GroupBookingController::create_organisation))
+            .route("/admin/organisations/{id}/members", web::post().to(GroupBookingController::add_organisation_member))
+            .route("/admin/schedules/{id}/group-quota", web::get().to(GroupBookingController::show_group_quota))
+            .route("/admin/schedules/{id}/group-quota", web::put().to(GroupBookingController::update_group_quota))
+            .route("/admin/groups", web::get().to(GroupBookingController::show_group_reservations))
+            .route("/admin/groups/{id}/approve", web::post().to(GroupBookingController::approve_group_reservation))
+            .route("/admin/groups/{id}/reject", web::post().to(GroupBookingController::reject_group_reservation))
+            .route("/admin/contents/{content_id}/prices", web::get().to(PricingController::show_content_prices))
+            .route("/admin/contents/{content_id}/prices", web::put().to(PricingController::update_content_prices))
+            .route("/admin/discounts", web::get().to(PricingController::show_discounts))
+            .route("/admin/discounts", web::post().to(PricingController::create_discount))
+            .route("/admin/discounts/{id}/deactivate", web::post().to(PricingController::deactivate_discount))
+            .route("/admin/members/{user_id}", web::put().to(PricingController::register_member))
+            .route("/admin/members/{user_id}", web::delete().to(PricingController::remove_member))
+            .app_data(web::Data::new(controller.clone()))
+            .app_data(web::Data::new(content_rule_controller.clone()))
+            .app_data(web::Data::new(webhook_controller.clone()))
+            .app_data(web::Data::new(group_booking_controller.clone()))
+            .app_data(web::Data::new(transfer_controller.clone()))
+            .app_data(web::Data::new(pricing_controller.clone()))
+            .app_data(web::Data::new(payment_controller.clone())),
     );
 }
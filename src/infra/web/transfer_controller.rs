@@ -0,0 +1,151 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::application::port::r#in::transfer_usecase::TransferUseCase;
+use crate::dto::transfer_dto::{ReservationTransferDTO, TransferQuery, TransferRequest};
+use crate::grpc_client::GrpcClients;
+use crate::common::valid::validate_user_token;
+
+#[derive(Clone)]
+pub struct TransferController {
+    use_case: Arc<dyn TransferUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+}
+
+impl TransferController {
+    pub fn new(
+        use_case: Arc<dyn TransferUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>
+    ) -> Self {
+        Self { use_case, grpc_clients }
+    }
+
+    // /reservation/transfers - 예약 양도 요청
+    pub async fn request_transfer(
+        controller: web::Data<Arc<TransferController>>,
+        req: web::Json<TransferRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.request_transfer(&user_id, req.reservation_id, &req.to_user_id).await {
+            Ok(transfer) => HttpResponse::Created().json(ReservationTransferDTO::from(transfer)),
+            Err(e) => HttpResponse::BadRequest().json(format!("양도 요청 실패: {}", e)),
+        }
+    }
+
+    // /reservation/transfers - 보낸 / 받은 양도 요청 목록
+    pub async fn show_transfers(
+        controller: web::Data<Arc<TransferController>>,
+        query: web::Query<TransferQuery>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.show_user_transfers(&user_id, query.limit).await {
+            Ok(transfers) => {
+                let transfer_dtos: Vec<ReservationTransferDTO> = transfers.into_iter().map(ReservationTransferDTO::from).collect();
+                HttpResponse::Ok().json(transfer_dtos)
+            },
+            Err(e) => HttpResponse::BadRequest().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/transfers/{id}/accept - 양도 수락 (받는 사용자 기준 예약 한도 검사)
+    pub async fn accept_transfer(
+        controller: web::Data<Arc<TransferController>>,
+        transfer_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        // 유저 정보 가져오기 (받는 사용자의 예약 한도)
+        let mut grpc_clients = controller.grpc_clients.lock().await;
+        let user_info = match grpc_clients.get_user_info(user_id.clone()).await {
+            Ok(info) => info,
+            Err(e) => {
+                println!("Failed to fetch user info: {}", e);
+                return HttpResponse::InternalServerError().json(format!("Failed to get user info: {}", e));
+            }
+        };
+        drop(grpc_clients);
+
+        match controller.use_case.accept_transfer(&user_id, transfer_id.into_inner(), user_info.ad_cnt, user_info.cd_cnt).await {
+            Ok(transfer) => HttpResponse::Ok().json(ReservationTransferDTO::from(transfer)),
+            Err(e) => HttpResponse::Conflict().json(format!("양도 수락 실패: {}", e)),
+        }
+    }
+
+    // /reservation/transfers/{id}/decline - 양도 거절
+    pub async fn decline_transfer(
+        controller: web::Data<Arc<TransferController>>,
+        transfer_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.decline_transfer(&user_id, transfer_id.into_inner()).await {
+            Ok(transfer) => HttpResponse::Ok().json(ReservationTransferDTO::from(transfer)),
+            Err(e) => HttpResponse::Conflict().json(format!("양도 거절 실패: {}", e)),
+        }
+    }
+
+    // /reservation/transfers/{id}/cancellation - 양도 요청 철회
+    pub async fn cancel_transfer(
+        controller: web::Data<Arc<TransferController>>,
+        transfer_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청
+        let user_id = match validate_user_token(controller.grpc_clients.clone(), &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        match controller.use_case.cancel_transfer(&user_id, transfer_id.into_inner()).await {
+            Ok(transfer) => HttpResponse::Ok().json(ReservationTransferDTO::from(transfer)),
+            Err(e) => HttpResponse::Conflict().json(format!("양도 철회 실패: {}", e)),
+        }
+    }
+}
\ No newline at end of file
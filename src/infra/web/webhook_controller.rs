@@ -0,0 +1,177 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::application::port::r#in::webhook_usecase::WebhookUseCase;
+use crate::dto::webhook_dto::{DeadLetterQuery, WebhookDeliveryDTO, WebhookSubscriptionDTO, WebhookSubscriptionRequest};
+use crate::grpc_client::GrpcClients;
+use crate::common::valid::{validate_staff_token, StaffRoles};
+
+#[derive(Clone)]
+pub struct WebhookController {
+    use_case: Arc<dyn WebhookUseCase + Send + Sync>,
+    grpc_clients: Arc<Mutex<GrpcClients>>,
+    roles: Arc<StaffRoles>,
+}
+
+impl WebhookController {
+    pub fn new(
+        use_case: Arc<dyn WebhookUseCase + Send + Sync>,
+        grpc_clients: Arc<Mutex<GrpcClients>>,
+        roles: Arc<StaffRoles>,
+    ) -> Self {
+        Self { use_case, grpc_clients, roles }
+    }
+
+    // /reservation/admin/webhooks - 웹훅 구독 등록
+    pub async fn create_webhook(
+        controller: web::Data<Arc<WebhookController>>,
+        req: web::Json<WebhookSubscriptionRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        let user_id = match validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response, // 오류 발생 시 바로 응답 반환
+        };
+
+        let event_types = match req.parse_event_types() {
+            Ok(event_types) => event_types,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+        let Some(secret) = req.secret.clone() else {
+            return HttpResponse::BadRequest().json("secret은 필수입니다.");
+        };
+        println!("웹훅 구독 등록 요청: url={}, user_id={}", req.url, user_id);
+
+        match controller.use_case.create_subscription(req.url.clone(), secret, event_types).await {
+            Ok(subscription) => HttpResponse::Created().json(WebhookSubscriptionDTO::from(subscription)),
+            Err(e) => HttpResponse::BadRequest().json(format!("웹훅 등록 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/webhooks - 웹훅 구독 목록
+    pub async fn show_webhooks(
+        controller: web::Data<Arc<WebhookController>>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_subscriptions().await {
+            Ok(subscriptions) => {
+                let subscription_dtos: Vec<WebhookSubscriptionDTO> = subscriptions.into_iter().map(WebhookSubscriptionDTO::from).collect();
+                HttpResponse::Ok().json(subscription_dtos)
+            },
+            Err(e) => HttpResponse::InternalServerError().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/webhooks/{id} - 웹훅 구독 수정
+    pub async fn update_webhook(
+        controller: web::Data<Arc<WebhookController>>,
+        subscription_id: web::Path<i64>,
+        req: web::Json<WebhookSubscriptionRequest>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        let event_types = match req.parse_event_types() {
+            Ok(event_types) => event_types,
+            Err(e) => return HttpResponse::BadRequest().json(e),
+        };
+
+        match controller.use_case.update_subscription(subscription_id.into_inner(), req.url.clone(), req.secret.clone(), event_types, req.active.unwrap_or(true)).await {
+            Ok(subscription) => HttpResponse::Ok().json(WebhookSubscriptionDTO::from(subscription)),
+            Err(e) => HttpResponse::BadRequest().json(format!("웹훅 수정 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/webhooks/{id} - 웹훅 구독 삭제
+    pub async fn delete_webhook(
+        controller: web::Data<Arc<WebhookController>>,
+        subscription_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.delete_subscription(subscription_id.into_inner()).await {
+            Ok(_) => HttpResponse::Ok().json("웹훅 구독이 삭제되었습니다."),
+            Err(e) => HttpResponse::NotFound().json(format!("웹훅 삭제 실패: {}", e)),
+        }
+    }
+
+    // /reservation/admin/webhooks/dead-letters - 최종 실패한 전달 목록
+    pub async fn show_dead_letters(
+        controller: web::Data<Arc<WebhookController>>,
+        query: web::Query<DeadLetterQuery>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.show_dead_deliveries(query.subscription_id, query.limit).await {
+            Ok(deliveries) => {
+                let delivery_dtos: Vec<WebhookDeliveryDTO> = deliveries.into_iter().map(WebhookDeliveryDTO::from).collect();
+                HttpResponse::Ok().json(delivery_dtos)
+            },
+            Err(e) => HttpResponse::BadRequest().json(format!("Error: {}", e)),
+        }
+    }
+
+    // /reservation/admin/webhooks/deliveries/{id}/replay - 전달 수동 재전송
+    pub async fn replay_delivery(
+        controller: web::Data<Arc<WebhookController>>,
+        delivery_id: web::Path<i64>,
+        http_req: HttpRequest,
+    ) -> impl Responder {
+        let token = match http_req.headers().get("Authorization") {
+            Some(value) => value.to_str().unwrap_or("").replace("Bearer ", "").trim().to_string(),
+            None => return HttpResponse::Unauthorized().json("No Authorization Header"),
+        };
+
+        // gRPC를 사용하여 AuthService에 토큰 검증 요청 (직원만 허용)
+        if let Err(response) = validate_staff_token(controller.grpc_clients.clone(), &controller.roles, &token).await {
+            return response; // 오류 발생 시 바로 응답 반환
+        }
+
+        match controller.use_case.replay_delivery(delivery_id.into_inner()).await {
+            Ok(_) => HttpResponse::Accepted().json("재전송이 예약되었습니다."),
+            Err(e) => HttpResponse::Conflict().json(format!("재전송 실패: {}", e)),
+        }
+    }
+}
@@ -19,3 +19,10 @@ pub mod reservation_proto {
 pub mod reservationfcm_proto {
     tonic::include_proto!("reservationfcm"); 
 }
+
+pub mod reservationevent_proto {
+    tonic::include_proto!("reservationevent"); 
+}
+pub mod notification_proto {
+    tonic::include_proto!("notification"); 
+}
\ No newline at end of file
@@ -12,6 +12,302 @@ pub struct Settings {
 
     pub grpc_host: String,
     pub grpc_port: u16, 
+
+    // 관리자 API 를 사용할 직원 사용자 ID (쉼표 구분, Auth 토큰에 역할 정보가 없으므로 설정으로 관리)
+    #[serde(default)]
+    pub staff_user_ids: String,
+    // 입장 티켓을 스캔할 수 있는 사용자 ID (쉼표 구분, 직원은 별도 설정 없이 허용)
+    #[serde(default)]
+    pub scanner_user_ids: String,
+
+    #[serde(default = "default_idempotency_ttl_secs")]
+    pub idempotency_ttl_secs: i64, // 멱등성 키 보관 시간 (초)
+    #[serde(default = "default_idempotency_lease_secs")]
+    pub idempotency_lease_secs: i64, // 처리 중(IN_PROGRESS) 키 점유 시간 (초), 지나면 같은 키로 재시도 가능
+
+    #[serde(default = "default_min_party_size")]
+    pub min_party_size: i32, // 1회 예약 최소 인원
+    #[serde(default = "default_max_party_size")]
+    pub max_party_size: i32, // 1회 예약 최대 인원
+
+    // 회차 시작 N분 전 마감 (음수면 시작 후 N분까지 허용)
+    #[serde(default)]
+    pub booking_close_minutes_before: i64,
+    #[serde(default)]
+    pub count_change_close_minutes_before: i64,
+    #[serde(default = "default_cancel_close_minutes_before")]
+    pub cancel_close_minutes_before: i64,
+
+    #[serde(default = "default_business_timezone")]
+    pub business_timezone: String, // 영업 시간대 ("오늘" 계산 기준)
+
+    // 입장 티켓 HMAC 서명 키 (32자 이상, APP__TICKET_SECRET)
+    // 없으면 티켓을 발급하지 않고 티켓 입장도 거부 (기존 설정은 그대로 기동)
+    #[serde(default)]
+    pub ticket_secret: Option<String>,
+    // 입장 가능 시간: 회차 시작 N분 전 ~ 시작 후 N분
+    #[serde(default = "default_check_in_opens_minutes_before")]
+    pub check_in_opens_minutes_before: i64,
+    #[serde(default = "default_check_in_closes_minutes_after")]
+    pub check_in_closes_minutes_after: i64,
+
+    #[serde(default = "default_no_show_scan_interval_secs")]
+    pub no_show_scan_interval_secs: u64, // 노쇼 처리 주기 (초)
+    // 최근 N일간 노쇼 횟수가 한도 이상이면 예약 제한 (0이면 제한 없음)
+    #[serde(default)]
+    pub no_show_limit: i64,
+    #[serde(default = "default_no_show_lookback_days")]
+    pub no_show_lookback_days: i64,
+
+    // 예약 이벤트 전달 대상: "log" (표준 출력 / 파일), "http" (웹훅 URL), "grpc" (ReservationEventSink 주소)
+    #[serde(default = "default_event_sink")]
+    pub event_sink: String,
+    #[serde(default)]
+    pub event_sink_target: Option<String>, // http/grpc 는 필수, log 는 파일 경로 (없으면 표준 출력)
+    #[serde(default = "default_event_sink_timeout_secs")]
+    pub event_sink_timeout_secs: u64,
+    #[serde(default = "default_outbox_relay_interval_secs")]
+    pub outbox_relay_interval_secs: u64, // 아웃박스 조회 주기 (초)
+    #[serde(default = "default_outbox_batch_size")]
+    pub outbox_batch_size: i64,
+    // 전달 실패 시 재시도 간격 (초, 실패할 때마다 2배, 최대 max)
+    #[serde(default = "default_outbox_retry_base_secs")]
+    pub outbox_retry_base_secs: i64,
+    #[serde(default = "default_outbox_retry_max_secs")]
+    pub outbox_retry_max_secs: i64,
+
+    #[serde(default = "default_webhook_delivery_interval_secs")]
+    pub webhook_delivery_interval_secs: u64, // 웹훅 전달 조회 주기 (초)
+    #[serde(default = "default_webhook_batch_size")]
+    pub webhook_batch_size: i64,
+    #[serde(default = "default_webhook_timeout_secs")]
+    pub webhook_timeout_secs: u64,
+    #[serde(default = "default_webhook_max_attempts")]
+    pub webhook_max_attempts: i32, // 이 횟수만큼 실패하면 데드레터로 이동
+    // 재시도 간격 (초, 실패할 때마다 2배, 최대 max)
+    #[serde(default = "default_webhook_retry_base_secs")]
+    pub webhook_retry_base_secs: i64,
+    #[serde(default = "default_webhook_retry_max_secs")]
+    pub webhook_retry_max_secs: i64,
+    #[serde(default)]
+    pub webhook_allow_private_targets: bool, // 개발용: 루프백 / 내부망 웹훅 주소 허용 (운영에서는 false)
+
+    #[serde(default)]
+    pub notification_grpc_addr: Option<String>, // 알림 gRPC 서비스 주소 (없으면 로그로만 기록)
+    #[serde(default = "default_notification_timeout_secs")]
+    pub notification_timeout_secs: u64,
+    // 회차 시작 N분 전 알림 (0이면 발송 안 함)
+    #[serde(default = "default_reminder_minutes_before")]
+    pub reminder_minutes_before: i64,
+    #[serde(default = "default_reminder_scan_interval_secs")]
+    pub reminder_scan_interval_secs: u64,
+    // 알림 문구 ({content_title}, {start_time}, {minutes} 치환)
+    #[serde(default = "default_reminder_title_template")]
+    pub reminder_title_template: String,
+    #[serde(default = "default_reminder_body_template")]
+    pub reminder_body_template: String,
+
+    // 예약 완료 / 취소 / 변경 알림 경로: "log" (발송 안 함), "grpc" (notification_grpc_addr), "email" (SMTP)
+    #[serde(default = "default_notification_channel")]
+    pub notification_channel: String,
+    #[serde(default = "default_notification_locale")]
+    pub notification_locale: String, // 기본 알림 언어 "ko" / "en" (User-Service 에 사용자 언어가 없을 때)
+    #[serde(default)]
+    pub smtp_host: Option<String>,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub smtp_username: Option<String>,
+    #[serde(default)]
+    pub smtp_password: Option<String>,
+    #[serde(default = "default_smtp_starttls")]
+    pub smtp_starttls: bool,
+    #[serde(default)]
+    pub smtp_from: Option<String>,
+    #[serde(default)]
+    pub smtp_recipient_template: Option<String>, // "{user_id}@example.com"
+    // 예약 양도 요청 응답 기한 (회차 시작이 더 빠르면 시작 시각까지)
+    #[serde(default = "default_transfer_expiry_hours")]
+    pub transfer_expiry_hours: i64,
+    #[serde(default = "default_pricing_currency")]
+    pub pricing_currency: String, // 예약 금액 통화 (ISO 4217)
+    #[serde(default = "default_payment_gateway")]
+    pub payment_gateway: String, // mock
+    // 결제 콜백 서명 키 (32자 이상, APP__PAYMENT_CALLBACK_SECRET)
+    // 콜백 API 는 토큰 없이 호출되므로 필수 (없으면 기동 실패)
+    pub payment_callback_secret: String,
+    #[serde(default = "default_payment_timeout_minutes")]
+    pub payment_timeout_minutes: i64, // 유료 예약 결제 기한
+    #[serde(default = "default_payment_expiry_scan_interval_secs")]
+    pub payment_expiry_scan_interval_secs: u64,
+    #[serde(default = "default_payment_expiry_batch_size")]
+    pub payment_expiry_batch_size: i64,
+    #[serde(default = "default_refund_full_minutes_before")]
+    pub refund_full_minutes_before: i64, // 회차 시작 기준 이 시간 이전 취소는 전액 환불
+    #[serde(default = "default_refund_partial_minutes_before")]
+    pub refund_partial_minutes_before: i64, // 이 시간 이전 취소는 부분 환불, 이후는 환불 없음
+    #[serde(default = "default_refund_partial_percent")]
+    pub refund_partial_percent: i32,
+}
+
+fn default_idempotency_ttl_secs() -> i64 {
+    24 * 60 * 60
+}
+
+fn default_idempotency_lease_secs() -> i64 {
+    60
+}
+
+fn default_min_party_size() -> i32 {
+    1
+}
+
+fn default_max_party_size() -> i32 {
+    10
+}
+
+fn default_cancel_close_minutes_before() -> i64 {
+    15
+}
+
+fn default_business_timezone() -> String {
+    "Asia/Seoul".to_string()
+}
+
+fn default_check_in_opens_minutes_before() -> i64 {
+    30
+}
+
+fn default_check_in_closes_minutes_after() -> i64 {
+    30
+}
+
+fn default_no_show_scan_interval_secs() -> u64 {
+    5 * 60
+}
+
+fn default_no_show_lookback_days() -> i64 {
+    90
+}
+
+fn default_event_sink() -> String {
+    "log".to_string()
+}
+
+fn default_event_sink_timeout_secs() -> u64 {
+    10
+}
+
+fn default_outbox_relay_interval_secs() -> u64 {
+    5
+}
+
+fn default_outbox_batch_size() -> i64 {
+    100
+}
+
+fn default_outbox_retry_base_secs() -> i64 {
+    5
+}
+
+fn default_outbox_retry_max_secs() -> i64 {
+    10 * 60
+}
+
+fn default_webhook_delivery_interval_secs() -> u64 {
+    5
+}
+
+fn default_webhook_batch_size() -> i64 {
+    50
+}
+
+fn default_webhook_timeout_secs() -> u64 {
+    10
+}
+
+fn default_webhook_max_attempts() -> i32 {
+    8
+}
+
+fn default_webhook_retry_base_secs() -> i64 {
+    30
+}
+
+fn default_webhook_retry_max_secs() -> i64 {
+    60 * 60
+}
+
+fn default_notification_timeout_secs() -> u64 {
+    10
+}
+
+fn default_reminder_minutes_before() -> i64 {
+    60
+}
+
+fn default_reminder_scan_interval_secs() -> u64 {
+    60
+}
+
+fn default_reminder_title_template() -> String {
+    "{content_title} 시작 {minutes}분 전입니다".to_string()
+}
+
+fn default_reminder_body_template() -> String {
+    "{start_time}에 {content_title} 회차가 시작됩니다. 입장 시간에 늦지 않게 와주세요.".to_string()
+}
+
+fn default_notification_channel() -> String {
+    "log".to_string()
+}
+
+fn default_notification_locale() -> String {
+    "ko".to_string()
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_smtp_starttls() -> bool {
+    true
+}
+
+fn default_transfer_expiry_hours() -> i64 {
+    48
+}
+
+fn default_pricing_currency() -> String {
+    "KRW".to_string()
+}
+
+fn default_payment_gateway() -> String {
+    "mock".to_string()
+}
+
+fn default_payment_timeout_minutes() -> i64 {
+    15
+}
+
+fn default_payment_expiry_scan_interval_secs() -> u64 {
+    60
+}
+
+fn default_payment_expiry_batch_size() -> i64 {
+    100
+}
+
+fn default_refund_full_minutes_before() -> i64 {
+    24 * 60
+}
+
+fn default_refund_partial_minutes_before() -> i64 {
+    60
+}
+
+fn default_refund_partial_percent() -> i32 {
+    50
 }
 
 impl Settings {
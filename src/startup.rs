@@ -58,9 +58,82 @@ async fn run_eureka_client(state: Arc<AppState>) {
     }
 }
 
+// 주기적으로 노쇼 예약 처리
+async fn run_no_show_job(state: Arc<AppState>) {
+    let interval = Duration::from_secs(state.settings.no_show_scan_interval_secs.max(1));
+    loop {
+        if let Err(err) = state.reservation_service.mark_no_shows().await {
+            eprintln!("노쇼 처리 실패: {}", err);
+        }
+        sleep(interval).await;
+    }
+}
+
+// 주기적으로 아웃박스 이벤트 전달 (대기 중인 이벤트가 남아 있으면 바로 다음 배치 처리)
+async fn run_outbox_relay(state: Arc<AppState>) {
+    let interval = Duration::from_secs(state.settings.outbox_relay_interval_secs.max(1));
+    let batch_size = state.settings.outbox_batch_size.max(1) as usize;
+    loop {
+        match state.outbox_relay_service.relay_pending().await {
+            Ok(published) if published >= batch_size => continue,
+            Ok(_) => {},
+            Err(err) => eprintln!("아웃박스 이벤트 전달 실패: {}", err),
+        }
+        sleep(interval).await;
+    }
+}
+
+// 주기적으로 웹훅 전달 (재시도 시각이 된 전달 포함)
+async fn run_webhook_delivery(state: Arc<AppState>) {
+    let interval = Duration::from_secs(state.settings.webhook_delivery_interval_secs.max(1));
+    let batch_size = state.settings.webhook_batch_size.max(1) as usize;
+    loop {
+        match state.webhook_service.deliver_pending().await {
+            Ok(processed) if processed >= batch_size => continue,
+            Ok(_) => {},
+            Err(err) => eprintln!("웹훅 전달 실패: {}", err),
+        }
+        sleep(interval).await;
+    }
+}
+
+// 주기적으로 회차 시작 전 알림 발송
+async fn run_reminder_job(state: Arc<AppState>) {
+    let interval = Duration::from_secs(state.settings.reminder_scan_interval_secs.max(1));
+    loop {
+        if let Err(err) = state.reminder_service.send_due_reminders().await {
+            eprintln!("회차 알림 발송 실패: {}", err);
+        }
+        sleep(interval).await;
+    }
+}
+
+// 주기적으로 결제 기한이 지난 예약 해제 + 실패한 환불 재시도
+async fn run_payment_expiry_job(state: Arc<AppState>) {
+    let interval = Duration::from_secs(state.settings.payment_expiry_scan_interval_secs.max(1));
+    loop {
+        if let Err(err) = state.payment_service.expire_unpaid().await {
+            eprintln!("결제 기한 처리 실패: {}", err);
+        }
+        sleep(interval).await;
+    }
+}
+
 pub fn run(listener: TcpListener, state: Arc<AppState>) -> Result<actix_web::dev::Server, std::io::Error> {
     // Eureka 클라이언트 실행 (비동기 태스크)
     task::spawn(run_eureka_client(state.clone()));
+    // 노쇼 처리 작업 실행 (비동기 태스크)
+    task::spawn(run_no_show_job(state.clone()));
+    // 예약 이벤트 릴레이 실행 (비동기 태스크)
+    task::spawn(run_outbox_relay(state.clone()));
+    // 웹훅 전달 실행 (비동기 태스크)
+    task::spawn(run_webhook_delivery(state.clone()));
+    // 회차 알림 발송 실행 (비동기 태스크, 0분이면 비활성)
+    if state.settings.reminder_minutes_before > 0 {
+        task::spawn(run_reminder_job(state.clone()));
+    }
+    // 결제 기한 처리 실행 (비동기 태스크)
+    task::spawn(run_payment_expiry_job(state.clone()));
 
     let server = HttpServer::new(move || {
         App::new()
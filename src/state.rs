@@ -1,15 +1,32 @@
 use sqlx::MySqlPool;
 use tokio::sync::Mutex;
 use std::sync::Arc;
-use crate::{adapter::reservation_adapter::ReservationAdapter,
-    application::{port::{r#in::reservation_usecase::ReservationUseCase, out::{reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort}}, 
+use crate::{adapter::{content_rule_adapter::ContentRuleAdapter, group_booking_adapter::GroupBookingAdapter, idempotency_adapter::IdempotencyAdapter, outbox_adapter::OutboxAdapter, payment_record_adapter::PaymentRecordAdapter, pricing_adapter::PricingAdapter, reminder_adapter::ReminderAdapter, reservation_adapter::ReservationAdapter, transfer_adapter::TransferAdapter, webhook_adapter::WebhookAdapter},
+    application::{booking_notifier::BookingNotifier, content_rule_service::ContentRuleService, group_booking_service::GroupBookingService, idempotency_service::IdempotencyService, outbox_relay_service::{OutboxRelayService, RetryBackoff}, payment_service::PaymentService, pricing_service::PricingService, policy::{booking_policy::BookingPolicy, rules::NoShowRule}, port::{r#in::{content_rule_usecase::ContentRuleUseCase, group_booking_usecase::GroupBookingUseCase, idempotency_usecase::IdempotencyUseCase, outbox_relay_usecase::OutboxRelayUseCase, payment_usecase::PaymentUseCase, pricing_usecase::PricingUseCase, reminder_usecase::ReminderUseCase, reservation_usecase::ReservationUseCase, transfer_usecase::TransferUseCase, webhook_usecase::WebhookUseCase}, out::{content_rule_port::ContentRulePort, event_publish_port::EventPublishPort, group_booking_port::GroupBookingPort, idempotency_port::IdempotencyPort, notification_port::NotificationPort, outbox_port::OutboxPort, payment_port::PaymentPort, payment_record_port::PaymentRecordPort, pricing_port::PricingPort, push_notification_port::PushNotificationPort, reminder_port::ReminderPort, reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort, transfer_port::TransferPort, user_locale_port::UserLocalePort, webhook_port::WebhookPort, webhook_send_port::WebhookSendPort}}, 
+    reminder_service::ReminderService, 
+    transfer_service::TransferService, 
+    webhook_service::{WebhookDeliveryConfig, WebhookService}, 
     reservation_service::ReservationService}, 
+    common::{clock::{Clock, SystemClock}, date::parse_timezone, ticket::TicketSigner, valid::StaffRoles},
     db_connection::establish_connection, 
+    domain::{booking_window::BookingWindow, check_in::CheckInWindow, notification::Locale, payment::RefundPolicy, reminder::ReminderTemplate},
     grpc::grpc_service::ReservationGrpcService,  
     grpc_client::GrpcClients, 
     infra::db::reservation_repository::ReservationRepository,
     infra::db::reservation_repository_impl::ReservationRepositoryImpl, 
-    infra::web::reservation_controller::ReservationController, settings::Settings};
+    infra::db::{idempotency_repository::IdempotencyRepository, idempotency_repository_impl::IdempotencyRepositoryImpl}, 
+    infra::db::{content_rule_repository::ContentRuleRepository, content_rule_repository_impl::ContentRuleRepositoryImpl}, 
+    infra::db::{outbox_repository::OutboxRepository, outbox_repository_impl::OutboxRepositoryImpl}, 
+    infra::db::{webhook_repository::WebhookRepository, webhook_repository_impl::WebhookRepositoryImpl}, 
+    infra::db::{reminder_repository::ReminderRepository, reminder_repository_impl::ReminderRepositoryImpl}, 
+    infra::db::{group_booking_repository::GroupBookingRepository, group_booking_repository_impl::GroupBookingRepositoryImpl}, 
+    infra::db::{transfer_repository::TransferRepository, transfer_repository_impl::TransferRepositoryImpl}, 
+    infra::db::{pricing_repository::PricingRepository, pricing_repository_impl::PricingRepositoryImpl}, 
+    infra::db::{payment_repository::PaymentRepository, payment_repository_impl::PaymentRepositoryImpl}, 
+    infra::payment::MockPaymentGateway, 
+    infra::notification::{GrpcPushNotifier, GrpcUserLocale, LogPushNotifier, SmtpConfig, SmtpEmailNotifier}, 
+    infra::event::{CompositeEventSink, GrpcEventSink, HttpEventSink, LogEventSink, WebhookFanoutSink, WebhookHttpSender}, 
+    infra::web::{content_rule_controller::ContentRuleController, group_booking_controller::GroupBookingController, payment_controller::PaymentController, pricing_controller::PricingController, reservation_controller::ReservationController, transfer_controller::TransferController, webhook_controller::WebhookController}, settings::Settings};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -17,7 +34,22 @@ pub struct AppState {
     pub db_pool: Arc<MySqlPool>,
     pub reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,  
     pub reservation_service: Arc<dyn ReservationUseCase + Send + Sync>,
+    pub idempotency_service: Arc<dyn IdempotencyUseCase + Send + Sync>,
+    pub content_rule_service: Arc<dyn ContentRuleUseCase + Send + Sync>,
+    pub outbox_relay_service: Arc<dyn OutboxRelayUseCase + Send + Sync>,
+    pub webhook_service: Arc<dyn WebhookUseCase + Send + Sync>,
+    pub reminder_service: Arc<dyn ReminderUseCase + Send + Sync>,
+    pub group_booking_service: Arc<dyn GroupBookingUseCase + Send + Sync>,
+    pub transfer_service: Arc<dyn TransferUseCase + Send + Sync>,
+    pub pricing_service: Arc<dyn PricingUseCase + Send + Sync>,
+    pub payment_service: Arc<dyn PaymentUseCase + Send + Sync>,
     pub reservation_controller: Arc<ReservationController>,
+    pub content_rule_controller: Arc<ContentRuleController>,
+    pub webhook_controller: Arc<WebhookController>,
+    pub group_booking_controller: Arc<GroupBookingController>,
+    pub transfer_controller: Arc<TransferController>,
+    pub pricing_controller: Arc<PricingController>,
+    pub payment_controller: Arc<PaymentController>,
     pub grpc_server: Arc<ReservationGrpcService>,
     pub grpc_clients: Arc<Mutex<GrpcClients>>,
 }
@@ -40,9 +72,53 @@ impl AppState {
         Arc::new(ReservationAdapter::new(Arc::clone(&reservation_repository)));
         let save_port: Arc<dyn ReservationSavePort + Send + Sync> = adapter.clone();
         let load_port: Arc<dyn ReservationLoadPort + Send + Sync> = adapter.clone();
-        let reservation_service: Arc<dyn ReservationUseCase + Send + Sync> = Arc::new(ReservationService::new(Arc::clone(&save_port), Arc::clone(&load_port)));
-        //let reservation_service: Arc<dyn ReservationUseCase + Send + Sync> = Arc::new(ReservationService::new(adapter.clone())); 
-        
+        let content_rule_repository: Arc<dyn ContentRuleRepository + Send + Sync> =
+        Arc::new(ContentRuleRepositoryImpl::new(Arc::clone(&db_pool)));
+        let content_rule_port: Arc<dyn ContentRulePort + Send + Sync> =
+        Arc::new(ContentRuleAdapter::new(Arc::clone(&content_rule_repository)));
+        let content_rule_service: Arc<dyn ContentRuleUseCase + Send + Sync> =
+        Arc::new(ContentRuleService::new(Arc::clone(&content_rule_port)));
+
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let timezone = parse_timezone(&settings.business_timezone)
+            .expect("❌ Invalid business_timezone setting");
+        let booking_window = BookingWindow {
+            create_close_minutes_before: settings.booking_close_minutes_before,
+            change_count_close_minutes_before: settings.count_change_close_minutes_before,
+            cancel_close_minutes_before: settings.cancel_close_minutes_before,
+        };
+        let ticket_signer = match settings.ticket_secret.as_deref() {
+            Some(secret) => Some(Arc::new(TicketSigner::new(secret).expect("❌ Invalid ticket_secret setting"))),
+            None => {
+                eprintln!("⚠️ ticket_secret 설정이 없어 입장 티켓을 발급하지 않습니다.");
+                None
+            }
+        };
+        let check_in_window = CheckInWindow {
+            opens_minutes_before: settings.check_in_opens_minutes_before,
+            closes_minutes_after: settings.check_in_closes_minutes_after,
+        };
+        let mut booking_policy = BookingPolicy::with_default_rules(Arc::clone(&load_port), Arc::clone(&content_rule_port), Arc::clone(&clock), settings.min_party_size, settings.max_party_size);
+        if settings.no_show_limit > 0 {
+            booking_policy = booking_policy.with_rule(Arc::new(NoShowRule::new(Arc::clone(&load_port), Arc::clone(&clock), settings.no_show_limit, settings.no_show_lookback_days)));
+        }
+        let booking_policy = Arc::new(booking_policy);
+        let notification_port: Arc<dyn NotificationPort + Send + Sync> = match settings.notification_channel.as_str() {
+            "grpc" => Arc::new(GrpcPushNotifier::new(settings.notification_grpc_addr.as_deref().expect("❌ notification_grpc_addr is required for grpc notification channel"), settings.notification_timeout_secs)
+                .expect("❌ Invalid notification_grpc_addr setting")),
+            "email" => Arc::new(SmtpEmailNotifier::new(SmtpConfig {
+                host: settings.smtp_host.clone().expect("❌ smtp_host is required for email notification channel"),
+                port: settings.smtp_port,
+                username: settings.smtp_username.clone(),
+                password: settings.smtp_password.clone(),
+                starttls: settings.smtp_starttls,
+                from: settings.smtp_from.clone().expect("❌ smtp_from is required for email notification channel"),
+                recipient_template: settings.smtp_recipient_template.clone().expect("❌ smtp_recipient_template is required for email notification channel"),
+            }).expect("❌ Invalid smtp setting")),
+            "log" => Arc::new(LogPushNotifier),
+            other => panic!("❌ Unknown notification_channel setting: {}", other),
+        };
+        let locale: Locale = settings.notification_locale.parse().expect("❌ Invalid notification_locale setting");
         let grpc_clients = if let Ok(client) = GrpcClients::new("http://localhost:50052", "http://localhost:50053").await {
             println!("✅ Successfully connected to gRPC services");
             Arc::new(Mutex::new(client))
@@ -50,21 +126,169 @@ impl AppState {
             eprintln!("⚠️ Failed to connect to gRPC services. Proceeding with dummy clients.");
             Arc::new(Mutex::new(GrpcClients::dummy())) 
         };
+        let user_locale_port: Arc<dyn UserLocalePort + Send + Sync> = Arc::new(GrpcUserLocale::new(Arc::clone(&grpc_clients)));
+        let booking_notifier = Arc::new(BookingNotifier::new(Arc::clone(&notification_port), Arc::clone(&user_locale_port), timezone, locale));
+        let pricing_repository: Arc<dyn PricingRepository + Send + Sync> =
+        Arc::new(PricingRepositoryImpl::new(Arc::clone(&db_pool)));
+        let pricing_port: Arc<dyn PricingPort + Send + Sync> =
+        Arc::new(PricingAdapter::new(Arc::clone(&pricing_repository)));
+        let pricing_service: Arc<dyn PricingUseCase + Send + Sync> = Arc::new(PricingService::new(Arc::clone(&pricing_port), Arc::clone(&load_port), Arc::clone(&clock), timezone, settings.pricing_currency.clone()));
+        let payment_repository: Arc<dyn PaymentRepository + Send + Sync> =
+        Arc::new(PaymentRepositoryImpl::new(Arc::clone(&db_pool)));
+        let payment_record_port: Arc<dyn PaymentRecordPort + Send + Sync> =
+        Arc::new(PaymentRecordAdapter::new(Arc::clone(&payment_repository)));
+        let payment_gateway: Arc<dyn PaymentPort + Send + Sync> = match settings.payment_gateway.as_str() {
+            "mock" => Arc::new(MockPaymentGateway::new(&settings.payment_callback_secret, Arc::clone(&clock)).expect("❌ Invalid payment_callback_secret setting")),
+            other => panic!("❌ Unknown payment_gateway setting: {}", other),
+        };
+        let refund_policy = RefundPolicy {
+            full_refund_minutes_before: settings.refund_full_minutes_before,
+            partial_refund_minutes_before: settings.refund_partial_minutes_before,
+            partial_refund_percent: settings.refund_partial_percent,
+        };
+        let payment_service: Arc<dyn PaymentUseCase + Send + Sync> = Arc::new(PaymentService::new(Arc::clone(&payment_record_port), Arc::clone(&payment_gateway), Arc::clone(&load_port), Arc::clone(&clock), Arc::clone(&booking_notifier), refund_policy, settings.payment_timeout_minutes, settings.payment_expiry_batch_size));
+        let reservation_service: Arc<dyn ReservationUseCase + Send + Sync> = Arc::new(ReservationService::new(Arc::clone(&save_port), Arc::clone(&load_port), Arc::clone(&booking_policy), Arc::clone(&clock), timezone, booking_window, ticket_signer, check_in_window, Arc::clone(&booking_notifier), Arc::clone(&pricing_service), Arc::clone(&payment_service)));
+        //let reservation_service: Arc<dyn ReservationUseCase + Send + Sync> = Arc::new(ReservationService::new(adapter.clone())); 
+
+        let group_booking_repository: Arc<dyn GroupBookingRepository + Send + Sync> =
+        Arc::new(GroupBookingRepositoryImpl::new(Arc::clone(&db_pool)));
+        let group_booking_port: Arc<dyn GroupBookingPort + Send + Sync> =
+        Arc::new(GroupBookingAdapter::new(Arc::clone(&group_booking_repository)));
+        let group_booking_service: Arc<dyn GroupBookingUseCase + Send + Sync> = Arc::new(GroupBookingService::new(Arc::clone(&group_booking_port), Arc::clone(&load_port), Arc::clone(&clock), booking_window, Arc::clone(&booking_notifier), Arc::clone(&pricing_service)));
+
+        let transfer_repository: Arc<dyn TransferRepository + Send + Sync> =
+        Arc::new(TransferRepositoryImpl::new(Arc::clone(&db_pool)));
+        let transfer_port: Arc<dyn TransferPort + Send + Sync> =
+        Arc::new(TransferAdapter::new(Arc::clone(&transfer_repository)));
+        let transfer_service: Arc<dyn TransferUseCase + Send + Sync> = Arc::new(TransferService::new(Arc::clone(&transfer_port), Arc::clone(&load_port), Arc::clone(&booking_policy), Arc::clone(&clock), booking_window, Arc::clone(&booking_notifier), settings.transfer_expiry_hours));
+
+        let idempotency_repository: Arc<dyn IdempotencyRepository + Send + Sync> =
+        Arc::new(IdempotencyRepositoryImpl::new(Arc::clone(&db_pool)));
+        let idempotency_port: Arc<dyn IdempotencyPort + Send + Sync> =
+        Arc::new(IdempotencyAdapter::new(Arc::clone(&idempotency_repository)));
+        let idempotency_service: Arc<dyn IdempotencyUseCase + Send + Sync> =
+        Arc::new(IdempotencyService::new(Arc::clone(&idempotency_port), settings.idempotency_ttl_secs, settings.idempotency_lease_secs));
+
+        let outbox_repository: Arc<dyn OutboxRepository + Send + Sync> =
+        Arc::new(OutboxRepositoryImpl::new(Arc::clone(&db_pool)));
+        let outbox_port: Arc<dyn OutboxPort + Send + Sync> =
+        Arc::new(OutboxAdapter::new(Arc::clone(&outbox_repository)));
+        let event_sink: Arc<dyn EventPublishPort + Send + Sync> = match settings.event_sink.as_str() {
+            "http" => Arc::new(HttpEventSink::new(settings.event_sink_target.as_deref().expect("❌ event_sink_target is required for http event sink"), settings.event_sink_timeout_secs)
+                .expect("❌ Invalid http event sink setting")),
+            "grpc" => Arc::new(GrpcEventSink::new(settings.event_sink_target.as_deref().expect("❌ event_sink_target is required for grpc event sink"), settings.event_sink_timeout_secs)
+                .expect("❌ Invalid grpc event sink setting")),
+            "log" => Arc::new(LogEventSink::new(settings.event_sink_target.as_deref())),
+            other => panic!("❌ Unknown event_sink setting: {}", other),
+        };
+        let webhook_repository: Arc<dyn WebhookRepository + Send + Sync> =
+        Arc::new(WebhookRepositoryImpl::new(Arc::clone(&db_pool)));
+        let webhook_port: Arc<dyn WebhookPort + Send + Sync> =
+        Arc::new(WebhookAdapter::new(Arc::clone(&webhook_repository)));
+        let webhook_sender: Arc<dyn WebhookSendPort + Send + Sync> = Arc::new(WebhookHttpSender::new(settings.webhook_timeout_secs, settings.webhook_allow_private_targets)
+            .expect("❌ Failed to build webhook http client"));
+        let webhook_service: Arc<dyn WebhookUseCase + Send + Sync> = Arc::new(WebhookService::new(
+            Arc::clone(&webhook_port),
+            Arc::clone(&webhook_sender),
+            Arc::clone(&clock),
+            WebhookDeliveryConfig {
+                batch_size: settings.webhook_batch_size,
+                max_attempts: settings.webhook_max_attempts,
+                backoff: RetryBackoff { base_secs: settings.webhook_retry_base_secs, max_secs: settings.webhook_retry_max_secs },
+                allow_private_targets: settings.webhook_allow_private_targets,
+            },
+        ));
+        // 설정된 전달 대상 + 웹훅 구독 전달 생성
+        let event_publisher: Arc<dyn EventPublishPort + Send + Sync> = Arc::new(CompositeEventSink::new(vec![
+            Arc::clone(&event_sink),
+            Arc::new(WebhookFanoutSink::new(Arc::clone(&webhook_port))),
+        ]));
+        let outbox_relay_service: Arc<dyn OutboxRelayUseCase + Send + Sync> = Arc::new(OutboxRelayService::new(
+            Arc::clone(&outbox_port),
+            Arc::clone(&event_publisher),
+            Arc::clone(&clock),
+            settings.outbox_batch_size,
+            RetryBackoff { base_secs: settings.outbox_retry_base_secs, max_secs: settings.outbox_retry_max_secs },
+        ));
+
+        let reminder_repository: Arc<dyn ReminderRepository + Send + Sync> =
+        Arc::new(ReminderRepositoryImpl::new(Arc::clone(&db_pool)));
+        let reminder_port: Arc<dyn ReminderPort + Send + Sync> =
+        Arc::new(ReminderAdapter::new(Arc::clone(&reminder_repository)));
+        let push_notifier: Arc<dyn PushNotificationPort + Send + Sync> = match settings.notification_grpc_addr.as_deref() {
+            Some(addr) => Arc::new(GrpcPushNotifier::new(addr, settings.notification_timeout_secs)
+                .expect("❌ Invalid notification_grpc_addr setting")),
+            None => Arc::new(LogPushNotifier),
+        };
+        let reminder_service: Arc<dyn ReminderUseCase + Send + Sync> = Arc::new(ReminderService::new(
+            Arc::clone(&reminder_port),
+            Arc::clone(&push_notifier),
+            Arc::clone(&clock),
+            timezone,
+            settings.reminder_minutes_before,
+            ReminderTemplate { title: settings.reminder_title_template.clone(), body: settings.reminder_body_template.clone() },
+        ));
 
 
+        let staff_roles = Arc::new(StaffRoles::new(&settings.staff_user_ids, &settings.scanner_user_ids));
         let reservation_controller = Arc::new(ReservationController::new(
             Arc::clone(&reservation_service),
-            Arc::clone(&grpc_clients)
+            Arc::clone(&idempotency_service),
+            Arc::clone(&grpc_clients),
+            Arc::clone(&staff_roles)
     ));
+        let content_rule_controller = Arc::new(ContentRuleController::new(
+            Arc::clone(&content_rule_service),
+            Arc::clone(&grpc_clients),
+            Arc::clone(&staff_roles)
+        ));
+        let webhook_controller = Arc::new(WebhookController::new(
+            Arc::clone(&webhook_service),
+            Arc::clone(&grpc_clients),
+            Arc::clone(&staff_roles)
+        ));
+        let group_booking_controller = Arc::new(GroupBookingController::new(
+            Arc::clone(&group_booking_service),
+            Arc::clone(&grpc_clients),
+            Arc::clone(&staff_roles)
+        ));
+        let transfer_controller = Arc::new(TransferController::new(
+            Arc::clone(&transfer_service),
+            Arc::clone(&grpc_clients)
+        ));
+        let pricing_controller = Arc::new(PricingController::new(
+            Arc::clone(&pricing_service),
+            Arc::clone(&grpc_clients),
+            Arc::clone(&staff_roles)
+        ));
+        let payment_controller = Arc::new(PaymentController::new(
+            Arc::clone(&payment_service),
+            Arc::clone(&grpc_clients)
+        ));
          // gRPC 서버 인스턴스 생성
-         let grpc_server = Arc::new(ReservationGrpcService::new(Arc::clone(&reservation_service)));
+         let grpc_server = Arc::new(ReservationGrpcService::new(Arc::clone(&reservation_service), Arc::clone(&idempotency_service), Arc::clone(&grpc_clients)));
 
          Self {
              settings: Arc::new(settings),
              db_pool: Arc::clone(&db_pool),
              reservation_repository,
              reservation_service,
+             idempotency_service,
+             content_rule_service,
+             outbox_relay_service,
+             webhook_service,
+             reminder_service,
+             group_booking_service,
+             transfer_service,
+             pricing_service,
+             payment_service,
              reservation_controller,
+             content_rule_controller,
+             webhook_controller,
+             group_booking_controller,
+             transfer_controller,
+             pricing_controller,
+             payment_controller,
              grpc_server, // gRPC 서버 추가
              grpc_clients
          }
@@ -0,0 +1,355 @@
+// 결제 흐름 테스트 (DB 없이 mock 결제 게이트웨이 + 고정 시계 + 메모리 저장소)
+//   cargo test --test payment_flow
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_tz::Asia::Seoul;
+use reservation_msservice::{
+    application::{
+        booking_notifier::BookingNotifier,
+        payment_service::PaymentService,
+        port::{
+            r#in::payment_usecase::PaymentUseCase,
+            out::{notification_port::NotificationPort, payment_port::PaymentPort, payment_record_port::PaymentRecordPort, reservation_load_port::ReservationLoadPort, user_locale_port::UserLocalePort},
+        },
+    },
+    common::{clock::FixedClock, page::{Page, PageRequest}, webhook_signature},
+    domain::{
+        notification::{Locale, NotificationMessage},
+        payment::{Payment, PaymentConfirmation, PaymentStatus, RefundPolicy},
+        pricing::ReservationPrice,
+        reservation::{Reservation, ReservationStatus},
+        reservation_history::{AuditContext, ReservationHistory},
+        schedule_availability::ScheduleAvailability,
+        ticket_category::PartyComposition,
+    },
+    dto::{reservation_chk_dto::ReservationLimits, reservation_query_dto::ReservationFilter, schedule_filter_dto::ScheduleAvailabilityFilter},
+    infra::payment::mock_payment_gateway::MockPaymentGateway,
+};
+
+const CALLBACK_SECRET: &str = "payment-flow-test-secret-0123456789";
+const USER_ID: &str = "user-1";
+const RESERVATION_ID: i32 = 1;
+const AMOUNT: i64 = 30_000;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 5, 1, 3, 0, 0).unwrap()
+}
+
+fn pending_payment_reservation(payment_due_at: DateTime<Utc>) -> Reservation {
+    let party = PartyComposition::from_legacy(2, 0);
+    Reservation {
+        id: RESERVATION_ID,
+        user_id: USER_ID.to_string(),
+        content_schedule_id: 10,
+        reserved_at: None,
+        status: Some(ReservationStatus::PendingPayment),
+        ad_cnt: party.adults(),
+        cd_cnt: party.children(),
+        use_at: false,
+        checked_in_ad_cnt: 0,
+        checked_in_cd_cnt: 0,
+        group_reservation_id: None,
+        payment_due_at: Some(payment_due_at),
+        party,
+        price: Some(ReservationPrice { list_price: AMOUNT, total_price: AMOUNT, discount_type: None, currency: "KRW".to_string() }),
+        schedule: None,
+        flags: None,
+        ticket_token: None,
+        version: None,
+    }
+}
+
+// PAYMENT / RESERVATION 을 메모리에 두고 PaymentRepositoryImpl 과 같은 상태 전이를 흉내냄
+#[derive(Default)]
+struct InMemoryStore {
+    reservations: Mutex<HashMap<i32, Reservation>>,
+    payments: Mutex<Vec<Payment>>,
+}
+
+impl InMemoryStore {
+    fn with_reservation(reservation: Reservation) -> Self {
+        let store = Self::default();
+        store.reservations.lock().unwrap().insert(reservation.id, reservation);
+        store
+    }
+
+    fn reservation(&self, reservation_id: i32) -> Reservation {
+        self.reservations.lock().unwrap()[&reservation_id].clone()
+    }
+
+    fn payment(&self, payment_id: i64) -> Payment {
+        self.payments.lock().unwrap().iter().find(|payment| payment.id == payment_id).cloned().expect("결제 없음")
+    }
+}
+
+#[async_trait]
+impl PaymentRecordPort for InMemoryStore {
+    async fn save_payment(&self, payment: &Payment) -> Result<i64, String> {
+        let mut payments = self.payments.lock().unwrap();
+        let mut payment = payment.clone();
+        payment.id = payments.len() as i64 + 1;
+        payments.push(payment.clone());
+        Ok(payment.id)
+    }
+
+    async fn attach_checkout(&self, payment_id: i64, provider_payment_id: &str, checkout_url: &str) -> Result<(), String> {
+        let mut payments = self.payments.lock().unwrap();
+        let payment = payments.iter_mut().find(|payment| payment.id == payment_id).ok_or("결제 없음")?;
+        payment.provider_payment_id = Some(provider_payment_id.to_string());
+        payment.checkout_url = Some(checkout_url.to_string());
+        Ok(())
+    }
+
+    async fn load_payment_by_provider_id(&self, provider: &str, provider_payment_id: &str) -> Result<Option<Payment>, String> {
+        Ok(self.payments
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|payment| payment.provider == provider && payment.provider_payment_id.as_deref() == Some(provider_payment_id))
+            .cloned())
+    }
+
+    async fn load_latest_payment(&self, reservation_id: i32) -> Result<Option<Payment>, String> {
+        Ok(self.payments.lock().unwrap().iter().rev().find(|payment| payment.reservation_id == reservation_id).cloned())
+    }
+
+    async fn confirm_payment(&self, payment_id: i64, now: DateTime<Utc>, _audit: &AuditContext) -> Result<PaymentConfirmation, String> {
+        let mut reservations = self.reservations.lock().unwrap();
+        let mut payments = self.payments.lock().unwrap();
+        let payment = payments.iter_mut().find(|payment| payment.id == payment_id).ok_or("결제 없음")?;
+        if !matches!(payment.status, PaymentStatus::Pending | PaymentStatus::Failed | PaymentStatus::Expired) {
+            return Ok(PaymentConfirmation::AlreadyProcessed);
+        }
+
+        payment.paid_at = Some(now);
+        match reservations.get_mut(&payment.reservation_id).filter(|reservation| reservation.is_pending_payment()) {
+            Some(reservation) => {
+                payment.status = PaymentStatus::Paid;
+                reservation.status = Some(ReservationStatus::Pending);
+                reservation.payment_due_at = None;
+                Ok(PaymentConfirmation::Confirmed)
+            }
+            None => {
+                payment.status = PaymentStatus::RefundPending;
+                payment.refund_amount = Some(payment.amount);
+                Ok(PaymentConfirmation::Late)
+            }
+        }
+    }
+
+    async fn release_unpaid_reservation(&self, reservation_id: i32, payment_status: PaymentStatus, failure_reason: Option<&str>, _audit: &AuditContext) -> Result<bool, String> {
+        let mut reservations = self.reservations.lock().unwrap();
+        let Some(reservation) = reservations.get_mut(&reservation_id).filter(|reservation| reservation.is_pending_payment()) else {
+            return Ok(false);
+        };
+        reservation.status = Some(ReservationStatus::Cancelled);
+        reservation.payment_due_at = None;
+
+        for payment in self.payments.lock().unwrap().iter_mut() {
+            if payment.reservation_id == reservation_id && payment.status == PaymentStatus::Pending {
+                payment.status = payment_status;
+                if let Some(reason) = failure_reason {
+                    payment.failure_reason = Some(reason.to_string());
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    async fn load_overdue_reservation_ids(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, String> {
+        Ok(self.reservations
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|reservation| reservation.is_pending_payment() && reservation.payment_due_at.is_some_and(|due_at| due_at <= now))
+            .map(|reservation| reservation.id)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn request_refund(&self, payment_id: i64, amount: i64) -> Result<bool, String> {
+        let mut payments = self.payments.lock().unwrap();
+        match payments.iter_mut().find(|payment| payment.id == payment_id && payment.status == PaymentStatus::Paid) {
+            Some(payment) => {
+                payment.status = PaymentStatus::RefundPending;
+                payment.refund_amount = Some(amount);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn complete_refund(&self, payment_id: i64, now: DateTime<Utc>) -> Result<(), String> {
+        let mut payments = self.payments.lock().unwrap();
+        if let Some(payment) = payments.iter_mut().find(|payment| payment.id == payment_id && payment.status == PaymentStatus::RefundPending) {
+            payment.status = PaymentStatus::Refunded;
+            payment.refunded_at = Some(now);
+        }
+        Ok(())
+    }
+
+    async fn load_refund_pending(&self, limit: i64) -> Result<Vec<Payment>, String> {
+        Ok(self.payments
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|payment| payment.status == PaymentStatus::RefundPending)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+// 결제 흐름에서 쓰는 예약 / 회차 조회만 구현
+#[async_trait]
+impl ReservationLoadPort for InMemoryStore {
+    async fn load_reservation(&self, reservation_id: i32) -> Result<Option<Reservation>, String> {
+        Ok(self.reservations.lock().unwrap().get(&reservation_id).cloned())
+    }
+    async fn load_reservations_by_user(&self, _user_id: &str, _page: PageRequest) -> Result<Page<Reservation>, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn load_reservations_by_date(&self, _start_time: DateTime<Utc>, _end_time: DateTime<Utc>, _page: PageRequest) -> Result<Page<Reservation>, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn load_reservations_by_content_schedule(&self, _content_schedule_id: u64, _page: PageRequest) -> Result<Page<Reservation>, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn load_reservation_history(&self, _reservation_id: i32) -> Result<Vec<ReservationHistory>, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn check_reservation_for_user_count(&self, _user_id: &str, _schedule_id: u64) -> Result<ReservationLimits, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn check_schedule_and_reservation(&self, _user_id: &str, _schedule_id: u64) -> Result<bool, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn check_user_reservation_for_content(&self, _user_id: &str, _schedule_id: u64) -> Result<bool, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn load_schedule_availability(&self, _schedule_id: u64) -> Result<Option<ScheduleAvailability>, String> {
+        Ok(None)
+    }
+    async fn load_schedules_availability(&self, _filter: ScheduleAvailabilityFilter) -> Result<Vec<ScheduleAvailability>, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn count_user_no_shows(&self, _user_id: &str, _since: DateTime<Utc>) -> Result<i64, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+    async fn search_reservations(&self, _filter: ReservationFilter, _page: PageRequest) -> Result<Page<Reservation>, String> {
+        Err("결제 테스트에서 사용하지 않음".to_string())
+    }
+}
+
+// 알림은 발송하지 않음
+struct SilentNotifier;
+
+#[async_trait]
+impl NotificationPort for SilentNotifier {
+    async fn notify(&self, _message: &NotificationMessage) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserLocalePort for SilentNotifier {
+    async fn load_locale(&self, _user_id: &str) -> Result<Option<Locale>, String> {
+        Ok(None)
+    }
+}
+
+// 콜백 서명 시각 검증은 게이트웨이 시계 기준
+fn mock_gateway(now: DateTime<Utc>) -> Arc<dyn PaymentPort + Send + Sync> {
+    Arc::new(MockPaymentGateway::new(CALLBACK_SECRET, Arc::new(FixedClock(now))).expect("mock 게이트웨이 생성 실패"))
+}
+
+fn payment_service(store: &Arc<InMemoryStore>, gateway: &Arc<dyn PaymentPort + Send + Sync>, now: DateTime<Utc>) -> PaymentService {
+    let clock = Arc::new(FixedClock(now));
+    let notifier = Arc::new(BookingNotifier::new(Arc::new(SilentNotifier), Arc::new(SilentNotifier), Seoul, Locale::Ko));
+    let refund_policy = RefundPolicy { full_refund_minutes_before: 24 * 60, partial_refund_minutes_before: 60, partial_refund_percent: 50 };
+    PaymentService::new(store.clone(), Arc::clone(gateway), store.clone(), clock, notifier, refund_policy, 15, 100)
+}
+
+fn signed_callback(body: &str, now: DateTime<Utc>) -> String {
+    webhook_signature::sign(CALLBACK_SECRET, now, body)
+}
+
+fn paid_body(provider_payment_id: &str, amount: i64) -> String {
+    format!(r#"{{"payment_id": "{}", "status": "PAID", "amount": {}}}"#, provider_payment_id, amount)
+}
+
+#[tokio::test]
+async fn paid_callback_confirms_pending_payment_reservation() {
+    let store = Arc::new(InMemoryStore::with_reservation(pending_payment_reservation(now() + Duration::minutes(15))));
+    let service = payment_service(&store, &mock_gateway(now()), now());
+
+    let payment = service.start_payment(USER_ID, RESERVATION_ID).await.expect("결제 시작 실패");
+    assert_eq!(payment.amount, AMOUNT);
+    let provider_payment_id = payment.provider_payment_id.clone().expect("결제창 ID 없음");
+
+    let body = paid_body(&provider_payment_id, AMOUNT);
+    service.handle_callback(&body, Some(&signed_callback(&body, now()))).await.expect("결제 콜백 실패");
+
+    assert_eq!(store.payment(payment.id).status, PaymentStatus::Paid);
+    assert!(matches!(store.reservation(RESERVATION_ID).status, Some(ReservationStatus::Pending)));
+
+    // 같은 콜백 재전송은 상태를 바꾸지 않음
+    service.handle_callback(&body, Some(&signed_callback(&body, now()))).await.expect("재전송 콜백 실패");
+    assert_eq!(store.payment(payment.id).status, PaymentStatus::Paid);
+}
+
+#[tokio::test]
+async fn unsigned_or_mismatched_callbacks_do_not_confirm() {
+    let store = Arc::new(InMemoryStore::with_reservation(pending_payment_reservation(now() + Duration::minutes(15))));
+    let service = payment_service(&store, &mock_gateway(now()), now());
+    let payment = service.start_payment(USER_ID, RESERVATION_ID).await.expect("결제 시작 실패");
+    let provider_payment_id = payment.provider_payment_id.clone().expect("결제창 ID 없음");
+
+    let body = paid_body(&provider_payment_id, AMOUNT);
+    assert!(service.handle_callback(&body, None).await.is_err());
+    assert!(service.handle_callback(&body, Some("t=0,v1=00")).await.is_err());
+
+    let underpaid = paid_body(&provider_payment_id, 1);
+    assert!(service.handle_callback(&underpaid, Some(&signed_callback(&underpaid, now()))).await.is_err());
+
+    assert_eq!(store.payment(payment.id).status, PaymentStatus::Pending);
+    assert!(store.reservation(RESERVATION_ID).is_pending_payment());
+}
+
+#[tokio::test]
+async fn overdue_payment_releases_reservation() {
+    let store = Arc::new(InMemoryStore::with_reservation(pending_payment_reservation(now() + Duration::minutes(15))));
+    let gateway = mock_gateway(now());
+    let payment = payment_service(&store, &gateway, now()).start_payment(USER_ID, RESERVATION_ID).await.expect("결제 시작 실패");
+
+    // 결제 기한이 지난 시각에 만료 작업 실행
+    let later = payment_service(&store, &gateway, now() + Duration::minutes(16));
+    assert_eq!(later.expire_unpaid().await.expect("만료 처리 실패"), 1);
+
+    assert_eq!(store.payment(payment.id).status, PaymentStatus::Expired);
+    assert!(store.reservation(RESERVATION_ID).is_cancelled());
+    assert_eq!(later.expire_unpaid().await.expect("만료 처리 실패"), 0);
+}
+
+#[tokio::test]
+async fn late_payment_is_fully_refunded() {
+    let store = Arc::new(InMemoryStore::with_reservation(pending_payment_reservation(now() + Duration::minutes(15))));
+    let later_time = now() + Duration::minutes(20);
+    let gateway = mock_gateway(later_time);
+    let payment = payment_service(&store, &gateway, now()).start_payment(USER_ID, RESERVATION_ID).await.expect("결제 시작 실패");
+    let provider_payment_id = payment.provider_payment_id.clone().expect("결제창 ID 없음");
+
+    let later = payment_service(&store, &gateway, later_time);
+    later.expire_unpaid().await.expect("만료 처리 실패");
+
+    // 예약이 해제된 뒤 도착한 결제 완료 콜백
+    let body = paid_body(&provider_payment_id, AMOUNT);
+    later.handle_callback(&body, Some(&signed_callback(&body, later_time))).await.expect("결제 콜백 실패");
+
+    let refunded = store.payment(payment.id);
+    assert_eq!(refunded.status, PaymentStatus::Refunded);
+    assert_eq!(refunded.refund_amount, Some(AMOUNT));
+    assert!(store.reservation(RESERVATION_ID).is_cancelled());
+}
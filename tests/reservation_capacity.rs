@@ -0,0 +1,199 @@
+// 동시 예약 좌석 초과 방지 스트레스 테스트 (MySQL 필요)
+//
+// 실제 예약 생성 경로(ReservationService::create_reservation → 예약 마감 / 정책 / 금액 / 결제 대기 / 저장)를 동시에 호출
+// CONTENTS / CONTENT_SCHEDULES / USERS 는 다른 서비스가 관리하므로 미리 준비된 회차와 사용자를 사용
+//   - TEST_SCHEDULE_ID: 시작 시간이 미래이고 권종별 인원 제한이 없는 회차
+//   - TEST_USER_ID: USERS 에 있는 사용자 (모든 요청을 같은 사용자로 보내므로 사용자별 규칙은 제외한 정책으로 검사)
+//   TEST_DATABASE_URL=mysql://.. TEST_SCHEDULE_ID=.. TEST_USER_ID=.. cargo test --test reservation_capacity
+// 환경 변수가 없으면 건너뜀 (CI 에서는 통과 처리)
+// 테스트 전용 DB 에서 실행할 것 (생성한 예약은 삭제하고 회차 예약 인원은 원래 값으로 되돌림)
+use std::{env, sync::Arc};
+
+use async_trait::async_trait;
+use chrono_tz::Asia::Seoul;
+use reservation_msservice::{
+    adapter::{payment_record_adapter::PaymentRecordAdapter, pricing_adapter::PricingAdapter, reservation_adapter::ReservationAdapter},
+    application::{
+        booking_notifier::BookingNotifier,
+        payment_service::PaymentService,
+        policy::{booking_policy::BookingPolicy, rules::{CategoryMixRule, PartySizeRule}},
+        port::{
+            r#in::{payment_usecase::PaymentUseCase, pricing_usecase::PricingUseCase, reservation_usecase::ReservationUseCase},
+            out::{notification_port::NotificationPort, reservation_load_port::ReservationLoadPort, reservation_save_port::ReservationSavePort, user_locale_port::UserLocalePort},
+        },
+        pricing_service::PricingService,
+        reservation_service::ReservationService,
+    },
+    common::clock::{Clock, SystemClock},
+    domain::{
+        booking_window::BookingWindow,
+        check_in::CheckInWindow,
+        notification::{Locale, NotificationMessage},
+        payment::RefundPolicy,
+        policy_violation::BookingOutcome,
+        reservation::{Reservation, ReservationStatus},
+        reservation_history::AuditContext,
+        ticket_category::PartyComposition,
+    },
+    infra::{
+        db::{payment_repository_impl::PaymentRepositoryImpl, pricing_repository_impl::PricingRepositoryImpl, reservation_repository::ReservationRepository, reservation_repository_impl::ReservationRepositoryImpl},
+        payment::MockPaymentGateway,
+    },
+};
+use sqlx::{mysql::MySqlPoolOptions, MySqlPool};
+
+const CONCURRENT_REQUESTS: usize = 300;
+const CALLBACK_SECRET: &str = "capacity-test-callback-secret-0123456789";
+
+fn one_adult_reservation(user_id: &str, schedule_id: u64) -> Reservation {
+    let party = PartyComposition::from_legacy(1, 0);
+    Reservation {
+        id: 0,
+        user_id: user_id.to_string(),
+        content_schedule_id: schedule_id,
+        reserved_at: None,
+        status: Some(ReservationStatus::Pending),
+        ad_cnt: party.adults(),
+        cd_cnt: party.children(),
+        use_at: false,
+        checked_in_ad_cnt: 0,
+        checked_in_cd_cnt: 0,
+        group_reservation_id: None,
+        payment_due_at: None,
+        party,
+        price: None,
+        schedule: None,
+        flags: None,
+        ticket_token: None,
+        version: None,
+    }
+}
+
+struct SilentNotifier;
+
+#[async_trait]
+impl NotificationPort for SilentNotifier {
+    async fn notify(&self, _message: &NotificationMessage) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserLocalePort for SilentNotifier {
+    async fn load_locale(&self, _user_id: &str) -> Result<Option<Locale>, String> {
+        Ok(None)
+    }
+}
+
+// state.rs 와 같은 구성 (알림은 보내지 않고, 같은 사용자의 반복 예약을 막는 규칙은 제외)
+fn reservation_service(pool: &Arc<MySqlPool>, repository: &Arc<dyn ReservationRepository + Send + Sync>) -> ReservationService {
+    let adapter = Arc::new(ReservationAdapter::new(Arc::clone(repository)));
+    let save_port: Arc<dyn ReservationSavePort + Send + Sync> = adapter.clone();
+    let load_port: Arc<dyn ReservationLoadPort + Send + Sync> = adapter.clone();
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+    let window = BookingWindow { create_close_minutes_before: 0, change_count_close_minutes_before: 0, cancel_close_minutes_before: 0 };
+    let policy = Arc::new(BookingPolicy::new(vec![Arc::new(PartySizeRule::new(1, 10)), Arc::new(CategoryMixRule)]));
+    let notifier = Arc::new(BookingNotifier::new(Arc::new(SilentNotifier), Arc::new(SilentNotifier), Seoul, Locale::Ko));
+
+    let pricing: Arc<dyn PricingUseCase + Send + Sync> = Arc::new(PricingService::new(
+        Arc::new(PricingAdapter::new(Arc::new(PricingRepositoryImpl::new(Arc::clone(pool))))),
+        Arc::clone(&load_port),
+        Arc::clone(&clock),
+        Seoul,
+        "KRW".to_string(),
+    ));
+    let gateway = Arc::new(MockPaymentGateway::new(CALLBACK_SECRET, Arc::clone(&clock)).expect("mock 게이트웨이 생성 실패"));
+    let refund_policy = RefundPolicy { full_refund_minutes_before: 24 * 60, partial_refund_minutes_before: 60, partial_refund_percent: 50 };
+    let payment: Arc<dyn PaymentUseCase + Send + Sync> = Arc::new(PaymentService::new(
+        Arc::new(PaymentRecordAdapter::new(Arc::new(PaymentRepositoryImpl::new(Arc::clone(pool))))),
+        gateway,
+        Arc::clone(&load_port),
+        Arc::clone(&clock),
+        Arc::clone(&notifier),
+        refund_policy,
+        15,
+        100,
+    ));
+
+    let check_in_window = CheckInWindow { opens_minutes_before: 60, closes_minutes_after: 30 };
+    ReservationService::new(save_port, load_port, policy, clock, Seoul, window, None, check_in_window, notifier, pricing, payment)
+}
+
+async fn reservation_ids(pool: &MySqlPool, user_id: &str, schedule_id: u64) -> Vec<i32> {
+    sqlx::query_scalar("SELECT id FROM RESERVATION WHERE user_id = ? AND content_schedule_id = ?")
+        .bind(user_id)
+        .bind(schedule_id)
+        .fetch_all(pool)
+        .await
+        .expect("예약 목록 조회 실패")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn concurrent_reservations_never_exceed_schedule_capacity() {
+    let (Ok(database_url), Ok(schedule_id), Ok(user_id)) = (env::var("TEST_DATABASE_URL"), env::var("TEST_SCHEDULE_ID"), env::var("TEST_USER_ID")) else {
+        eprintln!("TEST_DATABASE_URL / TEST_SCHEDULE_ID / TEST_USER_ID 가 없어 좌석 동시성 테스트를 건너뜁니다.");
+        return;
+    };
+    let schedule_id: u64 = schedule_id.parse().expect("TEST_SCHEDULE_ID 는 숫자");
+
+    let pool = Arc::new(MySqlPoolOptions::new().max_connections(20).connect(&database_url).await.expect("DB 연결 실패"));
+    let repository: Arc<dyn ReservationRepository + Send + Sync> = Arc::new(ReservationRepositoryImpl::new(Arc::clone(&pool)));
+    let service = Arc::new(reservation_service(&pool, &repository));
+
+    let before = repository
+        .load_schedule_availability(schedule_id)
+        .await
+        .expect("회차 조회 실패")
+        .expect("TEST_SCHEDULE_ID 회차가 없습니다");
+    assert!(
+        (before.seats_remaining as usize) < CONCURRENT_REQUESTS,
+        "잔여 좌석({})보다 많은 요청을 보내야 초과 예약을 확인할 수 있습니다",
+        before.seats_remaining
+    );
+    let existing_ids = reservation_ids(&pool, &user_id, schedule_id).await;
+
+    // 같은 회차에 1명씩 동시 예약
+    let tasks: Vec<_> = (0..CONCURRENT_REQUESTS)
+        .map(|_| {
+            let service = Arc::clone(&service);
+            let reservation = one_adult_reservation(&user_id, schedule_id);
+            tokio::spawn(async move { service.create_reservation(reservation, 10, 10, &AuditContext::system()).await })
+        })
+        .collect();
+
+    let mut created = 0;
+    for task in tasks {
+        if let Ok(BookingOutcome::Created) = task.await.expect("예약 태스크 panic") {
+            created += 1;
+        }
+    }
+
+    let after = repository
+        .load_schedule_availability(schedule_id)
+        .await
+        .expect("회차 조회 실패")
+        .expect("회차가 없습니다");
+    let created_ids: Vec<i32> = reservation_ids(&pool, &user_id, schedule_id)
+        .await
+        .into_iter()
+        .filter(|reservation_id| !existing_ids.contains(reservation_id))
+        .collect();
+
+    // 정리 (검증 실패 여부와 관계없이 먼저 수행)
+    for reservation_id in &created_ids {
+        repository.delete_reservation(*reservation_id, &AuditContext::system()).await.expect("예약 삭제 실패");
+    }
+    sqlx::query("UPDATE CONTENT_SCHEDULES SET adult_count = ?, child_count = ? WHERE id = ?")
+        .bind(before.booked_adults)
+        .bind(before.booked_children)
+        .bind(schedule_id)
+        .execute(&*pool)
+        .await
+        .expect("회차 예약 인원 복구 실패");
+
+    let booked_after = after.booked_adults + after.booked_children;
+    assert!(booked_after <= after.total_seats, "초과 예약 발생: 예약 {}명 / 좌석 {}석", booked_after, after.total_seats);
+    assert_eq!(created, before.seats_remaining as usize, "잔여 좌석만큼만 예약되어야 합니다");
+    assert_eq!(created_ids.len(), created, "생성 응답 수와 저장된 예약 수가 다릅니다");
+    assert_eq!(after.booked_adults - before.booked_adults, created as i32, "회차 예약 인원과 생성된 예약 수가 다릅니다");
+}